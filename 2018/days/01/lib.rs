@@ -0,0 +1,102 @@
+use std::{collections::HashSet, iter::Sum};
+
+use aoc_parse::{parse_lines, Result, Span};
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+enum Frequency {
+    Positive(u32),
+    Negative(u32),
+}
+
+impl Frequency {
+    fn parse(line: Span) -> Result<Self> {
+        let (sign, num) = line.split_at(1)?;
+        let num = num.parse::<u32>()?;
+
+        match sign.as_str() {
+            "+" => Ok(Self::Positive(num)),
+            "-" => Ok(Self::Negative(num)),
+            _ => Err(sign.error(format!("Invalid sign {sign}"))),
+        }
+    }
+
+    fn change_with(&self, with: Frequency) -> Self {
+        match self {
+            Frequency::Positive(self_value) => match with {
+                Frequency::Positive(with_value) => Frequency::Positive(self_value + with_value),
+                Frequency::Negative(with_value) => {
+                    if *self_value < with_value {
+                        Frequency::Negative(with_value - self_value)
+                    } else {
+                        Frequency::Positive(self_value - with_value)
+                    }
+                }
+            },
+            Frequency::Negative(self_value) => match with {
+                Frequency::Negative(with_value) => Frequency::Negative(self_value + with_value),
+                Frequency::Positive(with_value) => {
+                    if *self_value < with_value {
+                        Frequency::Positive(with_value - self_value)
+                    } else {
+                        Frequency::Negative(self_value - with_value)
+                    }
+                }
+            },
+        }
+    }
+
+    fn get_value(&self) -> Option<u32> {
+        match self {
+            Frequency::Positive(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl Sum for Frequency {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|acc, frequency| acc.change_with(frequency))
+            .unwrap()
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Frequency>> {
+    parse_lines(input, Frequency::parse)
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let frequencies = read_input(input).unwrap();
+    let result = frequencies.into_iter().sum::<Frequency>();
+    result.get_value()
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let frequencies = read_input(input).unwrap();
+    let mut mem: HashSet<Frequency> = HashSet::default();
+    let mut acc = Frequency::Positive(0);
+    for frequency in frequencies.into_iter().cycle() {
+        acc = acc.change_with(frequency);
+        if mem.contains(&acc) {
+            break;
+        }
+        mem.insert(acc.clone());
+    }
+
+    acc.get_value()
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 1: Chronal Calibration ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use aoc_parse::lines;
+
+#[derive(Debug, Clone)]
+struct Checksum {
+    source: String,
+    map: HashMap<char, usize>,
+}
+
+impl Display for Checksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl Ord for Checksum {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let diff_size = self.source.len() - self.common(other).len();
+        
+        if diff_size == 1 {
+            Ordering::Equal
+        } else if diff_size > 1 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+impl PartialOrd for Checksum {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Checksum {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for Checksum {}
+
+impl Checksum {
+    fn from_string(str: &str) -> Self {
+        let unique: HashSet<char> = str.chars().collect();
+        Self {
+            source: str.to_string(),
+            map: unique
+                .iter()
+                .map(|&c| (c, str.matches(c).count()))
+                .collect(),
+        }
+    }
+
+    fn common(&self, other: &Checksum) -> String {
+        self.source
+            .chars()
+            .zip(other.source.chars())
+            .filter(|&(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect::<String>()
+    }
+
+    fn get_value(&self) -> (u32, u32) {
+        let two = if self.map.values().any(|&v| v == 2) {
+            1
+        } else {
+            0
+        };
+        let three = if self.map.values().any(|&v| v == 3) {
+            1
+        } else {
+            0
+        };
+        (two, three)
+    }
+}
+
+fn read_input(input: &str) -> Vec<Checksum> {
+    lines(input)
+        .map(|line| Checksum::from_string(line.as_str()))
+        .collect()
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let checksums = read_input(input);
+    let (two, three) = checksums.iter().fold((0, 0), |(two, three), checksum| {
+        let (c_two, c_three) = checksum.get_value();
+        (c_two + two, c_three + three)
+    });
+    Some(two * three)
+}
+
+fn part2(input: &str) -> Option<String> {
+    let checksums = read_input(input);
+    for a in checksums.iter() {
+      for b in checksums.iter().rev() {
+        if matches!(a.cmp(b), Ordering::Equal) {
+          return Some(a.common(b));
+        }
+      }
+    }
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 2: Inventory Management System ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
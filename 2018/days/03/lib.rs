@@ -0,0 +1,138 @@
+use std::{
+    collections::HashMap,
+    fmt::{write, Display},
+};
+
+use aoc_parse::{parse_lines, Pattern, Result};
+
+type Vector = (u32, u32);
+
+#[derive(Debug)]
+struct Claim {
+    id: usize,
+    position: Vector,
+    size: Vector,
+}
+
+#[derive(Debug)]
+struct Fabric(HashMap<Vector, Vec<usize>>);
+
+impl Fabric {
+    fn new() -> Self {
+        Self {
+            0: Default::default(),
+        }
+    }
+
+    fn cut(&mut self, claim: &Claim) {
+        let x_min = claim.position.0;
+        let x_max = x_min + claim.size.0;
+
+        let y_min = claim.position.1;
+        let y_max = y_min + claim.size.1;
+
+        let positions = (x_min..x_max).flat_map(|x| (y_min..y_max).map(move |y| (x, y)));
+
+        for position in positions {
+            if let Some(claims) = self.0.get_mut(&position) {
+                claims.push(claim.id);
+            } else {
+                self.0.insert(position, vec![claim.id]);
+            }
+        }
+    }
+
+    fn is_alone(&self, claim: &Claim) -> bool {
+        self.0
+            .values()
+            .filter(|claims| claims.contains(&claim.id))
+            .all(|claims| claims.len() == 1)
+    }
+
+    fn get_count(&self) -> u32 {
+        self.0
+            .values()
+            .into_iter()
+            .filter(|claims| claims.len() > 1)
+            .count() as u32
+    }
+}
+
+impl Display for Fabric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let min_x = *self.0.keys().map(|(x, _)| x).min().unwrap();
+        let max_x = *self.0.keys().map(|(x, _)| x).max().unwrap();
+
+        let min_y = *self.0.keys().map(|(_, y)| y).min().unwrap();
+        let max_y = *self.0.keys().map(|(_, y)| y).max().unwrap();
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                if let Some(claims) = self.0.get(&(x, y)) {
+                    let tile = if claims.len() > 1 { 'X' } else { '.' };
+                    write!(f, "{tile}").unwrap();
+                } else {
+                    write!(f, " ").unwrap();
+                }
+            }
+            write!(f, "\n").unwrap();
+        }
+
+        Ok(())
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Claim>> {
+    let pattern = Pattern::new("#<id> @ <x>,<y>: <width>x<height>");
+    parse_lines(input, |line| {
+        let [id, x, y, width, height] = pattern.parse(line)?;
+        Ok(Claim {
+            id: id as usize,
+            position: (x, y),
+            size: (width, height),
+        })
+    })
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let claims = read_input(input).unwrap();
+    let mut fabric = Fabric::new();
+
+    for claim in claims.iter() {
+        fabric.cut(claim);
+    }
+
+    let count = fabric.get_count();
+    Some(count)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let claims = read_input(input).unwrap();
+    let mut fabric = Fabric::new();
+
+    for claim in claims.iter() {
+        fabric.cut(claim);
+    }
+
+    if let Some(claim) = claims.iter().find(|claim| fabric.is_alone(claim)) {
+        Some(claim.id as u32)
+    } else {
+        None
+    }
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 3: No Matter How You Slice It ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,245 @@
+use core::time;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+};
+
+use aoc_parse::{parse_lines, Pattern, Result, Span};
+
+type Date = (u16, u8, u8);
+type Time = (u8, u8);
+
+#[derive(Debug, PartialEq, Eq)]
+enum GuardAction {
+    Begin(u32),
+    Sleep,
+    Wake,
+}
+
+impl GuardAction {
+    fn parse(action: Span) -> Result<Self> {
+        match action.as_str() {
+            "wakes up" => Ok(Self::Wake),
+            "falls asleep" => Ok(Self::Sleep),
+            _ => {
+                let guard_no = action
+                    .strip_prefix("Guard #")?
+                    .strip_suffix(" begins shift")?
+                    .parse()?;
+                Ok(Self::Begin(guard_no))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    date: Date,
+    time: Time,
+    action: GuardAction,
+}
+
+impl Entry {
+    fn parse(pattern: &Pattern, line: Span) -> Result<Self> {
+        let captures = pattern.captures(line)?;
+
+        Ok(Self {
+            date: (
+                captures.parse("year")?,
+                captures.parse("month")?,
+                captures.parse("day")?,
+            ),
+            time: (captures.parse("hour")?, captures.parse("minute")?),
+            action: GuardAction::parse(captures.get("action"))?,
+        })
+    }
+
+    fn get_time(&self) -> u64 {
+        let year: u64 = self.date.0 as u64 * 1000_00_00_00_00;
+        let month: u64 = self.date.1 as u64 * 10_00_00_00;
+        let day: u64 = self.date.2 as u64 * 10_00_00;
+        let hour: u64 = self.time.0 as u64 * 10_00;
+        let minute: u64 = self.time.1 as u64 * 1;
+
+        year + month + day + hour + minute
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_time().cmp(&other.get_time())
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_time() == other.get_time() && self.action == other.action
+    }
+}
+
+impl Eq for Entry {}
+
+#[derive(Debug)]
+struct SleepCalendar {
+    calendar: BTreeMap<Date, (u32, Vec<Time>)>,
+    guards: HashMap<u32, (u32, HashMap<Time, usize>)>,
+    current_guard: Option<u32>,
+    sleeping: Option<Time>,
+}
+
+impl SleepCalendar {
+    fn new() -> Self {
+        Self {
+            calendar: Default::default(),
+            guards: Default::default(),
+            current_guard: None,
+            sleeping: None,
+        }
+    }
+
+    fn add_entry(&mut self, entry: &Entry) {
+        match entry.action {
+            GuardAction::Begin(guard) => self.current_guard = Some(guard),
+            GuardAction::Sleep => self.sleeping = Some(entry.time),
+            GuardAction::Wake => {
+                let guard = self.current_guard.unwrap();
+                let calendar_entry = self
+                    .calendar
+                    .entry(entry.date)
+                    .or_insert((guard, Default::default()));
+
+                let (from_hour, from_minute) = self.sleeping.unwrap();
+                let (to_hour, to_minute) = entry.time;
+
+                let elapsed_minutes =
+                    (60 * (((24 + to_hour) - from_hour) % 24) + to_minute) - from_minute;
+
+                let guard_entry = self.guards.entry(guard).or_insert((0, Default::default()));
+                guard_entry.0 += elapsed_minutes as u32;
+
+                for elapsed_minute in 0..elapsed_minutes {
+                    let minute = (from_minute + elapsed_minute) % 60;
+                    let hour = (from_hour
+                        + if (from_minute + elapsed_minute) >= 60 {
+                            1
+                        } else {
+                            0
+                        })
+                        % 24;
+                    let time = (hour, minute);
+                    let time_entry = guard_entry.1.entry(time).or_insert(0);
+                    *time_entry += 1;
+
+                    calendar_entry.1.push(time);
+                }
+            }
+        };
+    }
+}
+
+impl Display for SleepCalendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Date   ID   Minute")?;
+        writeln!(
+            f,
+            "               000000000011111111112222222222333333333344444444445555555555"
+        )?;
+        writeln!(
+            f,
+            "               012345678901234567890123456789012345678901234567890123456789"
+        )?;
+
+        for (date, (guard, sleep_times)) in self.calendar.iter() {
+            write!(f, "{:02}-{:02}  #{:04}   ", date.1, date.2, guard)?;
+            for minute in 0..60 {
+                if sleep_times.contains(&(0, minute)) {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            write!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Entry>> {
+    let pattern = Pattern::new("[<year>-<month>-<day> <hour>:<minute>] <action>");
+    parse_lines(input, |line| Entry::parse(&pattern, line))
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let mut entries = read_input(input).unwrap();
+    entries.sort();
+
+    let mut calendar = SleepCalendar::new();
+
+    for entry in entries.iter() {
+        calendar.add_entry(entry);
+    }
+
+    let best_guard = calendar
+        .guards
+        .iter()
+        .max_by(|&a, &b| a.1 .0.cmp(&b.1 .0))
+        .unwrap();
+
+    let best_time = best_guard
+        .1
+         .1
+        .iter()
+        .max_by(|&a, &b| a.1.cmp(b.1))
+        .unwrap();
+
+    Some(best_guard.0 * best_time.0 .1 as u32)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let mut entries = read_input(input).unwrap();
+    entries.sort();
+
+    let mut calendar = SleepCalendar::new();
+
+    for entry in entries.iter() {
+        calendar.add_entry(entry);
+    }
+
+    let best_guard = calendar
+        .guards
+        .iter()
+        .max_by(|&a, &b| a.1 .1.values().max().cmp(&b.1 .1.values().max()))
+        .unwrap();
+
+    let ((_, minute), _) = best_guard
+        .1
+         .1
+        .iter()
+        .max_by(|&a, &b| a.1.cmp(b.1))
+        .unwrap();
+
+    Some(best_guard.0 * *minute as u32)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 4: Repose Record ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
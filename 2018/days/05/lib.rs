@@ -0,0 +1,110 @@
+use aoc_parse::{single_line, Result};
+
+fn read_input(input: &str) -> Result<Polymer> {
+    Ok(Polymer::from_str(single_line(input)?.as_str()))
+}
+
+struct Polymer {
+    source: String,
+    skip: Option<char>,
+    reacted: String,
+}
+
+impl Polymer {
+    fn from_str(str: &str) -> Self {
+        Self {
+            source: str.to_string(),
+            reacted: str.to_string(),
+            skip: None,
+        }
+    }
+    fn get_units(&self) -> u32 {
+        self.reacted.len() as u32
+    }
+
+    fn react(&mut self) -> u32 {
+        self.reacted = self.source.clone();
+        while let Some(_) = self.next() {};
+
+        self.get_units()
+    }
+
+    fn react_with_skip(&mut self, unit: char) -> u32 {
+        self.skip = Some(unit);
+
+        self.react()
+    }
+}
+
+impl Iterator for Polymer {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut new_polymer = String::default();
+        let polymer = self.reacted.clone();
+        let mut chars = polymer.chars().peekable();
+
+        let mut did_change = false;
+        while let Some(char_left) = chars.next() {
+            match self.skip {
+                Some(char_skip) if char_skip.eq_ignore_ascii_case(&char_left) => {
+                    did_change = true;
+                    continue;
+                }
+                _ => match chars.peek() {
+                    Some(&char_right) if (char_right as i8 - char_left as i8).abs() == 32 => {
+                        chars.next()?;
+                        did_change = true;
+                    }
+                    _ => {
+                        new_polymer.push(char_left);
+                    }
+                },
+            }
+        }
+
+        self.reacted = new_polymer;
+
+        if did_change {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let mut polymer = read_input(input).unwrap();
+    let result = polymer.react();
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let mut polymer = read_input(input).unwrap();
+
+    let result = (65..=90)
+        .map(|unit| char::from_u32(unit).unwrap())
+        .map(|skip_char| {
+          polymer.react_with_skip(skip_char)
+        })
+        .min()
+        .unwrap();
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 5: Alchemical Reduction ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeSet;
+
+use aoc_graph::{topo_sort_by_key, Graph};
+use aoc_parse::{parse_lines, Pattern, Result};
+
+#[derive(Debug)]
+struct Instruction(char, char);
+
+fn read_input(input: &str) -> Result<Graph<char>> {
+    let pattern = Pattern::new("Step <before> must be finished before step <after> can begin.");
+    let instructions = parse_lines(input, |line| {
+        let [before, after] = pattern.parse(line)?;
+        Ok(Instruction(before, after))
+    })?;
+
+    let mut steps = Graph::new();
+    for Instruction(before, after) in instructions {
+        let before = steps.add_node(before);
+        let after = steps.add_node(after);
+        steps.add_edge(before, after, 1);
+    }
+    Ok(steps)
+}
+
+/// Seconds for `max_workers` to finish every step, each one taking
+/// `step_duration` plus its position in the alphabet.
+fn process_instructions(steps: &Graph<char>, max_workers: usize, step_duration: u32) -> u32 {
+    let mut waiting_on = vec![0; steps.len()];
+    for edge in steps.edges() {
+        waiting_on[edge.destination] += 1;
+    }
+
+    let mut visit: BTreeSet<(char, usize)> = steps
+        .nodes()
+        .filter(|(id, _)| waiting_on[*id] == 0)
+        .map(|(id, &name)| (name, id))
+        .collect();
+    // Step being worked on and the time it's done
+    let mut workers: Vec<(usize, u32)> = Vec::new();
+    let mut elapsed_time = 0;
+
+    while !visit.is_empty() || !workers.is_empty() {
+        while workers.len() < max_workers {
+            let Some((name, id)) = visit.pop_first() else {
+                break;
+            };
+            let work_time = 1 + (name as u8 - b'A') as u32 + step_duration;
+            workers.push((id, elapsed_time + work_time));
+        }
+
+        elapsed_time = workers.iter().map(|(_, done)| *done).min().unwrap();
+        for (id, _) in workers.extract_if(.., |(_, done)| *done == elapsed_time) {
+            for child in steps.neighbors(id) {
+                waiting_on[child] -= 1;
+                if waiting_on[child] == 0 {
+                    visit.insert((*steps.get_node(child), child));
+                }
+            }
+        }
+    }
+
+    elapsed_time
+}
+
+fn part1(input: &str) -> Option<String> {
+    let steps = read_input(input).unwrap();
+    let order = topo_sort_by_key(&steps, |id| *steps.get_node(id)).ok()?;
+    Some(order.into_iter().map(|id| steps.get_node(id)).collect())
+}
+
+fn part2(input: &str) -> Option<String> {
+    let steps = read_input(input).unwrap();
+    let time = process_instructions(&steps, 5, 60);
+    Some(format!("{time}"))
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 7: The Sum of Its Parts ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,88 @@
+use std::borrow::Borrow;
+
+use aoc_parse::{single_line, Result};
+
+#[derive(Debug)]
+struct Node {
+    children: Vec<Node>,
+    metadata: Vec<u32>,
+}
+
+type NumberIterator = dyn Iterator<Item = u32>;
+
+impl Node {
+    fn from_iter(numbers: &mut NumberIterator) -> Self {
+        let mut head = numbers.take(2);
+        let child_count = head.next().unwrap();
+        let metadata_count = head.next().unwrap() as usize;
+
+        let children: Vec<Node> = (0..child_count).map(|_| Node::from_iter(numbers)).collect();
+        let metadata: Vec<u32> = numbers.take(metadata_count).collect();
+
+        Self { children, metadata }
+    }
+
+    fn get_metadata_value(&self) -> u32 {
+        let child_count: u32 = self
+            .children
+            .iter()
+            .map(|child| child.get_metadata_value())
+            .sum();
+        child_count + self.metadata.iter().sum::<u32>()
+    }
+
+    fn get_value(&self) -> u32 {
+        if self.children.len() == 0 {
+            self.metadata.iter().sum::<u32>()
+        } else {
+            self.metadata
+                .iter()
+                .map(|&child_index| {
+                    if child_index == 0 {
+                        0
+                    } else if let Some(child) = self.children.get((child_index - 1) as usize) {
+                        child.get_value()
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Node> {
+    let numbers = single_line(input)?.list::<u32>(" ")?;
+
+    let mut numbers_iter = numbers.into_iter();
+
+    Ok(Node::from_iter(&mut numbers_iter))
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let root_node = read_input(input).unwrap();
+    let result = root_node.get_metadata_value();
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let root_node = read_input(input).unwrap();
+    let result = root_node.get_value();
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 8: Memory Maneuver ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
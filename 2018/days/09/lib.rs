@@ -0,0 +1,99 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+use aoc_parse::{single_line, Pattern, Result};
+
+#[derive(Debug)]
+struct MarbleGame {
+    circle: VecDeque<u32>,
+    current_marble_value: u32,
+}
+
+impl MarbleGame {
+    fn new() -> Self {
+        Self {
+            circle: VecDeque::from([0]),
+            current_marble_value: 0
+        }
+    }
+
+    fn next(&mut self) -> Option<u32> {
+        self.current_marble_value += 1;
+
+        if (self.current_marble_value % 23) == 0 {
+            self.circle.rotate_right(7);
+            let result = self.current_marble_value + self.circle.pop_back().unwrap();
+            self.circle.rotate_left(1);
+
+            Some(result)
+        } else {
+            self.circle.rotate_left(1);
+            self.circle.push_back(self.current_marble_value);
+
+            None
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<(usize, u32)> {
+    let pattern = Pattern::new("<players> players; last marble is worth <points> points");
+    let captures = pattern.captures(single_line(input)?)?;
+
+    Ok((captures.parse("players")?, captures.parse("points")?))
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let mut game = MarbleGame::new();
+    let (player_count, max_marble_value) = read_input(input).unwrap();
+
+    let mut players: Vec<u32> = vec![0; player_count];
+    let mut current_player_index = 0;
+
+    while game.current_marble_value <= max_marble_value {
+        let current_player = players.get_mut(current_player_index).unwrap();
+        if let Some(count) = game.next() {
+            *current_player += count
+        }
+
+        current_player_index = (current_player_index + 1) % player_count;
+    }
+
+    players.iter().max().copied()
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let mut game = MarbleGame::new();
+    let (player_count, max_marble_value) = read_input(input).unwrap();
+
+    let mut players: Vec<u32> = vec![0; player_count];
+    let mut current_player_index = 0;
+
+    while game.current_marble_value <= (max_marble_value * 100) {
+        let current_player = players.get_mut(current_player_index).unwrap();
+        if let Some(count) = game.next() {
+            *current_player += count
+        }
+
+        current_player_index = (current_player_index + 1) % player_count;
+    }
+
+    players.iter().max().copied()
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 9: Marble Mania ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt::Display;
+
+use aoc_parse::{parse_lines, Result, Span};
+
+#[derive(Debug)]
+struct Vector2 {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug)]
+struct Point {
+    position: Vector2,
+    velocity: Vector2,
+}
+
+#[derive(Debug)]
+struct SkyMap {
+    points: Vec<Point>,
+    min: Vector2,
+    max: Vector2,
+}
+
+impl SkyMap {
+    fn new(points: Vec<Point>) -> Self {
+        let x_min = points.iter().map(|point| point.position.x).min().unwrap();
+        let x_max = points.iter().map(|point| point.position.x).max().unwrap();
+
+        let y_min = points.iter().map(|point| point.position.y).min().unwrap();
+        let y_max = points.iter().map(|point| point.position.y).max().unwrap();
+
+        Self {
+            points,
+            min: Vector2 { x: x_min, y: y_min },
+            max: Vector2 { x: x_max, y: y_max },
+        }
+    }
+
+    fn forwards(&mut self, amount: i32) {
+        for point in self.points.iter_mut() {
+            point.forwards(amount);
+        }
+
+        self.calc_size();
+    }
+
+    fn calc_size(&mut self) {
+        self.min.x = self
+            .points
+            .iter()
+            .map(|point| point.position.x)
+            .min()
+            .unwrap();
+
+        self.max.x = self
+            .points
+            .iter()
+            .map(|point| point.position.x)
+            .max()
+            .unwrap();
+
+        self.min.y = self
+            .points
+            .iter()
+            .map(|point| point.position.y)
+            .min()
+            .unwrap();
+
+        self.max.y = self
+            .points
+            .iter()
+            .map(|point| point.position.y)
+            .max()
+            .unwrap();
+    }
+
+    fn get_size(&self) -> Vector2 {
+        let width = self.max.x - self.min.x;
+        let height = self.max.y - self.min.y;
+
+        Vector2 {
+            x: width,
+            y: height,
+        }
+    }
+
+    fn backwards(&mut self, amount: i32) {
+        for point in self.points.iter_mut() {
+            point.backwards(amount);
+        }
+
+        self.calc_size();
+    }
+}
+
+impl Display for SkyMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.min.y..=self.max.y {
+            let mut line = String::new();
+            for x in self.min.x..=self.max.x {
+                let point = self
+                    .points
+                    .iter()
+                    .any(|point| point.position.x == x && point.position.y == y);
+                if point {
+                    line.push('#');
+                } else {
+                    line.push('.');
+                }
+            }
+            write!(f, "{line}\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Point {
+    fn parse(line: Span) -> Result<Self> {
+        let [x, y, velocity_x, velocity_y] = line.ints_array()?;
+
+        Ok(Self {
+            position: Vector2 { x, y },
+            velocity: Vector2 {
+                x: velocity_x,
+                y: velocity_y,
+            },
+        })
+    }
+
+    fn forwards(&mut self, amount: i32) {
+        self.position = self.position.add(&self.velocity.multiply(amount));
+    }
+
+    fn backwards(&mut self, amount: i32) {
+        self.position = self.position.sub(&self.velocity.multiply(amount));
+    }
+}
+
+impl Vector2 {
+    fn add(&self, other: &Vector2) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+
+    fn sub(&self, other: &Vector2) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+
+    fn multiply(&self, scalar: i32) -> Self {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Point>> {
+    parse_lines(input, Point::parse)
+}
+
+fn part1(input: &str) -> Option<String> {
+    let points = read_input(input).unwrap();
+    let mut map = SkyMap::new(points);
+    let mut best_size = map.get_size();
+    for _ in 0..100000 {
+        map.forwards(1);
+        let new_size = map.get_size();
+        if new_size.x > best_size.x {
+            map.backwards(1);
+            break;
+        }
+        best_size = new_size;
+    }
+    Some(map.to_string().trim_end().to_string())
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let points = read_input(input).unwrap();
+    let mut map = SkyMap::new(points);
+    let mut best_size = map.get_size();
+    for seconds in 0..100000 {
+        map.forwards(1);
+        let new_size = map.get_size();
+        if new_size.x > best_size.x {
+            return Some(seconds as u32);
+        }
+        best_size = new_size;
+    }
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 10: The Stars Align ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
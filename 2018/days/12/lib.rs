@@ -0,0 +1,95 @@
+use aoc_automaton::{Line, LineRule};
+use aoc_parse::{lines, Result, Span};
+use aoc_simulation::{linear_drift, Simulation};
+
+fn pot(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn rule_entry(line: Span) -> Result<([bool; 5], bool)> {
+    let (rule, result) = line.split_once(" => ")?;
+    let pots: [bool; 5] = rule
+        .map_chars(pot)?
+        .try_into()
+        .map_err(|_| rule.error("Expected 5 pots"))?;
+    let [plant] = result.map_chars(pot)?[..] else {
+        return Err(result.error("Expected a single pot"));
+    };
+    Ok((pots, plant))
+}
+
+fn read_input(input: &str) -> Result<Line> {
+    let mut lines = lines(input);
+    let first = lines.next().unwrap_or(Span::new("", 1, 1));
+    let initial_state = first.strip_prefix("initial state: ")?;
+
+    let rules = lines.map(rule_entry).collect::<Result<LineRule>>()?;
+    let plants = initial_state.map_chars(pot)?;
+
+    Ok(Line::new(
+        rules,
+        (0..).zip(plants).filter(|(_, plant)| *plant).map(|(i, _)| i),
+    ))
+}
+
+fn count_plants(line: &Line) -> isize {
+    line.alive().sum()
+}
+
+struct Plants(Line);
+
+impl Simulation for Plants {
+    type Fingerprint = Vec<isize>;
+
+    fn step(&mut self) {
+        self.0.step();
+    }
+
+    fn fingerprint(&self) -> Vec<isize> {
+        self.0.pattern()
+    }
+
+    fn metric(&self) -> i64 {
+        count_plants(&self.0) as i64
+    }
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let mut line = read_input(input).unwrap();
+    let generations: isize = 20;
+
+    for _ in 1..=generations {
+        line.step();
+    }
+
+    let result = count_plants(&line);
+    Some(result as u64)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let mut plants = Plants(read_input(input).unwrap());
+
+    // Plants settle into gliders after a while, all moving the same way
+    let drift = linear_drift(&mut plants, 10);
+    Some(drift.metric_at(50_000_000_000) as u64)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 12: Subterranean Sustainability ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_parse::{single_line, Result, Span};
+
+fn read_input(input: &str) -> Result<Span<'_>> {
+    single_line(input)
+}
+
+fn print_scores(scores: &Vec<usize>, elf_1: usize, elf_2: usize) {
+    let score_line = scores
+        .iter()
+        .enumerate()
+        .map(|(index, score)| {
+            if index == elf_1 {
+                format!("({score})")
+            } else if index == elf_2 {
+                format!("[{score}]")
+            } else {
+                format!(" {score} ")
+            }
+        })
+        .collect::<String>();
+
+    println!("{score_line}")
+}
+
+fn set_score(scores: &mut Vec<usize>, elf_1: &mut usize, elf_2: &mut usize) -> Vec<usize> {
+    let score = scores[*elf_1] + scores[*elf_2];
+    let mut added_scores = Vec::new();
+
+    if score >= 10 {
+        let score_1 = score / 10;
+        let score_2 = score % 10;
+        scores.push(score_1);
+        scores.push(score_2);
+
+        added_scores.push(score_1);
+        added_scores.push(score_2);
+    } else {
+        scores.push(score);
+        added_scores.push(score);
+    }
+
+    *elf_1 = (*elf_1 + (scores[*elf_1] + 1)) % scores.len();
+    *elf_2 = (*elf_2 + (scores[*elf_2] + 1)) % scores.len();
+
+    added_scores
+}
+
+fn part1(input: &str) -> Option<String> {
+    const RECIPE_COUNT: usize = 10;
+    let desired_recipes: usize = read_input(input).and_then(|line| line.parse()).unwrap();
+
+    let mut scores = vec![3, 7];
+    let mut elf_1: usize = 0;
+    let mut elf_2: usize = 1;
+
+    while scores.len() < (desired_recipes + RECIPE_COUNT) {
+        set_score(&mut scores, &mut elf_1, &mut elf_2);
+    }
+
+    Some(
+        scores
+            .into_iter()
+            .skip(desired_recipes)
+            .take(RECIPE_COUNT)
+            .map(|score| score.to_string())
+            .collect::<String>(),
+    )
+}
+
+fn part2(input: &str) -> Option<String> {
+    let search_for_recipes = read_input(input).unwrap().as_str();
+
+    let mut scores = vec![3, 7];
+    let mut elf_1: usize = 0;
+    let mut elf_2: usize = 1;
+
+    for _ in 0.. {
+        set_score(&mut scores, &mut elf_1, &mut elf_2);
+
+        if scores.len() < search_for_recipes.len() + 1 {
+            continue;
+        }
+
+        let tail = scores[scores.len() - search_for_recipes.len() - 1..]
+            .iter()
+            .map(|score| score.to_string())
+            .collect::<String>();
+
+        if tail.starts_with(&search_for_recipes) {
+            return Some((scores.len() - search_for_recipes.len() - 1).to_string())
+        }
+        if tail.ends_with(&search_for_recipes) {
+            return Some((scores.len() - search_for_recipes.len()).to_string())
+        }
+    }
+
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 14: Chocolate Charts ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,605 @@
+use std::{cmp::Ordering, fmt::Display, usize};
+
+use aoc_geometry::{Vector2, DIRECTIONS};
+use aoc_graph::{dijkstra, Edge, Graph};
+use aoc_parse::grid_with_position;
+
+type UnitId = usize;
+
+#[derive(Debug)]
+enum ActionResult {
+    NoEnemies(Vec<UnitVariant>),
+    NoPath,
+    Death(Vec<UnitVariant>),
+    Nothing,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum UnitVariant {
+    Elf,
+    Goblin,
+}
+
+impl Display for UnitVariant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Elf => write!(f, "E"),
+            Self::Goblin => write!(f, "G"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Unit {
+    unit_id: UnitId,
+    variant: UnitVariant,
+    hp: isize,
+    ap: isize,
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.variant {
+            UnitVariant::Goblin => write!(f, "G"),
+            UnitVariant::Elf => write!(f, "E"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum TileType {
+    Wall,
+    Air,
+    Unit(Unit),
+}
+
+#[derive(Debug, Clone)]
+struct Tile {
+    tile_type: TileType,
+    position: Vector2,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.tile_type {
+            TileType::Air => write!(f, " "),
+            TileType::Wall => write!(f, "🪨"),
+            TileType::Unit(unit) => write!(f, "{}", unit),
+        }
+    }
+}
+
+impl PartialEq for Tile {
+    fn eq(&self, other: &Self) -> bool {
+        self.tile_type == other.tile_type && self.position == other.position
+    }
+}
+
+impl Eq for Tile {}
+
+struct Cave {
+    origin: (Vec<Vec<Tile>>, Vec<usize>),
+    map: Vec<Vec<Tile>>,
+    units: Vec<UnitId>,
+    graph: Graph<Vector2>,
+}
+
+impl Cave {
+    fn new(map: Vec<Vec<Tile>>, units: Vec<UnitId>, graph: Graph<Vector2>) -> Self {
+        let origin: (Vec<Vec<Tile>>, Vec<UnitId>) = (map.clone(), units.clone());
+        Self {
+            map,
+            units,
+            graph,
+            origin,
+        }
+    }
+
+    fn get_unit_tile(&self, unit_id: &UnitId) -> Option<&Tile> {
+        for row in self.map.iter() {
+            let tile = row.iter().find(|&tile| match tile.tile_type {
+                TileType::Unit(unit) => &unit.unit_id == unit_id,
+                _ => false,
+            });
+            if tile.is_some() {
+                return tile;
+            }
+        }
+        None
+    }
+
+    fn get_unit_tile_mut(&mut self, unit_id: &UnitId) -> Option<&mut Tile> {
+        for row in self.map.iter_mut() {
+            let tile = row.iter_mut().find(|tile| match tile.tile_type {
+                TileType::Unit(unit) => &unit.unit_id == unit_id,
+                _ => false,
+            });
+            if tile.is_some() {
+                return tile;
+            }
+        }
+        None
+    }
+
+    fn get_tile(&self, position: &Vector2) -> Option<&Tile> {
+        if let Some(row) = self.map.get(position.1 as usize) {
+            row.get(position.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    fn get_tile_mut(&mut self, position: &Vector2) -> Option<&mut Tile> {
+        if let Some(row) = self.map.get_mut(position.1 as usize) {
+            row.get_mut(position.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    fn get_neighbor_positions(&self, position: &Vector2) -> Vec<Vector2> {
+        match self.graph.get_id(position) {
+            Some(node) => self
+                .graph
+                .neighbors(node)
+                .map(|neighbor| *self.graph.get_node(neighbor))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn get_neighbor_tiles(&self, position: &Vector2) -> Vec<&Tile> {
+        self.get_neighbor_positions(position)
+            .iter()
+            .map(|neighbor| self.get_tile(neighbor).unwrap())
+            .collect()
+    }
+
+    fn reset(&mut self) {
+        self.map = self.origin.0.clone();
+        self.units = self.origin.1.clone();
+    }
+
+    fn set_unit_variant_ap(&mut self, variant: UnitVariant, ap: isize) {
+        for unit_index in 0..self.units.len() {
+            let unit_id = self.units[unit_index].clone();
+            let tile = self.get_unit_tile_mut(&unit_id).unwrap();
+            match tile.tile_type {
+                TileType::Unit(ref mut unit) if unit.variant == variant => {
+                    unit.ap = ap;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.map.iter() {
+            for tile in row.iter() {
+                write!(f, "{}", tile)?;
+            }
+            let unit_row = row
+                .iter()
+                .filter_map(|tile| match tile.tile_type {
+                    TileType::Unit(unit) => Some(format!("{}({})", unit.variant, unit.hp)),
+                    _ => None,
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+            write!(f, "   {unit_row}\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn game_round(cave: &mut Cave) -> Result<ActionResult, ActionResult> {
+    let mut units = cave.units.clone();
+    units.sort_by(
+        |a_id, b_id| match (cave.get_unit_tile(a_id), cave.get_unit_tile(b_id)) {
+            (
+                Some(Tile {
+                    tile_type: TileType::Unit(_),
+                    position: a,
+                }),
+                Some(Tile {
+                    tile_type: TileType::Unit(_),
+                    position: b,
+                }),
+            ) => {
+                a.cmp(b)
+            },
+            _ => Ordering::Equal
+        },
+    );
+
+    let mut killed_unit_types = Vec::new();
+
+    for unit_index in units.iter() {
+        if cave.get_unit_tile(unit_index).is_none() {
+            continue;
+        }
+
+        let has_enemies = cave.units.iter().any(|other| {
+            match (&cave.get_unit_tile(unit_index), &cave.get_unit_tile(other)) {
+                (
+                    Some(Tile {
+                        tile_type: TileType::Unit(this_unit),
+                        position: _,
+                    }),
+                    Some(Tile {
+                        tile_type: TileType::Unit(other_unit),
+                        position: _,
+                    }),
+                ) if this_unit.variant != other_unit.variant => true,
+                _ => false,
+            }
+        });
+
+        if !has_enemies || cave.units.len() == 1 {
+            return Err(ActionResult::NoEnemies(killed_unit_types));
+        }
+
+        if let Some(target_id) = get_enemy_target(unit_index, cave) {
+            if unit_attack(unit_index, &target_id, cave).is_err() {
+                let tile = cave.get_unit_tile_mut(&target_id).unwrap();
+                match tile.tile_type {
+                    TileType::Unit(unit) => {
+                        killed_unit_types.push(unit.variant);
+                        tile.tile_type = TileType::Air;
+                    }
+                    _ => {}
+                }
+            }
+        } else {
+            match unit_step(unit_index, cave) {
+                Err(ActionResult::NoPath) => continue,
+                Err(ActionResult::NoEnemies(_)) => {
+                    return Err(ActionResult::NoEnemies(killed_unit_types))
+                }
+                _ => {}
+            };
+            if let Some(target_id) = get_enemy_target(unit_index, cave) {
+                if unit_attack(unit_index, &target_id, cave).is_err() {
+                    let tile = cave.get_unit_tile_mut(&target_id).unwrap();
+                    match tile.tile_type {
+                        TileType::Unit(unit) => {
+                            killed_unit_types.push(unit.variant);
+                            tile.tile_type = TileType::Air;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    cave.units = cave
+        .units
+        .iter()
+        .filter_map(|unit_id| {
+            if cave.get_unit_tile(unit_id).is_some() {
+                Some(unit_id.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+    if killed_unit_types.len() > 0 {
+        Ok(ActionResult::Death(killed_unit_types))
+    } else {
+        Ok(ActionResult::Nothing)
+    }
+}
+
+fn unit_attack(unit_id: &UnitId, target_id: &UnitId, cave: &mut Cave) -> Result<(), ()> {
+    let unit_tile = cave.get_unit_tile(unit_id).unwrap();
+    let unit_ap = match unit_tile.tile_type {
+        TileType::Unit(unit) => unit.ap,
+        _ => panic!("Tried to attack with non unit type"),
+    };
+
+    let target_tile = cave.get_unit_tile_mut(target_id).unwrap();
+    match target_tile.tile_type {
+        TileType::Unit(ref mut target_unit) => {
+            target_unit.hp -= unit_ap;
+            if target_unit.hp <= 0 {
+                Err(())
+            } else {
+                Ok(())
+            }
+        }
+        _ => panic!("Tried to attack non unit type"),
+    }
+}
+
+fn unit_step(unit_id: &UnitId, cave: &mut Cave) -> Result<(), ActionResult> {
+    let to_position = {
+        let unit_tile = cave.get_unit_tile(unit_id).unwrap();
+
+        let considered_positions = cave
+            .get_neighbor_positions(&unit_tile.position)
+            .into_iter()
+            .filter(|neighbor| cave.get_tile(neighbor).unwrap().tile_type == TileType::Air)
+            .collect::<Vec<Vector2>>();
+
+        if considered_positions.len() == 0 {
+            return Err(ActionResult::NoPath);
+        }
+
+        let destination_positions = cave
+            .units
+            .iter()
+            .filter_map(|other_id| {
+                if unit_id == other_id {
+                    return None;
+                }
+                if let Some(other_tile) = cave.get_unit_tile(other_id) {
+                    match (&unit_tile.tile_type, &other_tile.tile_type) {
+                        (TileType::Unit(this_unit), TileType::Unit(other_unit))
+                            if this_unit.variant != other_unit.variant =>
+                        {
+                            // Check if we can walk to enemy
+                            if cave
+                                .get_neighbor_positions(&other_tile.position)
+                                .iter()
+                                .any(|neighbor| {
+                                    cave.get_tile(neighbor).unwrap().tile_type == TileType::Air
+                                })
+                            {
+                                Some(other_tile.position)
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    }
+                } else {
+                    None
+                }
+            })
+            .flat_map(|enemy_position| {
+                cave.get_neighbor_positions(&enemy_position)
+                    .into_iter()
+                    .filter(|neighbor| cave.get_tile(neighbor).unwrap().tile_type == TileType::Air)
+                    .collect::<Vec<Vector2>>()
+            })
+            .collect::<Vec<Vector2>>();
+
+        if destination_positions.len() == 0 {
+            return Err(ActionResult::NoPath);
+        }
+
+        let mut best_path_len = usize::MAX;
+        let mut best_paths = Vec::default();
+
+        let is_edge_walkable = |edge: &Edge| {
+            let destination = cave.graph.get_node(edge.destination);
+            match cave.get_tile(destination).unwrap().tile_type {
+                TileType::Wall => false,
+                TileType::Unit(_) => false,
+                _ => true,
+            }
+        };
+
+        for considered_position in considered_positions {
+            for destination_position in destination_positions.iter() {
+                if let Some(best_path) = dijkstra::get_path(
+                    &cave.graph,
+                    cave.graph.get_id(&considered_position).unwrap(),
+                    cave.graph.get_id(destination_position).unwrap(),
+                    is_edge_walkable,
+                ) {
+                    if best_path.len() > best_path_len {
+                        continue;
+                    }
+                    if best_path.len() < best_path_len {
+                        best_path_len = best_path.len();
+                        best_paths.clear();
+                    }
+                    best_paths.push((considered_position, destination_position.clone()));
+                } else {
+                    continue;
+                }
+            }
+        }
+
+        if best_paths.len() == 0 {
+            return Err(ActionResult::NoPath);
+        }
+
+        best_paths.sort_by(|a, b| {
+            let destination_cmp = a.1.cmp(&b.1);
+            if matches!(destination_cmp, Ordering::Equal) {
+                a.0.cmp(&b.0)
+            } else {
+                destination_cmp
+            }
+        });
+
+        best_paths[0].0
+    };
+
+    let unit_tile_clone = cave.get_unit_tile(unit_id).unwrap().clone();
+
+    cave.get_tile_mut(&to_position).unwrap().tile_type = unit_tile_clone.tile_type;
+    cave.get_tile_mut(&unit_tile_clone.position)
+        .unwrap()
+        .tile_type = TileType::Air;
+
+    Ok(())
+}
+
+fn get_enemy_target(unit_id: &usize, cave: &Cave) -> Option<UnitId> {
+    let unit_tile = cave.get_unit_tile(unit_id).unwrap();
+    let neighbor_tiles = cave.get_neighbor_tiles(&unit_tile.position);
+    let mut enemy_tiles = neighbor_tiles
+        .iter()
+        .filter_map(
+            |&neighbor_tile| match (&unit_tile.tile_type, &neighbor_tile.tile_type) {
+                (TileType::Unit(unit), TileType::Unit(neighbor_unit))
+                    if unit.variant != neighbor_unit.variant =>
+                {
+                    Some((neighbor_tile.position, neighbor_unit))
+                }
+                _ => None,
+            },
+        )
+        .collect::<Vec<(Vector2, &Unit)>>();
+
+    enemy_tiles.sort_by(|(a_position, a_unit), (b_position, b_unit)| {
+        let hp = a_unit.hp.cmp(&b_unit.hp);
+        if matches!(hp, Ordering::Equal) {
+            a_position.cmp(b_position)
+        } else {
+            hp
+        }
+    });
+
+    if let Some(&(_, enemy)) = enemy_tiles.first() {
+        Some(enemy.unit_id)
+    } else {
+        None
+    }
+}
+
+fn calc_score(round: usize, cave: &Cave) -> usize {
+    let unit_sum = cave
+        .units
+        .iter()
+        .filter_map(|unit_id| match cave.get_unit_tile(unit_id) {
+            Some(Tile {
+                tile_type: TileType::Unit(unit),
+                position: _,
+            }) => Some(unit.hp as usize),
+            _ => None,
+        })
+        .sum::<usize>();
+
+    unit_sum * round
+}
+
+fn read_input(input: &str) -> aoc_parse::Result<Cave> {
+    let mut units = Vec::new();
+    let mut graph = Graph::new();
+
+    let grid = grid_with_position(input, |position, char| {
+        let tile_type = match char {
+            '#' => TileType::Wall,
+            '.' => TileType::Air,
+            'G' => TileType::Unit(Unit {
+                unit_id: units.len(),
+                variant: UnitVariant::Goblin,
+                hp: 200,
+                ap: 3,
+            }),
+            'E' => TileType::Unit(Unit {
+                unit_id: units.len(),
+                variant: UnitVariant::Elf,
+                hp: 200,
+                ap: 3,
+            }),
+            _ => return None,
+        };
+
+        match tile_type {
+            TileType::Unit(unit) => {
+                units.push(unit.unit_id);
+                graph.add_node(position);
+            }
+            TileType::Air => {
+                graph.add_node(position);
+            }
+            TileType::Wall => {}
+        }
+        Some(Tile {
+            tile_type,
+            position,
+        })
+    })?;
+    let map: Vec<Vec<Tile>> = grid.rows().map(<[Tile]>::to_vec).collect();
+
+    for node in 0..graph.len() {
+        let neighbors = DIRECTIONS.iter().filter_map(|direction| {
+            let position = *graph.get_node(node) + *direction;
+            if let Some(row) = map.get(position.1 as usize) {
+                if let Some(tile) = row.get(position.0 as usize) {
+                    if tile.tile_type != TileType::Wall {
+                        Some(position)
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        });
+
+        for neighbor in neighbors.collect::<Vec<Vector2>>() {
+            let neighbor_node = graph.get_id(&neighbor).unwrap();
+            graph.add_edge(node, neighbor_node, 1);
+        }
+    }
+
+    Ok(Cave::new(map, units, graph))
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut cave = read_input(input).unwrap();
+
+    for round in 1.. {
+        if game_round(&mut cave).is_err() {
+            return Some(calc_score(round - 1, &cave));
+        }
+    }
+    None
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let mut cave = read_input(input).unwrap();
+
+    // Test repo: https://github.com/ShaneMcC/aoc-2018/blob/master/15/tests/
+    for ap in 4..=200 {
+        cave.reset();
+        cave.set_unit_variant_ap(UnitVariant::Elf, ap);
+
+        for round in 1.. {
+            match game_round(&mut cave) {
+                Err(ActionResult::NoEnemies(units))
+                    if units.iter().any(|variant| *variant == UnitVariant::Elf) =>
+                {
+                    break;
+                }
+                Err(_) => {
+                    return Some(calc_score(round - 1, &cave));
+                }
+                Ok(ActionResult::Death(units))
+                    if units.iter().any(|variant| *variant == UnitVariant::Elf) =>
+                {
+                    break;
+                }
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 15: Beverage Bandits ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
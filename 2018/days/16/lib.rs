@@ -0,0 +1,74 @@
+use aoc_elfcode::{infer_op_codes, Instruction, Program, Sample, Vm};
+use aoc_parse::{sections, Result};
+
+type Instructions = [isize; 4];
+
+fn read_input(input: &str) -> Result<(Vec<Sample<4>>, Vec<Instructions>)> {
+    let mut captures: Vec<Sample<4>> = Vec::new();
+    let mut instructions: Vec<Instructions> = Vec::new();
+
+    for section in sections(input) {
+        // Reading instructions
+        if !section.as_str().starts_with("Before:") {
+            for line in section.lines() {
+                instructions.push(line.ints_array()?);
+            }
+            continue;
+        }
+        // Reading captures
+        let lines: Vec<_> = section.lines().collect();
+        let [before, instruction, after] = lines[..] else {
+            return Err(section.error("Expected before, instruction and after lines"));
+        };
+        captures.push(Sample {
+            before: before.strip_prefix("Before:")?.ints_array()?,
+            instruction: instruction.ints_array()?,
+            after: after.strip_prefix("After:")?.ints_array()?,
+        });
+    }
+
+    Ok((captures, instructions))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let (captures, _) = read_input(input).unwrap();
+    let sum = captures
+        .iter()
+        .filter(|sample| sample.candidates().count() >= 3)
+        .count();
+
+    Some(sum as isize)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let (captures, instructions) = read_input(input).unwrap();
+    let code_map = infer_op_codes(&captures)?;
+    let program = Program::new(
+        None,
+        instructions
+            .into_iter()
+            .map(|[code, a, b, c]| Instruction::new(code_map[&code], a, b, c))
+            .collect(),
+    );
+
+    let mut vm: Vm<4> = Vm::new(&program);
+    vm.run();
+
+    Some(vm.registers()[0])
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 16: Chronal Classification ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,229 @@
+
+use hashbrown::{HashMap, HashSet};
+
+use std::{collections::VecDeque, fmt::Display};
+
+use aoc_geometry::Vector2;
+use aoc_parse::{parse_lines, Pattern, Result, Span};
+
+const DOWN: Vector2 = Vector2(0, 1);
+const LEFT: Vector2 = Vector2(-1, 0);
+const RIGHT: Vector2 = Vector2(1, 0);
+
+#[derive(Debug)]
+enum TileType {
+    Wall,
+    Sand,
+    Water,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Wall {
+    start: Vector2,
+    end: Vector2,
+}
+
+impl Wall {
+    fn parse(pattern: &Pattern, line: Span) -> Result<Self> {
+        let captures = pattern.captures(line)?;
+        let value = captures.parse("value")?;
+        let start = captures.parse("start")?;
+        let end = captures.parse("end")?;
+
+        match (captures.get("axis").as_str(), captures.get("range").as_str()) {
+            // Parse floor
+            ("y", "x") => Ok(Self {
+                start: Vector2::new(start, value),
+                end: Vector2::new(end, value),
+            }),
+            ("x", "y") => Ok(Self {
+                start: Vector2::new(value, start),
+                end: Vector2::new(value, end),
+            }),
+            _ => Err(line.error(format!("Expected an x and a y range, got {line}"))),
+        }
+    }
+
+    fn hit(&self, point: &Vector2) -> bool {
+        let hit_x = self.start.0 <= point.0 && point.0 <= self.end.0;
+        let hit_y = self.start.1 <= point.1 && point.1 <= self.end.1;
+        hit_x && hit_y
+    }
+}
+
+#[derive(Debug)]
+struct Reservoir {
+    start: Vector2,
+    end: Vector2,
+    walls: Vec<Wall>,
+    walls_map: HashMap<Vector2, Option<Wall>>,
+    water: HashSet<Vector2>,
+    wet_sand: HashSet<Vector2>,
+}
+
+impl Reservoir {
+    fn new(walls: Vec<Wall>) -> Self {
+        let min_x = walls.iter().map(|wall| wall.start.0).min().unwrap();
+        let min_y = walls.iter().map(|wall| wall.start.1).min().unwrap();
+        let max_x = walls.iter().map(|wall| wall.end.0).max().unwrap();
+        let max_y = walls.iter().map(|wall| wall.end.1).max().unwrap();
+
+        Self {
+            walls,
+            water: HashSet::new(),
+            wet_sand: HashSet::new(),
+            walls_map: HashMap::new(),
+            start: Vector2::new(min_x, min_y),
+            end: Vector2::new(max_x, max_y),
+        }
+    }
+
+    fn drop_water(&mut self, spring: &Vector2) -> bool {
+        let mut visit = VecDeque::from([*spring]);
+        let mut visited = HashSet::new();
+
+        let mut placed_water = false;
+
+        while let Some(source) = visit.pop_front() {
+            visited.insert(source.clone());
+            if source.1 > self.end.1 {
+                continue;
+            }
+
+            let down_point = source + DOWN;
+            let source_tile = self.get_tile_type(&source);
+            let down_tile = self.get_tile_type(&down_point);
+
+            match (source_tile, down_tile) {
+                (TileType::Sand, TileType::Sand) => {
+                    // Drop
+                    visit.push_back(down_point);
+                    self.wet_sand.insert(source);
+                }
+                (TileType::Sand, TileType::Wall | TileType::Water) => {
+                    let is_contained =
+                        self.hits_wall(&source, &LEFT) && self.hits_wall(&source, &RIGHT);
+                    if is_contained {
+                        placed_water = true;
+                        self.water.insert(source);
+                        self.wet_sand.remove(&source);
+                    } else {
+                        self.wet_sand.insert(source);
+                    }
+
+                    let left = source + LEFT;
+                    if !visited.contains(&left) {
+                        visit.push_back(left);
+                    }
+                    let right = source + RIGHT;
+                    if !visited.contains(&right) {
+                        visit.push_back(right);
+                    }
+                }
+                _ => {}
+            }
+        }
+        placed_water
+    }
+
+    fn hits_wall(&mut self, point: &Vector2, direction: &Vector2) -> bool {
+        match (
+            self.get_tile_type(point),
+            self.get_tile_type(&(*point + DOWN)),
+        ) {
+            (TileType::Sand, TileType::Sand) => false,
+            (TileType::Wall, TileType::Wall) => true,
+            _ => self.hits_wall(&(*point + *direction), direction),
+        }
+    }
+
+    fn get_tile_type(&mut self, point: &Vector2) -> TileType {
+        let hit_wall = self
+            .walls_map
+            .entry(*point)
+            .or_insert(self.walls.iter().find(|&wall| wall.hit(point)).copied());
+
+        let hit_water = self.water.contains(point);
+
+        if hit_wall.is_some() {
+            TileType::Wall
+        } else if hit_water {
+            TileType::Water
+        } else {
+            TileType::Sand
+        }
+    }
+}
+
+impl Display for Reservoir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in (self.start.1 - 1)..=(self.end.1 + 1) {
+            for x in (self.start.0 - 1)..=(self.end.0 + 1) {
+                let point = Vector2::new(x, y);
+
+                if self.walls.iter().any(|wall| wall.hit(&point)) {
+                    write!(f, "#")?;
+                } else if self.wet_sand.contains(&point) {
+                    write!(f, "|")?;
+                } else if self.water.contains(&point) {
+                    write!(f, "~")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            write!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn read_input(input: &str) -> Result<Reservoir> {
+    let pattern = Pattern::new("<axis>=<value>, <range>=<start>..<end>");
+    let walls = parse_lines(input, |line| Wall::parse(&pattern, line))?;
+
+    Ok(Reservoir::new(walls))
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut reservoir = read_input(input).unwrap();
+    let spring = Vector2::new(500, 0);
+
+    while reservoir.drop_water(&spring) {}
+
+    let mut points: HashSet<&Vector2> = reservoir.water.union(&reservoir.wet_sand).collect();
+    points = points
+        .into_iter()
+        .filter(|point| !(point.1 > reservoir.end.1 || point.1 < reservoir.start.1))
+        .collect();
+    Some(points.len())
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let mut reservoir = read_input(input).unwrap();
+    let spring = Vector2::new(500, 0);
+
+    while reservoir.drop_water(&spring) {}
+
+    let points: HashSet<Vector2> = reservoir.water
+        .into_iter()
+        .filter(|point| !(point.1 > reservoir.end.1 || point.1 < reservoir.start.1))
+        .collect();
+
+    Some(points.len())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 17: Reservoir Research ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
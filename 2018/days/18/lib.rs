@@ -0,0 +1,125 @@
+use std::{f32::MIN, fmt::Display};
+
+use aoc_automaton::{Neighbours, Plane};
+use aoc_geometry::Grid;
+use aoc_parse::{grid, Result};
+use aoc_simulation::{metric_at, Simulation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Acre {
+    Open,
+    Trees,
+    Lumberyard,
+}
+
+type Area = Grid<Acre>;
+
+impl Acre {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Open),
+            '|' => Some(Self::Trees),
+            '#' => Some(Self::Lumberyard),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Acre {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Acre::Open => write!(f, "."),
+            Acre::Trees => write!(f, "|"),
+            Acre::Lumberyard => write!(f, "#"),
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Area> {
+    grid(input, Acre::from_char)
+}
+
+fn print_area(area: &Area) {
+    print!("\n{area}");
+}
+
+fn step(acre: &Acre, adjacent: Neighbours<Acre>) -> Acre {
+    match acre {
+        Acre::Open if adjacent.count(|acre| *acre == Acre::Trees) >= 3 => Acre::Trees,
+        Acre::Trees if adjacent.count(|acre| *acre == Acre::Lumberyard) >= 3 => Acre::Lumberyard,
+        Acre::Lumberyard
+            if !adjacent.any(|acre| *acre == Acre::Lumberyard)
+                || !adjacent.any(|acre| *acre == Acre::Trees) =>
+        {
+            Acre::Open
+        }
+        acre => *acre,
+    }
+}
+
+fn calculate_score(area: &Area) -> usize {
+    let mut trees = 0;
+    let mut lumberyards = 0;
+
+    for acre in area.tiles() {
+        match acre {
+            Acre::Lumberyard => lumberyards += 1,
+            Acre::Trees => trees += 1,
+            Acre::Open => {}
+        }
+    }
+    trees * lumberyards
+}
+
+#[derive(Clone)]
+struct Settlement(Plane<Acre>);
+
+impl Simulation for Settlement {
+    type Fingerprint = Area;
+
+    fn step(&mut self) {
+        self.0.step(step);
+    }
+
+    fn fingerprint(&self) -> Area {
+        self.0.grid().clone()
+    }
+
+    fn metric(&self) -> i64 {
+        calculate_score(self.0.grid()) as i64
+    }
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut area = Plane::new(read_input(input).unwrap());
+    const MINUTES: usize = 10;
+    for _ in 1..=MINUTES {
+        area.step(step);
+    }
+
+    Some(calculate_score(area.grid()))
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let area = read_input(input).unwrap();
+    const MINUTES: usize = 1_000_000_000;
+
+    let settlement = Settlement(Plane::new(area));
+    Some(metric_at(&settlement, MINUTES) as usize)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 18: Settlers of The North Pole ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
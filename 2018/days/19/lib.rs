@@ -0,0 +1,66 @@
+use aoc_elfcode::{Program, Vm};
+
+fn read_input(input: &str) -> Program {
+    input.parse().unwrap()
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let program = read_input(input);
+    let mut vm: Vm<6> = Vm::new(&program);
+    vm.run();
+
+    Some(vm.registers()[0])
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let program = read_input(input);
+    let mut vm: Vm<6> = Vm::new(&program).with_registers([1, 0, 0, 0, 0, 0]);
+
+    // De-compiled version of the main loop (after initial setup to get the magical value)
+    // let r3 = 10551288;
+    // let mut r0 = 0;
+    // let mut r4 = 1;
+    // loop {
+    //     let mut r1 = 1;
+
+    //     loop {
+    //         if r4 * r1 == r3 {
+    //             r0 += r4
+    //         }
+    //         r1 += 1;
+    //         if r1 > r3 {
+    //             break;
+    //         }
+    //     }
+    //     r4 += 1;
+    //     if r4 > r3 {
+    //         break;
+    //     }
+    // }
+    // Can be reduced to, (the sum of all devisors for r3). The setup is done
+    // once it jumps back to 1, the magical value being the biggest register
+    vm.add_fast_forward(1, |register| {
+        let r3 = *register.iter().max().unwrap();
+        register[0] = (1..=r3).filter(|r4| r3 % r4 == 0).sum();
+        Some(usize::MAX)
+    });
+    vm.run();
+
+    Some(vm.registers()[0])
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 19: Go With The Flow ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+
+use aoc_geometry::{Vector2, DOWN, LEFT, RIGHT, UP};
+use aoc_graph::{bfs, Edge, Graph, NodeId};
+use aoc_parse::{single_line, Result};
+use hashbrown::HashSet;
+
+fn parse_regex(
+    routes: &mut VecDeque<char>,
+    from_node: NodeId,
+    graph: &mut Graph<Vector2>,
+) -> NodeId {
+    let mut source_node = from_node;
+    while let Some(route) = routes.pop_front() {
+        match route {
+            'N' | 'S' | 'E' | 'W' => {
+                let source_point = *graph.get_node(source_node);
+                let destination_point = match route {
+                    'N' => source_point + UP,
+                    'S' => source_point + DOWN,
+                    'E' => source_point + RIGHT,
+                    'W' => source_point + LEFT,
+                    _ => panic!("Not gonna happen"),
+                };
+
+                let destination_node = graph.add_node(destination_point);
+                graph.add_undirected_edge(destination_node, source_node, 1);
+
+                source_node = destination_node;
+            }
+            '^' => {
+                source_node = parse_regex(routes, source_node, graph);
+            }
+            '(' => loop {
+                parse_regex(routes, source_node, graph);
+
+                match routes.pop_front() {
+                    Some(')') => break,
+                    Some('|') => continue,
+                    _ => panic!("Unknown!"),
+                }
+            },
+            '|' => {
+                routes.push_front('|');
+                return source_node;
+            }
+            ')' => {
+                routes.push_front(')');
+                return source_node;
+            }
+            '$' => return source_node,
+            _ => {
+                todo!("Route not implemented {route}")
+            }
+        };
+    }
+    source_node
+}
+
+fn read_input(input: &str) -> Result<(Graph<Vector2>, NodeId, NodeId)> {
+    let mut graph = Graph::new();
+
+    let point = Vector2(0, 0);
+    let mut routes = single_line(input)?.as_str().chars().collect::<VecDeque<char>>();
+    let start_node = graph.add_node(point);
+
+    let end_node = parse_regex(&mut routes, start_node, &mut graph);
+
+    Ok((graph, start_node, end_node))
+}
+
+fn get_point_edges<'a>(graph: &'a Graph<Vector2>, point: &Vector2) -> Option<&'a [Edge]> {
+    graph.get_id(point).map(|node| graph.get_edges(node))
+}
+
+fn print_map(graph: &Graph<Vector2>, start: Vector2, with_path: &Vec<Vector2>, with_unknown: bool) {
+    let nodes: HashSet<Vector2> = graph.nodes().map(|(_, point)| *point).collect();
+
+    let x_start = nodes.iter().map(|vector| vector.0).min().unwrap();
+    let x_end = nodes.iter().map(|vector| vector.0).max().unwrap();
+    let y_start = nodes.iter().map(|vector| vector.1).min().unwrap();
+    let y_end = nodes.iter().map(|vector| vector.1).max().unwrap();
+
+    for y in y_start..=y_end {
+        for x in x_start..=x_end {
+            let point = Vector2(x, y);
+
+            if !nodes.contains(&point) {
+                print!("  ");
+                continue;
+            }
+
+            print!("#");
+
+            let point_up = point + UP;
+            match get_point_edges(graph, &point) {
+                Some(edges) => {
+                    if edges.iter().any(|edge| *graph.get_node(edge.destination) == point_up) {
+                        print!("-");
+                    } else if with_unknown {
+                        print!("?");
+                    } else {
+                        print!("#");
+                    }
+                }
+                _ => {
+                    if with_unknown {
+                        print!("?");
+                    } else {
+                        print!("#");
+                    }
+                }
+            }
+        }
+        print!("#");
+        print!("\n");
+        let point = Vector2(x_start, y);
+        if !nodes.contains(&point) {
+            print!(" ");
+        } else {
+            let left_point = point + LEFT;
+            match get_point_edges(graph, &left_point) {
+                Some(edges) => {
+                    if edges
+                        .iter()
+                        .any(|edge| *graph.get_node(edge.destination) == left_point)
+                    {
+                        print!("|");
+                    } else if with_unknown {
+                        print!("?");
+                    } else {
+                        print!("#");
+                    }
+                }
+                _ => {
+                    if with_unknown {
+                        print!("?");
+                    } else {
+                        print!("#");
+                    }
+                }
+            }
+        }
+
+        for x in x_start..=x_end {
+            let point = Vector2(x, y);
+
+            if !nodes.contains(&point) {
+                print!("  ");
+                continue;
+            }
+            if point == start {
+                print!("\x1b[1;42mS\x1b[0;0m");
+            } else if with_path.contains(&point) {
+                print!("\x1b[1;42m \x1b[0;0m");
+            } else {
+                print!(".");
+            }
+
+            let point_right = point + RIGHT;
+            match get_point_edges(graph, &point) {
+                Some(edges) => {
+                    if edges
+                        .iter()
+                        .any(|edge| *graph.get_node(edge.destination) == point_right)
+                    {
+                        print!("|");
+                    } else if with_unknown {
+                        print!("?");
+                    } else {
+                        print!("#");
+                    }
+                }
+                _ => {
+                    if with_unknown {
+                        print!("?");
+                    } else {
+                        print!("#");
+                    }
+                }
+            }
+        }
+        print!("\n");
+    }
+    for _ in x_start..=x_end {
+        print!("##");
+    }
+    print!("#\n");
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let (graph, start_node, _) = read_input(input).unwrap();
+    
+    let mut best_corner = Vector2(0, 0);
+    let mut best_path = Vec::new();
+    bfs::find_all_paths(&graph, start_node, |node, path| {
+        if best_path.len() < path.len() {
+            best_path = path.iter().map(|&node| *graph.get_node(node)).collect();
+            best_corner = *graph.get_node(node);
+        }
+    });
+
+    Some(best_path.len() - 1)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let (graph, start_node, _) = read_input(input).unwrap();
+    
+    let mut rooms = HashSet::new();
+    bfs::find_all_paths(&graph, start_node, |node, path| {
+        if (path.len() - 1) >= 1000 {
+            rooms.insert(node);
+        }
+    });
+
+    Some(rooms.len())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 20: A Regular Map ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use aoc_elfcode::{Program, Stop, Vm};
+
+fn read_input(input: &str) -> Program {
+    input.parse().unwrap()
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let program = read_input(input);
+    let mut vm: Vm<6> = Vm::new(&program);
+    // Ad int 16 r5 contains the result
+    vm.add_breakpoint(16);
+
+    match vm.run() {
+        Stop::Breakpoint(_) => Some(vm.registers()[5]),
+        _ => None,
+    }
+
+    // println!("{}", program.listing().to_pseudo_code());
+
+    // decompiled version:
+    // let mut r0: isize = 0;
+    // let mut r1: isize = 0;
+    // let mut r2: isize = 0;
+    // let mut r3: isize = 0;
+    // let mut r5: isize = 123;
+    // loop {
+    //     r5 = r5 & 456;
+    //     if r5 == 72 {
+    //       break;
+    //     }
+    // }
+
+    // r5 = 0;
+    // 'outer: loop {
+    //     r3 = r5 | 65536;
+    //     r5 = 10828530;
+
+    //     loop {
+    //         r2 = r3 & 255;
+    //         r5 = r5 + r2;
+    //         r5 = r5 & 16777215;
+    //         r5 = r5 * 65899;
+    //         r5 = r5 & 16777215;
+
+    //         if 256 > r3 {
+    //             if r5 == r0 {
+    //                  break 'outer;
+    //             }
+    //             break;
+    //         }
+
+    //         r2 = 0;
+    //         loop {
+    //             r1 = r2 + 1;
+    //             r1 = r1 * 256;
+
+    //             if r1 > r3 {
+    //                 r3 = r2;
+    //                 break;
+    //             } else {
+    //                 r2 += 1;
+    //             }
+    //         }
+    //     }
+    // }
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let program = read_input(input);
+    let mut vm: Vm<6> = Vm::new(&program);
+    vm.add_breakpoint(16);
+    // The profile report shows the hot loop at 18 counting r2 up until
+    // (r2 + 1) * 256 > r3, leaving r3 = r2 at 26
+    vm.add_fast_forward(18, |register| {
+        register[2] = register[3] / 256;
+        Some(26)
+    });
+
+    let mut prev_value = 0;
+    let mut mem = HashSet::new();
+
+    while let Stop::Breakpoint(_) = vm.run() {
+        if !mem.insert(vm.registers()[5]) {
+            return Some(prev_value);
+        }
+        prev_value = vm.registers()[5];
+    }
+
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 21: Chronal Conversion ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
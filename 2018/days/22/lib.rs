@@ -0,0 +1,201 @@
+
+use std::collections::HashMap;
+
+use aoc_geometry::{Vector2, DOWN, LEFT, RIGHT, UP};
+use aoc_graph::astar;
+use aoc_parse::{lines, Error, Result};
+
+type Gear = [bool; 3];
+
+const TORCH_INDEX: usize = 0;
+const CLIMBING_GEAR_INDEX: usize = 1;
+const NEITHER_INDEX: usize = 2;
+
+const TORCH_GEAR: Gear = [true, false, false];
+
+const CLIMBING_GEAR: Gear = [false, true, false];
+
+const NEITHER_GEAR: Gear = [false, false, true];
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Rock,
+    Wet,
+    Narrow,
+}
+
+impl Tile {
+    fn from_risk_level(risk_level: isize) -> Self {
+        match risk_level {
+            0 => Self::Rock,
+            1 => Self::Wet,
+            2 => Self::Narrow,
+            _ => panic!("Unknown tile type {risk_level}"),
+        }
+    }
+
+    fn allows(&self, gear: &Gear) -> bool {
+        match self {
+            Self::Rock => gear[TORCH_INDEX] || gear[CLIMBING_GEAR_INDEX],
+            Self::Wet => gear[CLIMBING_GEAR_INDEX] || gear[NEITHER_INDEX],
+            Self::Narrow => gear[TORCH_INDEX] || gear[NEITHER_INDEX],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cave {
+    depth: isize,
+    target: Vector2,
+    erosion_levels: HashMap<Vector2, isize>,
+    map: HashMap<Vector2, Tile>,
+}
+
+impl Cave {
+    fn new(target: Vector2, depth: isize) -> Self {
+        Cave {
+            depth,
+            target,
+            erosion_levels: HashMap::new(),
+            map: HashMap::new(),
+        }
+    }
+
+    fn get_erosion_level(&mut self, point: &Vector2) -> isize {
+        if let Some(erosion_level) = self.erosion_levels.get(point) {
+            *erosion_level
+        } else {
+            let geological_index = self.get_geological_index(point);
+            let erosion_level = (geological_index + self.depth) % 20183;
+
+            self.erosion_levels.insert(*point, erosion_level);
+            erosion_level
+        }
+    }
+
+    fn get_geological_index(&mut self, point: &Vector2) -> isize {
+        if *point == Vector2(0, 0) {
+            0
+        } else if *point == self.target {
+            0
+        } else if point.1 == 0 {
+            point.0 * 16807
+        } else if point.0 == 0 {
+            point.1 * 48271
+        } else {
+            self.get_erosion_level(&(*point + LEFT)) * self.get_erosion_level(&(*point + UP))
+        }
+    }
+
+    fn get_risk_level(&mut self, point: &Vector2) -> isize {
+        self.get_erosion_level(point) % 3
+    }
+
+    fn get_tile(&mut self, point: &Vector2) -> Option<Tile> {
+        if point.0 < 0 || point.1 < 0 {
+            None
+        } else {
+            match self.map.get(point) {
+                Some(tile) => Some(tile.clone()),
+                None => {
+                    let risk_level = self.get_risk_level(point);
+                    let tile = Tile::from_risk_level(risk_level);
+                    self.map.insert(*point, tile);
+
+                    Some(tile)
+                }
+            }
+        }
+    }
+
+    fn get_neighbors(&mut self, point: &Vector2) -> Vec<(Vector2, Tile)> {
+        let up_point = *point + UP;
+        let down_point = *point + DOWN;
+        let left_point = *point + LEFT;
+        let right_point = *point + RIGHT;
+
+        vec![up_point, down_point, left_point, right_point]
+            .into_iter()
+            .filter_map(|direction| match self.get_tile(&direction) {
+                Some(tile) => Some((direction, tile)),
+                None => None,
+            })
+            .collect()
+    }
+}
+
+fn find_path(cave: &mut Cave) -> Option<usize> {
+    let target = cave.target;
+    let successors = |&(point, gear): &(Vector2, Gear)| {
+        let tile = cave.get_tile(&point).unwrap();
+        let switch_gear = [TORCH_GEAR, CLIMBING_GEAR, NEITHER_GEAR]
+            .into_iter()
+            .filter(|other| *other != gear && tile.allows(other))
+            .map(|other| ((point, other), 7));
+
+        let moves = cave
+            .get_neighbors(&point)
+            .into_iter()
+            .filter(|(_, neighbor_tile)| neighbor_tile.allows(&gear))
+            .map(|(neighbor, _)| ((neighbor, gear), 1));
+
+        switch_gear.chain(moves).collect::<Vec<_>>()
+    };
+
+    let (_, minutes) = astar::get_path(
+        [(Vector2(0, 0), TORCH_GEAR)],
+        successors,
+        |(point, _)| point.manhattan_distance(&target) as usize,
+        |&state| state == (target, TORCH_GEAR),
+    )?;
+    Some(minutes)
+}
+
+fn read_input(input: &str) -> Result<Cave> {
+    let mut depth = None;
+    let mut target = None;
+    for line in lines(input) {
+        let (key, value) = line.key_value()?;
+        match key.as_str() {
+            "depth" => depth = Some(value.parse()?),
+            "target" => target = Some(value.ints_array()?),
+            _ => return Err(key.error(format!("Unknown key {key}"))),
+        }
+    }
+
+    let missing = |key| Error::new(1, 1, format!("Missing {key}"));
+    let [x, y] = target.ok_or_else(|| missing("target"))?;
+    Ok(Cave::new(Vector2(x, y), depth.ok_or_else(|| missing("depth"))?))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let mut cave = read_input(input).unwrap();
+    let mut result = 0;
+    for x in 0..=cave.target.0 {
+        for y in 0..=cave.target.1 {
+            result += cave.get_risk_level(&Vector2(x, y))
+        }
+    }
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let mut cave = read_input(input).unwrap();
+    find_path(&mut cave).map(|minutes| minutes as isize)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 22: Mode Maze ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
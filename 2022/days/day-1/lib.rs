@@ -0,0 +1,49 @@
+use aoc_parse::{sections, Result};
+
+
+fn parse_input(input: &str) -> Result<Vec<i32>> {
+    sections(input)
+        .iter()
+        .map(|elf| elf.lines().map(|line| line.parse::<i32>()).sum())
+        .collect()
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let elfs = parse_input(input).unwrap();
+    let mut max = 0;
+    for elf in elfs {
+        if elf > max {
+            max = elf;
+        }
+    }
+
+    Some(max)
+}
+
+fn part2(input: &str) -> Option<i32> {
+    let mut elfs = parse_input(input).unwrap();
+    elfs.sort_by(|a, b| b.partial_cmp(a).unwrap());
+
+    let mut sum = 0;
+    for elf in &elfs[..=2] {
+        sum += elf;
+    }
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 1: Calorie Counting ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
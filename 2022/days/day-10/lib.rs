@@ -0,0 +1,155 @@
+use aoc_parse::{parse_lines, Result, Span};
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+impl Instruction {
+    fn parse(line: Span) -> Result<Instruction> {
+        if line.as_str() == "noop" {
+            return Ok(Instruction::Noop);
+        }
+
+        Ok(Instruction::Add(line.strip_prefix("addx ")?.parse()?))
+    }
+}
+
+#[derive(Debug)]
+struct CPU {
+    register: i32,
+    cycles: i32,
+    instructions: Vec<Instruction>,
+    instruction_pointer: usize,
+    cycles_remaining: i32,
+}
+
+impl CPU {
+    fn new(instructions: Vec<Instruction>) -> CPU {
+        CPU {
+            register: 1,
+            cycles: 0,
+            instruction_pointer: 0,
+            cycles_remaining: 0,
+            instructions,
+        }
+    }
+
+    fn cycle(self: &mut CPU) {
+        self.cycles += 1;
+
+        if self.cycles_remaining == 0 {
+            let instruction = self.instructions[self.instruction_pointer];
+
+            match instruction {
+                Instruction::Noop => {
+                    self.cycles_remaining = 1;
+                }
+                Instruction::Add(_) => {
+                    self.cycles_remaining = 2;
+                }
+            }
+        }
+
+        self.cycles_remaining -= 1;
+        if self.cycles_remaining == 0 {
+            let instruction = self.instructions[self.instruction_pointer];
+            match instruction {
+                Instruction::Noop => {}
+                Instruction::Add(amount) => {
+                    self.register += amount;
+                }
+            }
+
+            self.instruction_pointer = (self.instruction_pointer + 1) % self.instructions.len();
+        }
+    }
+}
+
+struct CRT<'a> {
+    pointer: (usize, usize),
+    screen: [[&'a str; 40]; 6],
+}
+
+impl<'a> CRT<'a> {
+    fn new() -> CRT<'a> {
+        CRT {
+            pointer: (0, 0),
+            screen: [[" "; 40]; 6],
+        }
+    }
+
+    fn cycle(self: &mut CRT<'a>, cpu: &CPU) {
+        let pixel_value = if self.pointer.0 as i32 >= (cpu.register - 1)
+            && self.pointer.0 as i32 <= (cpu.register + 1)
+        {
+            "🎁"
+        } else {
+            "🎄"
+        };
+        self.screen[self.pointer.1][self.pointer.0] = pixel_value;
+
+        self.pointer.0 = (self.pointer.0 + 1) % 40;
+
+        let y_inc = if self.pointer.0 == 0 { 1 } else { 0 };
+        self.pointer.1 = (self.pointer.1 + y_inc) % 6;
+    }
+
+    fn render(self: &CRT<'a>) -> String {
+        format!("\n{}", self.screen.map(|x| x.join("")).join("\n"))
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
+    parse_lines(input, Instruction::parse)
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let instructions = parse_instructions(input).unwrap();
+    let cpu = &mut CPU::new(instructions);
+
+    let cycle_stops = vec![20, 60, 100, 140, 180, 220];
+    let mut sum = 0;
+
+    for stop in cycle_stops {
+        loop {
+            cpu.cycle();
+            if stop - 1 == cpu.cycles {
+                let strength = cpu.register * stop;
+                sum += strength;
+                break;
+            }
+        }
+    }
+
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<String> {
+    let instructions = parse_instructions(input).unwrap();
+    let cpu = &mut CPU::new(instructions);
+    let crt = &mut CRT::new();
+
+    for _i in 0..240 {
+        crt.cycle(cpu);
+        cpu.cycle();
+    }
+    Some(crt.render())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 10: Cathode-Ray Tube ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+
+use aoc_parse::{sections, Pattern, Result, Span};
+
+#[derive(Debug, Clone, Copy)]
+enum OpSign {
+    Plus,
+    Multiplied,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum OpValue {
+    Num(i128),
+    Old
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Operation(OpSign, OpValue);
+
+impl Operation {
+    fn calculate(self: &Operation, value: i128) -> i128 {
+        match self.1 {
+            OpValue::Num(num) => {
+                match self.0 {
+                    OpSign::Plus => {
+                        value + num
+                    },
+                    OpSign::Multiplied => {
+                        value * num
+                    }
+                }
+            },
+            OpValue::Old => {
+                match self.0 {
+                    OpSign::Plus => {
+                        value + value
+                    },
+                    OpSign::Multiplied => {
+                        (value * value) as i128
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl OpValue {
+    fn parse(span: Span) -> Result<OpValue> {
+        if span.as_str() == "old" {
+            return Ok(OpValue::Old);
+        }
+        Ok(OpValue::Num(span.parse()?))
+    }
+}
+
+impl OpSign {
+    fn parse(span: Span) -> Result<OpSign> {
+        match span.as_str() {
+            "+" => Ok(OpSign::Plus),
+            "*" => Ok(OpSign::Multiplied),
+            sign => Err(span.error(format!("Unknown operation {sign}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<i128>,
+    operation: Operation,
+    test_value: i128,
+    true_monkey: usize,
+    false_monkey: usize,
+    ispections: i128
+}
+
+impl Monkey {
+    fn operate(self: &mut Monkey, relief: i128, cycle_length: i128) -> Vec<(usize, i128)> {
+        let mut result: Vec<(usize, i128)> = vec![];
+
+        while let Some(item) = self.items.pop() {
+            self.ispections += 1;
+            let mut worry = self.operation.calculate(item) / relief;
+            if cycle_length > 0 {
+                worry = worry % cycle_length;
+            }
+
+            if worry % self.test_value == 0 {
+                result.push((self.true_monkey, worry));
+            } else {
+                result.push((self.false_monkey, worry));
+            }
+        }
+
+        result
+    }
+}
+
+fn get_monkey_cycle(monkeys: &Vec<Monkey>) -> i128 {
+    let mut divisors: Vec<i128> = monkeys.iter().map(|monkey| monkey.test_value).collect();
+    divisors.sort();
+    divisors.reverse();
+
+    let mut cycle = 0;
+    let mut initial_pattern = String::new();
+    loop {
+        let mut pattern = String::new();
+        
+        for divisor in divisors.iter() {
+            if cycle % divisor == 0 {
+                pattern.push_str(format!("{}", divisor).as_str());
+            }
+        }
+
+        if cycle == 0 {
+            initial_pattern = pattern.clone();
+        } else {
+            if initial_pattern == pattern {
+                return cycle;
+            }
+        }
+
+        cycle += 1;
+    }
+
+    // for val in 0..=(divisors[0] * 20000) {
+    //     let mut divisibles: Vec<i128> = Vec::new();
+        
+    //     for divisor in divisors.iter() {
+    //         if val % divisor == 0 {
+    //             divisibles.push(*divisor);
+    //         }
+    //     }
+
+    //     divisor_map.insert(val, divisibles);
+    // }
+
+    // let mut lines: Vec<String> = Vec::new();
+    // for val in 0..=(divisors[0] * 20000) {
+    //     lines.push(format!("{:?}", divisor_map[&val]));
+    // }
+    // fs::write("test.txt", lines.join("\n"));
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let patterns = [
+        Pattern::new("Monkey <id>:"),
+        Pattern::new("Starting items: <items>"),
+        Pattern::new("Operation: new = old <sign> <value>"),
+        Pattern::new("Test: divisible by <value>"),
+        Pattern::new("If true: throw to monkey <monkey>"),
+        Pattern::new("If false: throw to monkey <monkey>"),
+    ];
+
+    sections(input)
+        .iter()
+        .map(|section| {
+            let captures = patterns
+                .iter()
+                .zip(section.lines())
+                .map(|(pattern, line)| pattern.captures(line))
+                .collect::<Result<Vec<_>>>()?;
+            let [_, items, operation, test, if_true, if_false] = &captures[..] else {
+                return Err(section.error("Expected six lines per monkey"));
+            };
+
+            Ok(Monkey {
+                items: items.get("items").list(",")?,
+                operation: Operation(
+                    OpSign::parse(operation.get("sign"))?,
+                    OpValue::parse(operation.get("value"))?,
+                ),
+                test_value: test.parse("value")?,
+                true_monkey: if_true.parse("monkey")?,
+                false_monkey: if_false.parse("monkey")?,
+                ispections: 0
+            })
+        })
+        .collect()
+}
+
+fn part1(input: &str) -> Option<i128> {
+    let monkeys = &mut parse_monkeys(input).unwrap();
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let monkey_result = monkeys[i].operate(3, -1);
+            for (monkey_index, item) in monkey_result {
+                monkeys[monkey_index].items.push(item);
+            }
+        }
+    }
+
+    let mut inspections = monkeys.iter().map(|monkey| monkey.ispections).collect::<Vec<i128>>();
+    inspections.sort();
+    inspections.reverse();
+
+    let result = inspections[0] * inspections[1];
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<i128> {
+    let monkeys = &mut parse_monkeys(input).unwrap();
+    let monkey_cycle = get_monkey_cycle(monkeys);
+
+    for _ in 0..10000 {
+        for i in 0..monkeys.len() {
+            let monkey_result = monkeys[i].operate(1, monkey_cycle);
+            for (monkey_index, item) in monkey_result {
+                monkeys[monkey_index].items.push(item);
+            }
+        }
+    }
+
+    let mut inspections = monkeys.iter().map(|monkey| monkey.ispections).collect::<Vec<i128>>();
+    inspections.sort();
+    inspections.reverse();
+
+    let result = inspections[0] * inspections[1];
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 11: Monkey in the Middle ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use aoc_parse::{lines, Result};
+
+type Position = (i32, i32);
+
+#[derive(Debug)]
+enum NodeType {
+    Start,
+    End,
+    Tile(u32),
+}
+
+impl NodeType {
+    fn from_char(c: char) -> Option<NodeType> {
+        match c {
+            'S' => Some(NodeType::Start),
+            'E' => Some(NodeType::End),
+            'a'..='z' => Some(NodeType::Tile(c as u32)),
+            _ => None,
+        }
+    }
+
+    fn elevation(&self) -> u32 {
+        match self {
+            NodeType::Start => 'a' as u32,
+            NodeType::End => 'z' as u32,
+            NodeType::Tile(elevation) => *elevation,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    elevation: u32,
+    node_type: NodeType,
+    position: Position,
+    visited: bool,
+    distance: i64,
+    parent: Option<Position>,
+}
+
+impl Node {
+    fn is_neighbour(self: &Node, position: Position, map: &HeightMap) -> bool {
+        if let Some(neighbour) = map.get(&position) {
+            return neighbour.elevation <= self.elevation + 1;
+        }
+        false
+    }
+}
+
+type HeightMap = HashMap<Position, Node>;
+
+fn parse_map(input: &str) -> Result<HeightMap> {
+    let mut map: HeightMap = HashMap::new();
+
+    for (y, row) in lines(input).enumerate() {
+        for (x, node_type) in row.map_chars(NodeType::from_char)?.into_iter().enumerate() {
+            let position = (x as i32, y as i32);
+            let distance = if matches!(node_type, NodeType::Start) {
+                0
+            } else {
+                i32::MAX as i64
+            };
+            map.insert(
+                position,
+                Node {
+                    elevation: node_type.elevation(),
+                    node_type,
+                    position,
+                    distance,
+                    visited: false,
+                    parent: None,
+                },
+            );
+        }
+    }
+
+    Ok(map)
+}
+
+fn get_neighbours(node_position: Position, map: &HeightMap) -> Vec<Position> {
+    let mut positions = Vec::new();
+    let node = map.get(&node_position).unwrap();
+
+    let up: Position = (node.position.0, node.position.1 - 1);
+    if node.is_neighbour(up, map) {
+        positions.push(up);
+    }
+
+    let down: Position = (node.position.0, node.position.1 + 1);
+    if node.is_neighbour(down, map) {
+        positions.push(down);
+    }
+
+    let left: Position = (node.position.0 - 1, node.position.1);
+    if node.is_neighbour(left, map) {
+        positions.push(left);
+    }
+
+    let right: Position = (node.position.0 + 1, node.position.1);
+    if node.is_neighbour(right, map) {
+        positions.push(right);
+    }
+
+    positions
+}
+
+fn find_path(map: &mut HeightMap, start_node_position: Position) -> Option<Position> {
+    map.values_mut().for_each(|node| {
+        node.distance = if node.position == start_node_position { 0 } else { i32::MAX as i64 };
+        node.visited = false;
+        node.parent = None
+    });
+    
+    let mut unvisited: Vec<Position> = vec![start_node_position];
+
+    while let Some(current_position) = unvisited.pop() {
+        let current_node = map.get(&current_position).unwrap();
+        if current_node.visited {
+            continue;
+        }
+        let current_distance = current_node.distance;
+        let neighbours = get_neighbours(current_position, map);
+
+        for neighbour_position in neighbours {
+            let neighbour_node = map.get_mut(&neighbour_position).unwrap();
+            let neighbour_distance = current_distance + 1;
+
+            if neighbour_node.distance >= neighbour_distance {
+                neighbour_node.distance = neighbour_distance;
+                neighbour_node.parent = Some(current_position);
+            }
+
+            if matches!(neighbour_node.node_type, NodeType::End) {
+                return  Some(neighbour_position);
+            }
+
+            if neighbour_node.visited == false {
+                unvisited.push(neighbour_position);
+            }
+        }
+        map.get_mut(&current_position).unwrap().visited = true;
+        unvisited.sort_by(|a, b| {
+            let a_node = map.get(a).unwrap();
+            let b_node = map.get(b).unwrap();
+
+            return b_node.distance.cmp(&a_node.distance);
+        })
+    }
+    None
+}
+
+fn part1(input: &str) -> Option<i64> {
+    let map = &mut parse_map(input).unwrap();
+    let start_node_position = map
+        .values()
+        .find(|node| matches!(node.node_type, NodeType::Start))
+        .unwrap()
+        .position;
+
+    let end_pos = find_path(map, start_node_position).unwrap();
+    let steps = map.get(&end_pos).unwrap().distance;
+
+    Some(steps)
+}
+
+fn part2(input: &str) -> Option<i64> {
+    let map = &mut parse_map(input).unwrap();
+    let elevation = 'a' as u32;
+
+    let positions: Vec<Position> = map
+        .values()
+        .filter(|node| node.elevation == elevation)
+        .map(|node| node.position).collect();
+
+    let mut best = i32::MAX as i64;
+    for start_node_position in positions {
+        if let Some(end_pos) = find_path(map, start_node_position) {
+            let steps = map.get(&end_pos).unwrap().distance;
+            if steps < best {
+                best = steps;
+            }
+        }
+    }
+
+    Some(best)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 12: Hill Climbing Algorithm ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
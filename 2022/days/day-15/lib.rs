@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc_interval::{Interval, IntervalSet};
+use aoc_parse::{lines, Pattern, Result, Span};
+
+type Coord = (i32, i32);
+
+#[derive(Debug)]
+struct Sensor {
+    position: Coord,
+    beacon_position: Coord,
+    distance: i32,
+}
+
+impl Sensor {
+    fn parse(pattern: &Pattern, line: Span) -> Result<Sensor> {
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = pattern.parse(line)?;
+        let sensor_position = (sensor_x, sensor_y);
+        let beacon_position = (beacon_x, beacon_y);
+
+        let distance = manhattan_distance(sensor_position, beacon_position);
+
+        Ok(Sensor {
+            position: sensor_position,
+            beacon_position,
+            distance,
+        })
+    }
+}
+
+fn manhattan_distance((x1, y1): Coord, (x2, y2): Coord) -> i32 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+fn parse_sensors(input: &str) -> Result<HashMap<Coord, Sensor>> {
+    let pattern = Pattern::new("Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>");
+    lines(input)
+        .map(|line| {
+            let sensor = Sensor::parse(&pattern, line)?;
+            Ok((sensor.position, sensor))
+        })
+        .collect()
+}
+
+/// The x positions on row `y` within range of a sensor.
+fn coverage(y: i32, sensors: &HashMap<Coord, Sensor>) -> IntervalSet<i32> {
+    sensors
+        .values()
+        .map(|sensor| {
+            let reach = sensor.distance - (sensor.position.1 - y).abs();
+            Interval::inclusive(sensor.position.0 - reach, sensor.position.0 + reach)
+        })
+        .collect()
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let sensors = parse_sensors(input).unwrap();
+    let y_pos = 2000000;
+
+    let covered = coverage(y_pos, &sensors);
+    let beacons: HashSet<Coord> = sensors
+        .values()
+        .map(|sensor| sensor.beacon_position)
+        .filter(|(x, y)| *y == y_pos && covered.contains(*x))
+        .collect();
+
+    Some(covered.len() - beacons.len() as i32)
+}
+
+fn part2(input: &str) -> Option<i64> {
+    let sensors = parse_sensors(input).unwrap();
+    let max = 4000000;
+    let area = IntervalSet::from(Interval::inclusive(0, max));
+
+    (0..=max).find_map(|y| {
+        let x = area.difference(&coverage(y, &sensors)).min()?;
+        Some((x as i64 * 4000000) + y as i64)
+    })
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 15: Beacon Exclusion Zone ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_graph::{all_pairs, contract, longest_path, Graph, NodeId};
+use aoc_parse::{parse_lines, Pattern, Result, Span};
+
+#[derive(Debug, Clone)]
+struct Valve {
+    links: Vec<String>,
+    rate: i32,
+    name: String,
+}
+
+impl Valve {
+    fn parse(pattern: &Pattern, line: Span) -> Result<Valve> {
+        let captures = pattern.captures(line)?;
+        let links = captures.get("links");
+        let links = links
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|_| links.strip_prefix("tunnel leads to valve "))?;
+
+        Ok(Valve {
+            links: links.split(", ").map(|link| link.to_string()).collect(),
+            rate: captures.parse("rate")?,
+            name: captures.get("name").to_string(),
+        })
+    }
+}
+
+/// The valves worth opening plus `AA`, each edge being the minutes it takes
+/// to walk to a valve and open it.
+struct Cave {
+    valves: Graph<NodeId, i32>,
+    rates: Vec<i32>,
+    start: NodeId,
+}
+
+fn parse_cave(input: &str) -> Result<Cave> {
+    let pattern = Pattern::new("Valve <name> has flow rate=<rate>; <links>");
+    let valves = parse_lines(input, |line| Valve::parse(&pattern, line))?;
+
+    let mut tunnels: Graph<String, i32> = Graph::new();
+    for valve in valves.iter() {
+        tunnels.add_node(valve.name.clone());
+    }
+    for valve in valves.iter() {
+        let origin = tunnels.get_id(&valve.name).unwrap();
+        for link in valve.links.iter() {
+            let destination = tunnels.get_id(link).unwrap();
+            tunnels.add_edge(origin, destination, 1);
+        }
+    }
+
+    // Zero rate valves are only ever walked through
+    let working = contract(&tunnels, |id, name| name == "AA" || valves[id].rate > 0);
+    let mut routes = Graph::new();
+    for (_, &id) in working.nodes() {
+        routes.add_node(id);
+    }
+    for (origin, row) in all_pairs::get_distances(&working).into_iter().enumerate() {
+        for (destination, distance) in row.into_iter().enumerate() {
+            if let Some(distance) = distance.filter(|_| origin != destination) {
+                routes.add_edge(origin, destination, distance + 1);
+            }
+        }
+    }
+
+    Ok(Cave {
+        rates: working.nodes().map(|(_, &id)| valves[id].rate).collect(),
+        start: working
+            .find_node(|&id| tunnels.get_node(id) == "AA")
+            .unwrap(),
+        valves: routes,
+    })
+}
+
+/// Pressure a valve releases by minute `time` when opened after `minutes`.
+fn pressure(cave: &Cave, time: i32) -> impl Fn(NodeId, i32) -> Option<i32> + '_ {
+    move |valve, minutes| match cave.rates[valve] {
+        0 => None,
+        rate => Some((time - minutes) * rate),
+    }
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let cave = parse_cave(input).unwrap();
+    let ans = longest_path::get_max_reward(&cave.valves, cave.start, 30, pressure(&cave, 30));
+    Some(ans)
+}
+
+fn part2(input: &str) -> Option<i32> {
+    let cave = parse_cave(input).unwrap();
+    let best = longest_path::get_best_rewards(&cave.valves, cave.start, 26, pressure(&cave, 26));
+
+    // The elephant opens a different set of valves at the same time
+    let best: Vec<(u128, i32)> = best.into_iter().collect();
+    let mut ans = 0;
+    for (i, &(mine, my_pressure)) in best.iter().enumerate() {
+        for &(elephants, elephant_pressure) in best[i..].iter() {
+            if mine & elephants == 0 {
+                ans = ans.max(my_pressure + elephant_pressure);
+            }
+        }
+    }
+    Some(ans)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 16: Proboscidea Volcanium ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
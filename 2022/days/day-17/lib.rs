@@ -0,0 +1,340 @@
+use std::fmt::Display;
+
+use aoc_parse::{single_line, Result};
+use aoc_simulation::{metric_at, Simulation};
+
+type Pos = (i32, i32);
+
+const BOARD_WIDTH: usize = 7;
+const ROCK_COUNT: usize = 5;
+const CAVE_CEILING_LIMIT: i32 = 20;
+static ROCK_ORDER: [Rock; ROCK_COUNT] = [
+    Rock::Horizontal,
+    Rock::Plus,
+    Rock::L,
+    Rock::Vertical,
+    Rock::Box,
+];
+
+#[derive(Debug, Copy, Clone)]
+enum Rock {
+    Horizontal,
+    Plus,
+    L,
+    Vertical,
+    Box,
+}
+
+impl Rock {
+    fn get_height(&self) -> i32 {
+        match self {
+            Rock::Horizontal => 1,
+            Rock::Plus => 3,
+            Rock::L => 3,
+            Rock::Vertical => 4,
+            Rock::Box => 2,
+        }
+    }
+
+    fn get_shape_deltas(&self) -> Vec<Pos> {
+        match self {
+            Rock::Horizontal => {
+                vec![(0, 0), (1, 0), (2, 0), (3, 0)]
+            }
+            Rock::Plus => {
+                vec![(1, 0), (0, -1), (1, -1), (2, -1), (1, -2)]
+            }
+            Rock::L => {
+                vec![(2, 0), (2, -1), (0, -2), (1, -2), (2, -2)]
+            }
+            Rock::Vertical => {
+                vec![(0, 0), (0, -1), (0, -2), (0, -3)]
+            }
+            Rock::Box => {
+                vec![(0, 0), (1, 0), (0, -1), (1, -1)]
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Gas {
+    Left,
+    Right,
+    Down,
+}
+
+impl Gas {
+    fn from_char(gas_char: char) -> Option<Self> {
+        match gas_char {
+            '>' => Some(Gas::Right),
+            '<' => Some(Gas::Left),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CaveTile {
+    Air,
+    Rock,
+    Moving,
+}
+
+impl Display for CaveTile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaveTile::Air => write!(f, "."),
+            CaveTile::Rock => write!(f, "#"),
+            CaveTile::Moving => write!(f, "@"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    initialied: bool,
+    rock_pointer: usize,
+    rock_pos: Pos,
+    cave_ceiling: i32,
+    cave: Vec<[CaveTile; BOARD_WIDTH]>,
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let current_rock = ROCK_ORDER[self.rock_pointer];
+        let binding = current_rock.get_shape_deltas();
+
+        let rock_shape: Vec<Pos> = binding
+            .iter()
+            .map(|(x_delta, y_delta)| (self.rock_pos.0 + x_delta, self.rock_pos.1 + y_delta))
+            .collect();
+
+        for (y, row) in self.cave.iter().enumerate().rev() {
+            write!(f, "|").unwrap();
+            for (x, cave_tile) in row.iter().enumerate() {
+                let tile = if rock_shape
+                    .iter()
+                    .any(|(rock_x, rock_y)| *rock_x == (x as i32) && *rock_y == (y as i32))
+                {
+                    &CaveTile::Moving
+                } else {
+                    cave_tile
+                };
+                write!(f, "{}", tile).unwrap();
+            }
+            write!(f, "|\n").unwrap();
+        }
+
+        writeln!(f, "+{:-<width$}+", "-", width = BOARD_WIDTH)
+    }
+}
+
+impl Board {
+    fn new() -> Self {
+        Self {
+            initialied: false,
+            rock_pointer: 0,
+            rock_pos: (0, 0),
+            cave_ceiling: -1,
+            cave: vec![],
+        }
+    }
+
+    fn new_rock(&mut self) {
+        self.rock_pointer = if self.initialied {
+            (self.rock_pointer + 1) % ROCK_COUNT
+        } else {
+            self.initialied = true;
+            0
+        };
+
+        let rock = ROCK_ORDER[self.rock_pointer];
+        self.rock_pos = (2, self.cave_ceiling + rock.get_height() + 3);
+        let additional_height: i32 = (self.rock_pos.1 + 1) - (self.cave.len() as i32);
+
+        for _i in 0..additional_height {
+            self.cave.push([
+                CaveTile::Air,
+                CaveTile::Air,
+                CaveTile::Air,
+                CaveTile::Air,
+                CaveTile::Air,
+                CaveTile::Air,
+                CaveTile::Air,
+            ]);
+        }
+    }
+
+    fn move_rock(&mut self, gas: &Gas) -> bool {
+        let current_rock = ROCK_ORDER[self.rock_pointer];
+        let new_pos = match gas {
+            Gas::Left => {
+                if self.rock_pos.0 > 0 {
+                    (self.rock_pos.0 - 1, self.rock_pos.1)
+                } else {
+                    return false;
+                }
+            }
+            Gas::Right => {
+                if self.rock_pos.0 < (BOARD_WIDTH as i32) - 1 {
+                    (self.rock_pos.0 + 1, self.rock_pos.1)
+                } else {
+                    return false;
+                }
+            }
+            Gas::Down => {
+                if self.rock_pos.1 > 0 {
+                    (self.rock_pos.0, self.rock_pos.1 - 1)
+                } else {
+                    return false;
+                }
+            }
+        };
+        if self.can_move_rock(new_pos, current_rock.get_shape_deltas()) {
+            self.rock_pos = new_pos;
+            return true;
+        }
+        false
+    }
+
+    fn can_move_rock(&self, new_pos: Pos, rock_edges_deltas: Vec<Pos>) -> bool {
+        for (delta_x, delta_y) in rock_edges_deltas {
+            let x = new_pos.0 + delta_x;
+            let y = new_pos.1 + delta_y;
+
+            let row = if let Some(row) = self.cave.get(y as usize) {
+                row
+            } else {
+                return false;
+            };
+            let tile = if let Some(tile) = row.get(x as usize) {
+                tile
+            } else {
+                return false;
+            };
+
+            if matches!(tile, CaveTile::Rock) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn settle_rock(&mut self) {
+        let current_rock = ROCK_ORDER[self.rock_pointer];
+        let rock_shape = current_rock.get_shape_deltas();
+
+        for (x_delta, y_delta) in rock_shape {
+            let x = self.rock_pos.0 + x_delta;
+            let y = self.rock_pos.1 + y_delta;
+
+            if self.cave_ceiling < y {
+                self.cave_ceiling = y;
+            }
+
+            self.cave[y as usize][x as usize] = CaveTile::Rock
+        }
+    }
+
+    fn cave_height(&self) -> i32 {
+        self.cave_ceiling + 1
+    }
+
+    fn cave_state(&self) -> String {
+        let air_pocket = self.cave.len() - self.cave_height() as usize;
+        let top_rows = self.cave.iter().rev().skip(air_pocket).take(CAVE_CEILING_LIMIT as usize);
+
+        let state = top_rows.fold("".to_string(), |acc, row| {
+            format!("{acc}{}", row.map(|tile| tile.to_string()).join(""))
+        });
+
+        state
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Gas>> {
+    single_line(input)?.map_chars(Gas::from_char)
+}
+
+fn drop_rock(board: &mut Board, initial_gas_pointer: usize, gas: &Vec<Gas>) -> usize {
+    let mut gas_pointer = initial_gas_pointer;
+    loop {
+        board.move_rock(&gas[gas_pointer]);
+        gas_pointer = (gas_pointer + 1) % gas.len();
+        
+        if board.move_rock(&Gas::Down) {
+            continue;
+        }
+        board.settle_rock();
+        break;
+    }
+    gas_pointer
+}
+
+#[derive(Clone)]
+struct Tetris<'a> {
+    board: Board,
+    gas: &'a Vec<Gas>,
+    gas_pointer: usize,
+}
+
+impl Simulation for Tetris<'_> {
+    type Fingerprint = (String, usize, usize);
+
+    fn step(&mut self) {
+        self.board.new_rock();
+        self.gas_pointer = drop_rock(&mut self.board, self.gas_pointer, self.gas);
+    }
+
+    fn fingerprint(&self) -> Self::Fingerprint {
+        (self.board.cave_state(), self.board.rock_pointer, self.gas_pointer)
+    }
+
+    fn metric(&self) -> i64 {
+        self.board.cave_height() as i64
+    }
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let gas = parse_input(input).unwrap();
+    let mut board = Board::new();
+
+    let mut gas_pointer: usize = 0;
+    let rocks = 2022;
+
+    for _rock_count in 0..rocks {
+        board.new_rock();
+        gas_pointer = drop_rock(&mut board, gas_pointer, &gas);
+    }
+    Some(board.cave_height())
+}
+
+fn part2(input: &str) -> Option<i64> {
+    let gas = parse_input(input).unwrap();
+    let tetris = Tetris {
+        board: Board::new(),
+        gas: &gas,
+        gas_pointer: 0,
+    };
+
+    let total_rocks = 1_000_000_000_000;
+    Some(metric_at(&tetris, total_rocks))
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 17: Pyroclastic Flow ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
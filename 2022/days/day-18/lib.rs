@@ -0,0 +1,149 @@
+use aoc_geometry::Vector3;
+use aoc_parse::{parse_lines, Result};
+use std::collections::HashMap;
+
+type Cube = Vector3;
+type Droplet = HashMap<Cube, (DropletState, bool)>;
+
+const MIN_X: isize = -1;
+const MIN_Y: isize = -1;
+const MIN_Z: isize = -1;
+
+const MAX_X: isize = 21;
+const MAX_Y: isize = 21;
+const MAX_Z: isize = 21;
+
+#[derive(Debug)]
+enum DropletState {
+    Air,
+    Lava,
+}
+
+fn find_edge_cubes(droplet: &mut Droplet, visit_cube: &Cube) {
+    if visit_cube.0 < MIN_X
+        || visit_cube.0 > MAX_X
+        || visit_cube.1 < MIN_Y
+        || visit_cube.1 > MAX_Y
+        || visit_cube.2 < MIN_Z
+        || visit_cube.2 > MAX_Z
+    {
+        return;
+    }
+    let state = droplet.get_mut(visit_cube).unwrap();
+
+    // Visited
+    if state.1 {
+        return;
+    }
+
+    match state.0 {
+        DropletState::Air => {
+            state.1 = true;
+
+            for direction in visit_cube.neighbors() {
+                find_edge_cubes(droplet, &direction);
+            }
+        }
+        DropletState::Lava => {
+            state.1 = true;
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Cube>> {
+    parse_lines(input, |line| line.parse())
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let cubes = parse_input(input).unwrap();
+    let mut droplet: Droplet = HashMap::default();
+    for cube in cubes.iter() {
+        droplet.insert(*cube, (DropletState::Lava, false));
+    }
+
+    let sum = cubes.iter().fold(0, |acc, cube| {
+        acc + cube
+            .neighbors()
+            .filter(|neighbour| !droplet.contains_key(neighbour))
+            .count() as i32
+    });
+
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let cubes = parse_input(input).unwrap();
+    let mut droplet: Droplet = HashMap::default();
+
+    for cube in cubes.iter() {
+        droplet.insert(*cube, (DropletState::Lava, false));
+    }
+
+    for z in MIN_Z..=MAX_Z {
+        for y in MIN_Y..=MAX_Y {
+            for x in MIN_X..=MAX_X {
+                let air_cube = Vector3(x, y, z);
+                if !droplet.contains_key(&air_cube) {
+                    droplet.insert(air_cube, (DropletState::Air, false));
+                }
+            }
+        }
+    }
+
+    for x in MIN_X..=MAX_X {
+        find_edge_cubes(&mut droplet, &Vector3(x, MIN_Y, MIN_Z));
+        find_edge_cubes(&mut droplet, &Vector3(x, MIN_Y, MAX_Z));
+        find_edge_cubes(&mut droplet, &Vector3(x, MAX_Y, MIN_Z));
+        find_edge_cubes(&mut droplet, &Vector3(x, MAX_Y, MAX_Z));
+    }
+
+    for y in MIN_Y..=MAX_Y {
+        find_edge_cubes(&mut droplet, &Vector3(MIN_X, y, MIN_Z));
+        find_edge_cubes(&mut droplet, &Vector3(MIN_X, y, MAX_Z));
+        find_edge_cubes(&mut droplet, &Vector3(MAX_X, y, MIN_Z));
+        find_edge_cubes(&mut droplet, &Vector3(MAX_X, y, MAX_Z));
+    }
+
+    for z in MIN_Z..=MAX_Z {
+        find_edge_cubes(&mut droplet, &Vector3(MIN_X, MIN_Y, z));
+        find_edge_cubes(&mut droplet, &Vector3(MIN_X, MAX_Y, z));
+        find_edge_cubes(&mut droplet, &Vector3(MAX_X, MIN_Y, z));
+        find_edge_cubes(&mut droplet, &Vector3(MAX_X, MAX_Y, z));
+    }
+
+    let mut sum = 0;
+    for cube in cubes.iter() {
+        let (_, visited) = droplet.get(cube).unwrap();
+        if !visited {
+            continue;
+        }
+
+        let count = cube
+            .neighbors()
+            .filter(|neighbour| {
+                let (state, visited) = droplet.get(neighbour).unwrap();
+                matches!(state, DropletState::Air) && *visited
+            })
+            .count();
+
+        sum += count;
+    }
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 18: Boiling Boulders ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
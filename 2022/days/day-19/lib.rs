@@ -0,0 +1,335 @@
+use aoc_parse::{parse_lines, Result, Span};
+use hashbrown::HashMap;
+
+#[derive(Debug)]
+struct Robot {
+    ore_cost: i32,
+    clay_cost: i32,
+    obsidian_cost: i32,
+}
+
+impl Robot {
+    fn new() -> Self {
+        Self {
+            ore_cost: 0,
+            clay_cost: 0,
+            obsidian_cost: 0,
+        }
+    }
+    fn parse(recipe: Span) -> Result<Self> {
+        let mut robot = Self::new();
+
+        let (_, resources) = recipe.split_once(" robot costs ")?;
+        for resource in resources.split(" and ") {
+            let (cost, resource_type) = resource.split_once(" ")?;
+            match resource_type.as_str() {
+                "ore" => robot.ore_cost = cost.parse()?,
+                "clay" => robot.clay_cost = cost.parse()?,
+                "obsidian" => robot.obsidian_cost = cost.parse()?,
+                _ => return Err(resource_type.error("Unknown resource type")),
+            }
+        }
+
+        Ok(robot)
+    }
+}
+
+#[derive(Debug)]
+struct Blueprint {
+    id: i32,
+    ore_robot: Robot,
+    ore_robot_max: i32,
+    clay_robot: Robot,
+    clay_robot_max: i32,
+    obsidian_robot: Robot,
+    obsidian_robot_max: i32,
+    geode_robot: Robot,
+}
+
+impl Blueprint {
+    fn parse(blueprint_line: Span) -> Result<Self> {
+        let (name, recipe) = blueprint_line.split_once(":")?;
+        let robots = recipe
+            .split(".")
+            .map(|recipe| recipe.trim())
+            .filter(|recipe| !recipe.is_empty())
+            .map(Robot::parse)
+            .collect::<Result<Vec<_>>>()?;
+        let Ok([ore_robot, clay_robot, obsidian_robot, geode_robot]) =
+            <[Robot; 4]>::try_from(robots)
+        else {
+            return Err(blueprint_line.error("Expected four robot recipes"));
+        };
+
+        let ore_robot_max = vec![
+            ore_robot.ore_cost,
+            clay_robot.ore_cost,
+            obsidian_robot.ore_cost,
+            geode_robot.ore_cost,
+        ]
+        .iter()
+        .max()
+        .unwrap()
+        .clone();
+
+        let clay_robot_max = vec![
+            ore_robot.clay_cost,
+            clay_robot.clay_cost,
+            obsidian_robot.clay_cost,
+            geode_robot.clay_cost,
+        ]
+        .iter()
+        .max()
+        .unwrap()
+        .clone();
+
+        let obsidian_robot_max = vec![
+            ore_robot.obsidian_cost,
+            clay_robot.obsidian_cost,
+            obsidian_robot.obsidian_cost,
+            geode_robot.obsidian_cost,
+        ]
+        .iter()
+        .max()
+        .unwrap()
+        .clone();
+
+        Ok(Self {
+            id: name.strip_prefix("Blueprint ")?.parse()?,
+            ore_robot,
+            ore_robot_max,
+            clay_robot,
+            clay_robot_max,
+            obsidian_robot,
+            obsidian_robot_max,
+            geode_robot,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+struct State {
+    ore_count: i32,
+    clay_count: i32,
+    obsidian_count: i32,
+    geode_count: i32,
+
+    ore_robots: i32,
+    clay_robots: i32,
+    obsidian_robots: i32,
+    geode_robots: i32,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            ore_count: 0,
+            clay_count: 0,
+            obsidian_count: 0,
+            geode_count: 0,
+
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        }
+    }
+
+    fn can_build(&self, robot: &Robot) -> bool {
+        if self.ore_count < robot.ore_cost {
+            return false;
+        }
+        if self.clay_count < robot.clay_cost {
+            return false;
+        }
+        if self.obsidian_count < robot.obsidian_cost {
+            return false;
+        }
+        true
+    }
+
+    fn should_have_built(&self, _robot: &Robot) -> bool {
+        /* Todo: look into this */
+        // if robot.ore_cost != 0 && (self.ore_count - self.ore_robots) >= robot.ore_cost {
+        //     return true;
+        // }
+
+        // if robot.clay_cost != 0 && (self.clay_count - self.clay_robots) >= robot.clay_cost {
+        //     return true;
+        // }
+
+        false
+    }
+
+    fn collect_and_build(&mut self, robot: &Robot) {
+        self.collect();
+
+        self.ore_count -= robot.ore_cost;
+        self.clay_count -= robot.clay_cost;
+        self.obsidian_count -= robot.obsidian_cost;
+    }
+
+    fn collect(&mut self) {
+        self.ore_count += self.ore_robots;
+        self.clay_count += self.clay_robots;
+        self.obsidian_count += self.obsidian_robots;
+        self.geode_count += self.geode_robots;
+    }
+}
+
+fn run_simulation(
+    state: &State,
+    blueprint: &Blueprint,
+    max_minutes: i32,
+    minute: i32,
+    mem: &mut HashMap<(i32, State), i32>,
+) -> i32 {
+    let key = (minute, *state);
+    if mem.contains_key(&key) {
+        return *mem.get(&key).unwrap();
+    }
+
+    if minute > max_minutes {
+        return state.geode_count;
+    }
+
+    let mut best_value = 0;
+
+    // Try build robots
+    if state.can_build(&blueprint.geode_robot) {
+        let mut new_state = state.clone();
+
+        new_state.collect_and_build(&blueprint.geode_robot);
+        new_state.geode_robots += 1;
+
+        best_value = best_value.max(run_simulation(
+            &new_state,
+            blueprint,
+            max_minutes,
+            minute + 1,
+            mem,
+        ));
+    } else {
+        if blueprint.obsidian_robot_max != state.obsidian_robots
+            && state.can_build(&blueprint.obsidian_robot)
+            && !state.should_have_built(&blueprint.obsidian_robot)
+        {
+            let mut new_state = state.clone();
+
+            new_state.collect_and_build(&blueprint.obsidian_robot);
+            new_state.obsidian_robots += 1;
+
+            best_value = best_value.max(run_simulation(
+                &new_state,
+                blueprint,
+                max_minutes,
+                minute + 1,
+                mem,
+            ));
+        }
+        if blueprint.clay_robot_max != state.clay_robots
+            && state.can_build(&blueprint.clay_robot)
+            && !state.should_have_built(&blueprint.clay_robot)
+        {
+            let mut new_state = state.clone();
+
+            new_state.collect_and_build(&blueprint.clay_robot);
+            new_state.clay_robots += 1;
+
+            best_value = best_value.max(run_simulation(
+                &new_state,
+                blueprint,
+                max_minutes,
+                minute + 1,
+                mem,
+            ));
+        }
+        if blueprint.ore_robot_max != state.ore_robots
+            && state.can_build(&blueprint.ore_robot)
+            && !state.should_have_built(&blueprint.ore_robot)
+        {
+            let mut new_state = state.clone();
+
+            new_state.collect_and_build(&blueprint.ore_robot);
+            new_state.ore_robots += 1;
+
+            best_value = best_value.max(run_simulation(
+                &new_state,
+                blueprint,
+                max_minutes,
+                minute + 1,
+                mem,
+            ));
+        }
+        // Do not build any robot
+        let mut new_state = state.clone();
+        new_state.collect();
+
+        best_value = best_value.max(run_simulation(
+            &new_state,
+            blueprint,
+            max_minutes,
+            minute + 1,
+            mem,
+        ));
+    }
+
+    mem.insert(key, best_value);
+    best_value
+}
+
+fn parse_input(input: &str) -> Result<Vec<Blueprint>> {
+    parse_lines(input, Blueprint::parse)
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let blueprints = parse_input(input).unwrap();
+
+    let mut sum: i32 = 0;
+    let mut mem = HashMap::new();
+
+    for blueprint in blueprints.iter() {
+        let state = State::new();
+        let result = run_simulation(&state, blueprint, 24, 1, &mut mem);
+
+        mem.clear();
+
+        sum += result * blueprint.id;
+    }
+
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<i32> {
+    let blueprints = parse_input(input).unwrap();
+
+    let mut sum: i32 = 1;
+    let mut mem = HashMap::new();
+
+    for blueprint in blueprints.iter().take(3) {
+        let state = State::new();
+        let result = run_simulation(&state, blueprint, 32, 1, &mut mem);
+
+        mem.clear();
+
+        sum *= result;
+    }
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 19: Not Enough Minerals ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
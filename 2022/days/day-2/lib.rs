@@ -0,0 +1,156 @@
+use aoc_parse::{parse_lines, Span};
+
+#[derive(Debug)]
+enum Shape {
+    Rock(i32),
+    Paper(i32),
+    Scissor(i32),
+}
+
+impl Shape {
+    fn value(self: &Shape) -> i32 {
+        match self {
+            Self::Rock(_) => 1,
+            Self::Paper(_) => 2,
+            Self::Scissor(_) => 3,
+        }
+    }
+
+    fn parse(span: Span) -> aoc_parse::Result<Shape> {
+        match span.as_str() {
+            "A" => Ok(Shape::Rock(1)),
+            "B" => Ok(Shape::Paper(1)),
+            "C" => Ok(Shape::Scissor(1)),
+            "X" => Ok(Shape::Rock(2)),
+            "Y" => Ok(Shape::Paper(2)),
+            "Z" => Ok(Shape::Scissor(2)),
+            shape => Err(span.error(format!("Unknown shape {shape}"))),
+        }
+    }
+}
+
+enum Result {
+    Loss,
+    Win,
+    Draw
+}
+
+impl Result {
+    fn parse(span: Span) -> aoc_parse::Result<Result> {
+        match span.as_str() {
+            "X" => Ok(Result::Loss),
+            "Y" => Ok(Result::Draw),
+            "Z" => Ok(Result::Win),
+            result => Err(span.error(format!("Unknown result {result}"))),
+        }
+    }
+
+    fn value(self: &Result) -> i32 {
+        match self {
+            Self::Draw => 3,
+            Self::Win => 6,
+            Self::Loss => 0
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Round(Shape, Shape);
+
+struct Outcome(Shape, Result);
+
+impl Outcome {
+    fn get_shape(self: &Outcome) -> Shape {
+        match self.0 {
+            Shape::Rock(_) => match self.1 {
+                Result::Win => Shape::Paper(2),
+                Result::Loss => Shape::Scissor(2),
+                Result::Draw => Shape::Rock(2)
+            }
+            Shape::Paper(_) => match self.1 {
+                Result::Win => Shape::Scissor(2),
+                Result::Loss => Shape::Rock(2),
+                Result::Draw => Shape::Paper(2)
+            }
+            Shape::Scissor(_) => match self.1 {
+                Result::Win => Shape::Rock(2),
+                Result::Loss => Shape::Paper(2),
+                Result::Draw => Shape::Scissor(2)
+            }
+        }
+    }
+}
+
+
+fn calc_result(round: &Round) -> i32 {
+    let win = match round.1 {
+        Shape::Rock(_) => {
+            match round.0 {
+                Shape::Scissor(_) => 6,
+                Shape::Rock(_) => 3,
+                _ => 0
+            }
+        }
+        Shape::Paper(_) => {
+            match round.0 {
+                Shape::Rock(_) => 6,
+                Shape::Paper(_) => 3,
+                _ => 0
+            }
+        }
+        Shape::Scissor(_) => {
+            match round.0 {
+                Shape::Paper(_) => 6,
+                Shape::Scissor(_) => 3,
+                _ => 0
+            }
+        }
+    };
+    win + round.1.value()
+}
+
+fn calc_outcome(outcome: &Outcome) -> i32 {
+    outcome.get_shape().value() + outcome.1.value()
+}
+
+fn get_rounds(input: &str) -> aoc_parse::Result<Vec<Round>> {
+    parse_lines(input, |line| {
+        let (a, b) = line.split_once(" ")?;
+        Ok(Round(Shape::parse(a)?, Shape::parse(b.trim())?))
+    })
+}
+
+fn get_outcomes(input: &str) -> aoc_parse::Result<Vec<Outcome>> {
+    parse_lines(input, |line| {
+        let (a, b) = line.split_once(" ")?;
+        Ok(Outcome(Shape::parse(a)?, Result::parse(b.trim())?))
+    })
+}
+
+fn part1(input: &str) -> Option<i32> {
+    let rounds = get_rounds(input).unwrap();
+    let result = rounds.iter().fold(0,|sum, round| sum + calc_result(round));
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<i32> {
+    let outcomes = get_outcomes(input).unwrap();
+    let result = outcomes.iter().fold(0,|sum, outcome| sum + calc_outcome(outcome));
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 2: Rock Paper Scissors ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::VecDeque;
+
+use aoc_automaton::Sparse;
+use aoc_geometry::{
+    Vector2, COMPASS, EAST, NORTH, NORTH_EAST, NORTH_WEST, SOUTH, SOUTH_EAST, SOUTH_WEST, WEST,
+};
+use aoc_parse::{parse_lines, Result};
+
+type OffsetRule = ([Vector2; 3], Vector2);
+
+const OFFSETS_N: OffsetRule = ([NORTH, NORTH_EAST, NORTH_WEST], NORTH);
+const OFFSETS_S: OffsetRule = ([SOUTH, SOUTH_EAST, SOUTH_WEST], SOUTH);
+const OFFSETS_W: OffsetRule = ([WEST, NORTH_WEST, SOUTH_WEST], WEST);
+const OFFSETS_E: OffsetRule = ([EAST, NORTH_EAST, SOUTH_EAST], EAST);
+
+fn parse_input(input: &str) -> Result<Sparse> {
+    let rows = parse_lines(input, |line| {
+        line.map_chars(|c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+    })?;
+    let elfs = rows.into_iter().enumerate().flat_map(|(y, row)| {
+        row.into_iter()
+            .enumerate()
+            .filter(|(_, elf)| *elf)
+            .map(move |(x, _)| Vector2(x as isize, y as isize))
+    });
+    Ok(Sparse::new(elfs))
+}
+
+fn is_empty(elf: Vector2, offsets: &[Vector2], elfs: &Sparse) -> bool {
+    offsets
+        .iter()
+        .all(|offset| !elfs.contains(&(elf + *offset)))
+}
+
+fn simulate(elfs: &mut Sparse, rules: &VecDeque<OffsetRule>) -> bool {
+    let moved = elfs.step(|elf, elfs| {
+        if is_empty(elf, &COMPASS, elfs) {
+            return None;
+        }
+        rules
+            .iter()
+            .find(|(offsets, _)| is_empty(elf, offsets, elfs))
+            .map(|(_, offset)| elf + *offset)
+    });
+
+    moved > 0
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut elfs = parse_input(input).unwrap();
+    let mut rules: VecDeque<_> = [OFFSETS_N, OFFSETS_S, OFFSETS_W, OFFSETS_E].into();
+
+    for _ in 0..10 {
+        simulate(&mut elfs, &rules);
+        rules.rotate_left(1);
+    }
+
+    let (min, max) = elfs.bounds()?;
+    let area = (max.0 - min.0 + 1) * (max.1 - min.1 + 1);
+    Some(area as usize - elfs.len())
+}
+
+fn part2(input: &str) -> Option<i32> {
+    let mut elfs = parse_input(input).unwrap();
+    let mut rules: VecDeque<_> = [OFFSETS_N, OFFSETS_S, OFFSETS_W, OFFSETS_E].into();
+
+    let mut iteration = 1;
+    while simulate(&mut elfs, &rules) {
+        rules.rotate_left(1);
+        iteration += 1;
+    }
+
+    Some(iteration)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 23: Unstable Diffusion ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
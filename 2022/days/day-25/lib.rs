@@ -0,0 +1,92 @@
+use aoc_parse::{parse_lines, Result};
+
+
+struct SNAFU {}
+
+impl SNAFU {
+    fn decode(snafu_str: &str) -> i64 {
+        let iter = snafu_str.chars().map(Self::char_to_dec).rev().enumerate();
+        let mut result = 0;
+        for (exp, num) in iter {
+            result += num * 5_i64.pow(exp as u32)
+        }
+        result
+    }
+
+    fn encode(decimal: i64) -> String {
+        let mut snafu = String::new();
+
+        let mut current = decimal;
+        let max_exp = (0..).find(|exp| 5_i64.pow(*exp as u32) >= current).unwrap();
+
+        for exp in (0..max_exp).rev() {
+            let base = 5_i64.pow(exp as u32);
+            let count = (current as f64 / base as f64).round() as i64;
+            let str = Self::num_to_str(count);
+            
+            current -= count * base;
+            snafu.push_str(&str);
+        }
+
+        snafu
+    }
+
+    fn num_to_str(num: i64) -> String {
+        match num {
+            -2 => "=",
+            -1 => "-",
+            0 => "0",
+            1 => "1",
+            2 => "2",
+            _ => panic!("Unable to convert {num}"),
+        }.to_string()
+    }
+
+    fn char_to_dec(c: char) -> i64 {
+        match c {
+            '-' => -1,
+            '=' => -2,
+            _ => c.to_string().parse().unwrap(),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    parse_lines(input, |line| {
+        line.map_chars(|c| "=-012".contains(c).then_some(c))
+            .map(String::from_iter)
+    })
+}
+
+fn part1(input: &str) -> Option<String> {
+    let numbers = parse_input(input).unwrap();
+
+    let mut sum = 0;
+    for snafu in numbers {
+        let decoded = SNAFU::decode(&snafu);
+        sum += decoded;
+    }
+    
+    let encoded = SNAFU::encode(sum);
+    Some(encoded)
+}
+
+fn part2(_input: &str) -> Option<String> {
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 25: Full of Hot Air ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_interval::Interval;
+use aoc_parse::{parse_lines, Result, Span};
+
+fn parse_section(span: Span) -> Result<Interval<i32>> {
+    let (start, last) = span.split_once("-")?;
+    Ok(Interval::inclusive(start.parse()?, last.parse()?))
+}
+
+#[derive(Debug)]
+struct Pair(Interval<i32>, Interval<i32>);
+
+impl Pair {
+    fn is_overlaped(self: &Pair) -> bool {
+        self.0.covers(&self.1) || self.1.covers(&self.0)
+    }
+
+    fn is_partially_overlaped(self: &Pair) -> bool {
+        self.0.overlaps(&self.1)
+    }
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<Pair>> {
+    parse_lines(input, |line| {
+        let (first, second) = line.split_once(",")?;
+        Ok(Pair(parse_section(first)?, parse_section(second)?))
+    })
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let pairs = parse_pairs(input).unwrap();
+    let filtered_pairs = pairs.iter().filter(|x| x.is_overlaped()).collect::<Vec<&Pair>>();
+    Some(filtered_pairs.len())
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let pairs = parse_pairs(input).unwrap();
+    let filtered_pairs = pairs.iter().filter(|x| x.is_partially_overlaped()).collect::<Vec<&Pair>>();
+    Some(filtered_pairs.len())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 4: Camp Cleanup ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use aoc_parse::{sections, Error, Pattern, Result};
+
+const STACKS_SIZE: usize = 9;
+
+type Stack = VecDeque<String>;
+
+struct Stacks([Stack; STACKS_SIZE]);
+
+impl Stacks {
+    fn follow_direction(self: &mut Stacks, direction: &Direction) {
+        for _i in 0..direction.0 {
+            if let Some(elem) = &self.0[direction.1].pop_back() {
+                self.0[direction.2].push_back(elem.to_string());
+            }
+        }
+    }
+
+    fn follow_direction_9001(self: &mut Stacks, direction: &Direction) {
+        let mut tmp: VecDeque<String> = VecDeque::new();
+
+        for _i in 0..direction.0 {
+            if let Some(elem) = &self.0[direction.1].pop_back() {
+                tmp.push_back(elem.to_string());
+            }
+        }
+
+        for _i in 0..direction.0 {
+            if let Some(elem) = tmp.pop_back() {
+                self.0[direction.2].push_back(elem);
+            }
+        }
+    }
+
+
+
+    fn get_ends(self: &Stacks) -> String {
+        let mut ends = String::new();
+        for i in 0..STACKS_SIZE {
+            if let Some(elem) = self.0[i].back() {
+                ends.push_str(elem);
+            }
+        }
+        ends
+    }
+}
+
+#[derive(Debug)]
+struct Direction(i32, usize, usize);
+
+fn parse_instructions(input: &str) -> Result<(Stacks, Vec<Direction>)> {
+    let sections = sections(input);
+    let [drawing, instructions] = &sections[..] else {
+        return Err(Error::new(1, 1, "Expected stacks and instructions"));
+    };
+
+    // The drawing lines up by column, so it is read untrimmed
+    let mut stacks: [Stack; STACKS_SIZE] = Default::default();
+    for line in drawing.as_str().lines() {
+        for (index, stack) in stacks.iter_mut().enumerate() {
+            match line.chars().nth(index * 4 + 1) {
+                Some(c) if c.is_ascii_uppercase() => stack.push_front(c.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    let pattern = Pattern::new("move <count> from <from> to <to>");
+    let directions = instructions
+        .lines()
+        .map(|line| {
+            let [count, from, to] = pattern.parse::<usize, 3>(line)?;
+            Ok(Direction(count as i32, from - 1, to - 1))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((Stacks(stacks), directions))
+}
+
+fn part1(input: &str) -> Option<String> {
+    let mut instructions = parse_instructions(input).unwrap();
+    for direction in instructions.1 {
+        instructions.0.follow_direction(&direction);
+    }
+
+    Some(instructions.0.get_ends())
+}
+
+fn part2(input: &str) -> Option<String> {
+    let mut instructions = parse_instructions(input).unwrap();
+    for direction in instructions.1 {
+        instructions.0.follow_direction_9001(&direction);
+    }
+
+    Some(instructions.0.get_ends())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 5: Supply Stacks ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
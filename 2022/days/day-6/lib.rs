@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use aoc_parse::single_line;
+
+fn find_packet(input: &str, packet_size: usize) -> usize {
+    let content = single_line(input).unwrap().as_str();
+
+    for index in 0..content.len() {
+        let mut packet: HashSet<char> = HashSet::new();
+        (&content[index..(index + packet_size)]).chars().for_each(| part | {
+            packet.insert(part);
+        });
+        
+        if packet.len() == packet_size {
+            return index + packet_size
+        }
+    }
+
+    return 0
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let result = find_packet(input, 4);
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let result = find_packet(input, 14);
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 6: Tuning Trouble ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
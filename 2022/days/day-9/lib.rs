@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use aoc_parse::{parse_lines, Result, Span};
+
+type Vector = (i32, i32);
+type Position = (i32, i32);
+
+#[derive(Debug, Clone, Copy)]
+struct RopeEdge(Position);
+
+impl RopeEdge {
+    fn new() -> RopeEdge {
+        RopeEdge((0, 0))
+    }
+
+    fn move_to(self: &mut RopeEdge, vector: Vector) {
+        self.0 .0 += vector.0;
+        self.0 .1 += vector.1;
+    }
+
+    fn move_closer(self: &mut RopeEdge, head: &RopeEdge) {
+        let x_diff = self.0 .0 - head.0 .0;
+        let y_diff = self.0 .1 - head.0 .1;
+
+        // They are touching, let it be
+        if x_diff == 0 && y_diff == 0 {
+            // Do not do anything
+            return;
+        }
+
+        // Same column, move up or down
+        if self.0 .0 == head.0 .0 {
+            if y_diff < -1 {
+                self.0 .1 += 1;
+                return;
+            } else if y_diff > 1 {
+                self.0 .1 -= 1;
+                return;
+            }
+        }
+
+        // Same row, move right or left
+        if self.0 .1 == head.0 .1 {
+            if x_diff < -1 {
+                self.0 .0 += 1;
+                return;
+            } else if x_diff > 1 {
+                self.0 .0 -= 1;
+            }
+        }
+
+        // Move diagonally
+        if x_diff.abs() > 1 && y_diff.abs() > 0 || x_diff.abs() > 0 && y_diff.abs() > 1 {
+            self.0 .0 += x_diff.clamp(-1, 1) * -1;
+            self.0 .1 += y_diff.clamp(-1, 1) * -1;
+            return;
+        }
+
+        // Too close just return
+    }
+}
+
+#[derive(Debug)]
+struct Direction(Vector, i32);
+
+impl Direction {
+    fn parse(line: Span) -> Result<Direction> {
+        let (dir_str, dir_count) = line.split_once(" ")?;
+        let dir_count = dir_count.parse::<i32>()?;
+
+        match dir_str.as_str() {
+            "U" => Ok(Direction((0, -1), dir_count)),
+            "R" => Ok(Direction((1, 0), dir_count)),
+            "D" => Ok(Direction((0, 1), dir_count)),
+            "L" => Ok(Direction((-1, 0), dir_count)),
+            dir => Err(dir_str.error(format!("Unknown direction {dir}"))),
+        }
+    }
+}
+
+fn parse_directions(input: &str) -> Result<Vec<Direction>> {
+    parse_lines(input, Direction::parse)
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let directions = parse_directions(input).unwrap();
+    let mut map: HashMap<Position, bool> = Default::default();
+
+    let head = &mut RopeEdge::new();
+    let tail = &mut RopeEdge::new();
+
+    map.insert(tail.0, true);
+
+    for direction in directions {
+        for _i in 0..direction.1 {
+            head.move_to(direction.0);
+            tail.move_closer(head);
+
+            map.insert(tail.0, true);
+        }
+    }
+    Some(map.len())
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let directions = parse_directions(input).unwrap();
+    let mut map: HashMap<Position, bool> = Default::default();
+    let parts = 10;
+
+    let head = &mut RopeEdge::new();
+    let mut body_parts = vec![*head];
+    for _i in 0..(parts - 2) {
+        body_parts.push(RopeEdge::new())
+    }
+    let tail = &mut RopeEdge::new();
+    body_parts.push(*tail);
+
+    map.insert(tail.0, true);
+
+    for direction in directions {
+        for _i in 0..direction.1 {
+            for part_index in 0..body_parts.len() {
+                if part_index == 0 {
+                    (&mut body_parts[part_index]).move_to(direction.0);
+                } else {
+                    let (left, right) = body_parts.split_at_mut(part_index);
+                    (&mut right[0]).move_closer(&left[part_index - 1]);
+
+                    if part_index == (parts - 1) {
+                        map.insert((&mut right[0]).0, true);
+                    }
+                }
+            }
+        }
+    }
+    Some(map.len())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 9: Rope Bridge ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
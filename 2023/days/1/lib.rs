@@ -0,0 +1,74 @@
+use aoc_parse::lines;
+
+fn read_input(input: &str) -> Vec<String> {
+    lines(input).map(|line| line.to_string()).collect()
+}
+
+const NUM_STR: [&str; 18] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "1", "2", "3", "4",
+    "5", "6", "7", "8", "9",
+];
+
+fn part1(input: &str) -> Option<u32> {
+    let input = read_input(input);
+    let sum = input.iter().fold(0_u32, |acc, line| {
+        let chars_iter = line.chars().collect::<Vec<char>>();
+        let head = chars_iter.iter().find(|c| c.is_numeric()).unwrap();
+        let tail = chars_iter.iter().rev().find(|c| c.is_numeric()).unwrap();
+
+        acc + format!("{head}{tail}").parse::<u32>().unwrap()
+    });
+
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let input = read_input(input);
+    let sum = input
+        .iter()
+        .map(|line| {
+            let nums = NUM_STR
+                .iter()
+                .enumerate()
+                .flat_map(|(num_i, num_str)| {
+                    line.match_indices(num_str)
+                        .map(move |(i, _)| (i as i32, (num_i % 9) + 1))
+                })
+                .collect::<Vec<(i32, usize)>>();
+
+            let (head, _) = nums.iter().fold((0, i32::MAX), |acc, i| {
+                if i.0 < acc.1 {
+                    return (i.1, i.0);
+                }
+                acc
+            });
+
+            let (tail, _) = nums.iter().fold((0, -1), |acc, i| {
+                if i.0 > acc.1 {
+                    return (i.1, i.0);
+                }
+                acc
+            });
+
+            format!("{head}{tail}").parse::<u32>().unwrap()
+        })
+        .sum();
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 1: Trebuchet?! ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+};
+
+use aoc_geometry::{Location, Polygon, Vector2};
+use aoc_parse::{parse_lines, Result};
+
+type Map = Vec<Vec<Pipe>>;
+type Position = (i32, i32);
+
+#[derive(Debug)]
+enum Pipe {
+    Vertical,
+    Horizontal,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+    Air,
+    Animal,
+    Undetermined,
+}
+
+impl Pipe {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Pipe::Vertical),
+            '-' => Some(Pipe::Horizontal),
+            'L' => Some(Pipe::NE),
+            'J' => Some(Pipe::NW),
+            '7' => Some(Pipe::SW),
+            'F' => Some(Pipe::SE),
+            '.' => Some(Pipe::Ground),
+            'S' => Some(Pipe::Start),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Pipe {
+    fn eq(&self, other: &Self) -> bool {
+        core::mem::discriminant(self) == core::mem::discriminant(other)
+    }
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Pipe::Vertical => '|',
+            Pipe::Horizontal => '-',
+            Pipe::NE => '└',
+            Pipe::NW => '┘',
+            Pipe::SW => '┐',
+            Pipe::SE => '┌',
+            Pipe::Ground => '.',
+            Pipe::Start => 'S',
+            Pipe::Air => ' ',
+            Pipe::Animal => '@',
+            Pipe::Undetermined => '?',
+        };
+        write!(f, "{c}")
+    }
+}
+
+fn read_input(input: &str) -> Result<Map> {
+    parse_lines(input, |line| line.map_chars(Pipe::from_char))
+}
+
+fn get_connecting_pipes((x, y): Position, map: &Map) -> Vec<Position> {
+    let mut positions = Vec::new();
+    let pipe = &map[y as usize][x as usize];
+
+    if y - 1 >= 0
+        && (matches!(pipe, Pipe::NE)
+            || matches!(pipe, Pipe::NW)
+            || matches!(pipe, Pipe::Vertical)
+            || matches!(pipe, Pipe::Start))
+    {
+        match map[(y - 1) as usize][x as usize] {
+            Pipe::SE => positions.push((x, y - 1)),
+            Pipe::SW => positions.push((x, y - 1)),
+            Pipe::Vertical => positions.push((x, y - 1)),
+            Pipe::Start => positions.push((x, y - 1)),
+            _ => {}
+        };
+    }
+    if y + 1 < map.len() as i32
+        && (matches!(pipe, Pipe::SE)
+            || matches!(pipe, Pipe::SW)
+            || matches!(pipe, Pipe::Vertical)
+            || matches!(pipe, Pipe::Start))
+    {
+        match map[(y + 1) as usize][x as usize] {
+            Pipe::NE => positions.push((x, y + 1)),
+            Pipe::NW => positions.push((x, y + 1)),
+            Pipe::Vertical => positions.push((x, y + 1)),
+            Pipe::Start => positions.push((x, y + 1)),
+            _ => {}
+        };
+    }
+    if x - 1 >= 0
+        && (matches!(pipe, Pipe::SW)
+            || matches!(pipe, Pipe::NW)
+            || matches!(pipe, Pipe::Horizontal)
+            || matches!(pipe, Pipe::Start))
+    {
+        match map[y as usize][(x - 1) as usize] {
+            Pipe::NE => positions.push((x - 1, y)),
+            Pipe::SE => positions.push((x - 1, y)),
+            Pipe::Horizontal => positions.push((x - 1, y)),
+            Pipe::Start => positions.push((x - 1, y)),
+            _ => {}
+        };
+    }
+    if x + 1 < map[0].len() as i32
+        && (matches!(pipe, Pipe::SE)
+            || matches!(pipe, Pipe::NE)
+            || matches!(pipe, Pipe::Horizontal)
+            || matches!(pipe, Pipe::Start))
+    {
+        match map[y as usize][(x + 1) as usize] {
+            Pipe::NW => positions.push((x + 1, y)),
+            Pipe::SW => positions.push((x + 1, y)),
+            Pipe::Horizontal => positions.push((x + 1, y)),
+            Pipe::Start => positions.push((x + 1, y)),
+            _ => {}
+        };
+    }
+
+    positions
+}
+
+fn find_loop(
+    from: Position,
+    destination: Position,
+    map: &Map,
+    visited: &mut HashSet<(Position, Position)>,
+) -> Option<Vec<Position>> {
+    let mut stack = VecDeque::new();
+    stack.push_front((from, vec![]));
+
+    while let Some((current, path)) = stack.pop_front() {
+        if current == destination {
+            let mut new_path = path;
+            new_path.push(current);
+            return Some(new_path);
+        }
+
+        let connecting_pipes = get_connecting_pipes(current, map)
+            .iter()
+            .filter(|pos| {
+                !visited.contains(&(**pos, current)) && !visited.contains(&(current, **pos))
+            })
+            .map(|pos| *pos)
+            .collect::<Vec<Position>>();
+
+        for connecting_pipe in connecting_pipes {
+            stack.push_front((connecting_pipe, {
+                let mut new_path = path.clone();
+                new_path.push(current);
+                new_path
+            }));
+            visited.insert((current, connecting_pipe));
+        }
+    }
+
+    None
+}
+
+fn search(map: &Map) -> Vec<Position> {
+    let mut start = (0, 0);
+    'outer: for (y, row) in map.iter().enumerate() {
+        for (x, pipe) in row.iter().enumerate() {
+            if matches!(pipe, Pipe::Start) {
+                start = (x as i32, y as i32);
+                break 'outer;
+            }
+        }
+    }
+
+    let connecting_pipes = get_connecting_pipes(start, &map);
+    let mut visited = HashSet::new();
+    for connecting_pipe in connecting_pipes {
+        visited.insert((start, connecting_pipe));
+        if let Some(path) = find_loop(connecting_pipe, start, map, &mut visited) {
+            return path;
+        }
+    }
+
+    panic!("No loop found!")
+}
+
+fn fill_undetermined(map: &mut Map, path: &Vec<Position>) {
+    for (y, row) in map.iter_mut().enumerate() {
+        for (x, pipe) in row.iter_mut().enumerate() {
+            if !path.contains(&(x as i32, y as i32)) {
+                *pipe = Pipe::Undetermined
+            }
+        }
+    }
+}
+
+fn fill_air_or_animal(map: &mut Map, path: &Vec<Position>) {
+    let polygon = Polygon::new(
+        path.iter()
+            .map(|&(x, y)| Vector2(x as isize, y as isize))
+            .collect(),
+    );
+    for (y, row) in map.iter_mut().enumerate() {
+        for (x, pipe) in row.iter_mut().enumerate() {
+            if !matches!(pipe, Pipe::Undetermined) {
+                continue;
+            }
+            let location = polygon.locate(Vector2(x as isize, y as isize));
+            if location == Location::Inside {
+                *pipe = Pipe::Animal;
+            } else {
+                *pipe = Pipe::Air;
+            }
+        }
+    }
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let map = read_input(input).unwrap();
+    let path = search(&map);
+    let result = (path.len() as f32 / 2_f32).ceil() as u32;
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let mut map = read_input(input).unwrap();
+    let path = search(&map);
+    fill_undetermined(&mut map, &path);
+    fill_air_or_animal(&mut map, &path);
+
+    let result = map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|pipe| matches!(pipe, Pipe::Animal))
+                .count() as u32
+        })
+        .sum();
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 10: Pipe Maze ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use aoc_parse::{parse_lines, Result};
+
+fn read_input(input: &str) -> Result<Vec<(String, Vec<u64>)>> {
+    parse_lines(input, |line| {
+        let (hot_springs, records) = line.split_once(" ")?;
+        Ok((hot_springs.to_string(), records.list(",")?))
+    })
+}
+
+fn search(spring_width_pointer: usize, spring_widths: &Vec<u64>, springs: String, mem: &mut HashMap<(usize, String), u64>) -> u64 {
+    if let Some(cached) = mem.get(&(spring_width_pointer, springs.clone())) {
+        return *cached;
+    }
+
+    let total_width = spring_widths[spring_width_pointer..].iter().map(|num| *num).sum::<u64>();
+    let springs_remaining = springs.clone().chars().map(|c| c == '#' || c == '?').count();
+    if total_width > springs_remaining as u64 {
+        return 0;
+    }
+    if spring_width_pointer >= spring_widths.len() {
+        return if springs.contains('#') { 0 } else { 1 };
+    }
+    let normalized_spring = springs
+        .chars()
+        .skip_while(|c| *c == '.')
+        .collect::<String>();
+
+    let mut count = 0;
+
+    if normalized_spring.starts_with('?') {
+        let mut new_string = normalized_spring.clone();
+        new_string.replace_range(0..1, ".");
+
+        count += search(spring_width_pointer, spring_widths, new_string, mem);
+    }
+
+    let mut current_width = spring_widths[spring_width_pointer] as i32;
+    for (index, spring) in normalized_spring.clone().chars().enumerate() {
+        match spring {
+            '?' => {
+                current_width -= 1;
+            }
+            '#' => {
+                current_width -= 1;
+            }
+            '.' => {
+                break;
+            }
+            _ => panic!("Unable to parse spring {spring}"),
+        }
+
+        if current_width == 0 {
+            let mut remaining_springs = normalized_spring.clone()[index + 1..].to_string();
+            if !remaining_springs.starts_with("#") {
+                if remaining_springs.len() != 0 {
+                    remaining_springs.replace_range(0..1, ".");
+                }
+                count += search(
+                    spring_width_pointer + 1,
+                    spring_widths,
+                    remaining_springs.clone(),
+                    mem
+                );
+            }
+            break;
+        }
+    }
+
+    mem.insert((spring_width_pointer, springs), count);
+    count
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let records = read_input(input).unwrap();
+    let mut sum = 0;
+    let mut mem = HashMap::default();
+    for (springs, records) in records.iter() {
+        let count = search(0, records, springs.clone(), &mut mem);
+        sum += count
+    }
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let records = read_input(input).unwrap();
+    let sum = records
+        .iter()
+        .map(|(springs, records)| {
+            let mut mem = HashMap::default();
+            let expanded_springs = format!("{springs}?{springs}?{springs}?{springs}?{springs}");
+            let expanded_records = records
+                .iter()
+                .cycle()
+                .take(records.len() * 5)
+                .map(|record| *record)
+                .collect::<Vec<u64>>();
+            search(0, &expanded_records, expanded_springs, &mut mem)
+        })
+        .sum();
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 12: Hot Springs ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use aoc_parse::{parse_lines, Result};
+use aoc_simulation::{metric_at, Simulation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'O' => Some(Self::RoundRock),
+            '#' => Some(Self::CubeRock),
+            '.' => Some(Self::Empty),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::CubeRock => '#',
+            Self::RoundRock => 'O',
+            Self::Empty => '.',
+        };
+        write!(f, "{c}")
+    }
+}
+
+enum FallDirection {
+    North,
+    West,
+    South,
+    East,
+}
+
+fn read_input(input: &str) -> Result<Vec<Vec<Tile>>> {
+    parse_lines(input, |line| line.map_chars(Tile::from_char))
+}
+
+fn drop_rocks_north(map: &mut Vec<Vec<Tile>>) {
+    let mut column_map = Vec::new();
+    for x in 0..map[0].len() {
+        let mut column = Vec::new();
+        for y in 0..map.len() {
+            column.push(map[y][x]);
+        }
+        column_map.push(column);
+    }
+
+    for (x, column) in column_map.iter_mut().enumerate() {
+        let mut ground_index = 0;
+        for y in 0..column.len() {
+            let tile = column[y];
+            match tile {
+                Tile::RoundRock => {
+                    *map.get_mut(y).unwrap().get_mut(x).unwrap() = Tile::Empty;
+                    *map.get_mut(ground_index).unwrap().get_mut(x).unwrap() = Tile::RoundRock;
+                    ground_index += 1
+                }
+                Tile::CubeRock => ground_index = y + 1,
+                Tile::Empty => {}
+            }
+        }
+    }
+}
+
+fn drop_rocks_south(map: &mut Vec<Vec<Tile>>) {
+    let mut column_map = Vec::new();
+
+    for x in 0..map[0].len() {
+        let mut column = Vec::new();
+        for y in (0..map.len()).rev() {
+            column.push(map[y][x]);
+        }
+        column_map.push(column);
+    }
+
+    for (x, column) in column_map.iter_mut().enumerate() {
+        let mut ground_index = 0;
+        for y in 0..column.len() {
+            let tile = column[y];
+            match tile {
+                Tile::RoundRock => {
+                    *map.get_mut(column.len() - 1 - y)
+                        .unwrap()
+                        .get_mut(x)
+                        .unwrap() = Tile::Empty;
+                    *map.get_mut(column.len() - 1 - ground_index)
+                        .unwrap()
+                        .get_mut(x)
+                        .unwrap() = Tile::RoundRock;
+                    ground_index += 1
+                }
+                Tile::CubeRock => ground_index = y + 1,
+                Tile::Empty => {}
+            }
+        }
+    }
+}
+
+fn drop_rocks_west(map: &mut Vec<Vec<Tile>>) {
+    let mut column_map = map.clone();
+
+    for (x, column) in column_map.iter_mut().enumerate() {
+        let mut ground_index = 0;
+        for y in 0..column.len() {
+            let tile = column[y];
+            match tile {
+                Tile::RoundRock => {
+                    *map.get_mut(x).unwrap().get_mut(y).unwrap() = Tile::Empty;
+                    *map.get_mut(x).unwrap().get_mut(ground_index).unwrap() = Tile::RoundRock;
+                    ground_index += 1
+                }
+                Tile::CubeRock => ground_index = y + 1,
+                Tile::Empty => {}
+            }
+        }
+    }
+}
+
+fn drop_rocks_east(map: &mut Vec<Vec<Tile>>) {
+    let mut column_map = map.clone();
+
+    for (x, column) in column_map.iter_mut().enumerate() {
+        let mut ground_index = column.len() - 1;
+        for y in (0..column.len()).rev() {
+            let tile = column[y];
+            match tile {
+                Tile::RoundRock => {
+                    *map.get_mut(x).unwrap().get_mut(y).unwrap() = Tile::Empty;
+                    *map.get_mut(x).unwrap().get_mut(ground_index).unwrap() = Tile::RoundRock;
+                    if ground_index > 0 {
+                        ground_index -= 1
+                    }
+                }
+                Tile::CubeRock => {
+                    if y > 0 {
+                        ground_index = y - 1
+                    }
+                }
+                Tile::Empty => {}
+            }
+        }
+    }
+}
+
+fn drop_rocks(map: &mut Vec<Vec<Tile>>, fall_direction: FallDirection) {
+    match fall_direction {
+        FallDirection::North => drop_rocks_north(map),
+        FallDirection::South => drop_rocks_south(map),
+        FallDirection::East => drop_rocks_east(map),
+        FallDirection::West => drop_rocks_west(map),
+    }
+}
+
+fn cycle(map: &mut Vec<Vec<Tile>>) {
+    drop_rocks(map, FallDirection::North);
+    drop_rocks(map, FallDirection::West);
+    drop_rocks(map, FallDirection::South);
+    drop_rocks(map, FallDirection::East);
+}
+
+fn count_rocks(map: &Vec<Vec<Tile>>) -> u32 {
+    let mut sum = 0;
+    for (y, row) in map.iter().enumerate() {
+        for tile in row.iter() {
+            match tile {
+                Tile::RoundRock => sum += (map.len() - y) as u32,
+                _ => {}
+            }
+        }
+    }
+    sum
+}
+
+#[derive(Clone)]
+struct Dish(Vec<Vec<Tile>>);
+
+impl Simulation for Dish {
+    type Fingerprint = Vec<Vec<Tile>>;
+
+    fn step(&mut self) {
+        cycle(&mut self.0);
+    }
+
+    fn fingerprint(&self) -> Vec<Vec<Tile>> {
+        self.0.clone()
+    }
+
+    fn metric(&self) -> i64 {
+        count_rocks(&self.0) as i64
+    }
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let mut map = read_input(input).unwrap();
+    drop_rocks(&mut map, FallDirection::North);
+
+    let result = count_rocks(&map);
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let map = read_input(input).unwrap();
+    let max_cycles = 1000000000;
+
+    let result = metric_at(&Dish(map), max_cycles);
+    Some(result as u32)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 14: Parabolic Reflector Dish ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
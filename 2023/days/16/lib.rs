@@ -0,0 +1,218 @@
+use std::{collections::{HashMap, HashSet}, vec};
+
+use aoc_parse::{lines, Result};
+
+type Position = (i32, i32);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum BeamDirection {
+    Right,
+    Left,
+    Up,
+    Down
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Empty,
+    Mirror(char),
+    Beam(BeamDirection),
+}
+
+fn read_input(input: &str) -> Result<(HashMap<Position, (Tile, u32)>, i32, i32)> {
+    let mut map: HashMap<(i32, i32), (Tile, u32)> = HashMap::new();
+    let mut y_max = 0;
+    let mut x_max = 0;
+
+    for (y, line) in lines(input).enumerate() {
+        let tiles = line.map_chars(|char| match char {
+            '.' => Some(Tile::Empty),
+            '/' | '\\' | '|' | '-' => Some(Tile::Mirror(char)),
+            _ => None,
+        })?;
+        for (x, tile) in tiles.into_iter().enumerate() {
+            map.insert((x as i32, y as i32), (tile, 0));
+            x_max = x as i32;
+        }
+        y_max = y as i32;
+    }
+
+    Ok((map, x_max, y_max))
+}
+
+// fn print_map(map: &mut HashMap<Position, (Tile, u32)>, with_count: bool) {
+//     for y in 0..10 {
+//         for x in 0..10 {
+//             let (tile, visit_count) = map.get(&(x, y)).unwrap();
+//             match tile {
+//                 Tile::Mirror(mirror_type) => {
+//                     print!("{mirror_type}");
+//                 },
+//                 Tile::Empty => {
+//                     print!(".");
+//                 },
+//                 Tile::Beam(beam_direction) => {
+//                     if with_count && *visit_count > 1 {
+//                         print!("{visit_count}");
+//                     } else {
+//                         match beam_direction {
+//                             BeamDirection::Up => {
+//                                 print!("^");
+//                             },
+//                             BeamDirection::Down => {
+//                                 print!("v");
+//                             },
+//                             BeamDirection::Left => {
+//                                 print!("<");
+//                             }
+//                             BeamDirection::Right => {
+//                                 print!(">");
+//                             }
+//                         }
+//                     }
+//                 }
+//             }
+//         }
+//         print!("\n");
+//     }
+// }
+
+fn get_new_pos((x, y): Position, beam_direction: BeamDirection) -> (Position, BeamDirection) {
+    match beam_direction {
+        BeamDirection::Up => ((x, y - 1), beam_direction),
+        BeamDirection::Down => ((x, y + 1), beam_direction),
+        BeamDirection::Left => ((x - 1, y), beam_direction),
+        BeamDirection::Right => ((x + 1, y), beam_direction),
+    }
+}
+
+fn search(start_position: Position, start_direction: BeamDirection, map: &mut HashMap<Position, (Tile, u32)>) {
+    let mut visit = vec![(start_position, start_direction)];
+    let mut visited = HashSet::new();
+
+    while let Some((current_position, beam_direction)) = visit.pop() {
+        if visited.contains(&(current_position, beam_direction)) {
+            continue;
+        }
+        if let Some((current_tile, tile_visit_count)) = map.get_mut(&current_position) {
+            match (current_tile.clone(), beam_direction) {
+                (Tile::Empty, _) => {
+                    *current_tile = Tile::Beam(beam_direction.clone());
+                    visit.push(get_new_pos(current_position, beam_direction));
+                },
+    
+                (Tile::Mirror('|'), BeamDirection::Left) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Up));
+                    visit.push(get_new_pos(current_position, BeamDirection::Down));
+                },
+                (Tile::Mirror('|'), BeamDirection::Right) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Up));
+                    visit.push(get_new_pos(current_position, BeamDirection::Down));
+                },
+                (Tile::Mirror('|'), _) => {
+                    visit.push(get_new_pos(current_position, beam_direction));
+                },
+    
+                (Tile::Mirror('-'), BeamDirection::Up) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Left));
+                    visit.push(get_new_pos(current_position, BeamDirection::Right));
+                },
+                (Tile::Mirror('-'), BeamDirection::Down) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Left));
+                    visit.push(get_new_pos(current_position, BeamDirection::Right));
+                },
+                (Tile::Mirror('-'), _) => {
+                    visit.push(get_new_pos(current_position, beam_direction));
+                },
+    
+                (Tile::Mirror('/'), BeamDirection::Up) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Right));
+                },
+                (Tile::Mirror('/'), BeamDirection::Down) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Left));
+                },
+                (Tile::Mirror('/'), BeamDirection::Left) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Down));
+                },
+                (Tile::Mirror('/'), BeamDirection::Right) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Up));
+                },
+    
+                (Tile::Mirror('\\'), BeamDirection::Up) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Left));
+                },
+                (Tile::Mirror('\\'), BeamDirection::Down) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Right));
+                },
+                (Tile::Mirror('\\'), BeamDirection::Left) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Up));
+                },
+                (Tile::Mirror('\\'), BeamDirection::Right) => {
+                    visit.push(get_new_pos(current_position, BeamDirection::Down));
+                },
+
+                (Tile::Beam(_), _) => {
+                    visit.push(get_new_pos(current_position, beam_direction));
+                } 
+                (_, _) => panic!("Combination not covered: {:?} - {:?}", current_tile, beam_direction)
+            }
+            *tile_visit_count += 1;
+            visited.insert((current_position, beam_direction));
+        }
+    }
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let (mut map, _, _) = read_input(input).unwrap();
+    search((0, 0), BeamDirection::Right, &mut map);
+    let result = map.iter().filter(|(_, (_, visit_count))| *visit_count > 0).count();
+    Some(result as u32)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let (map, x_max, y_max) = read_input(input).unwrap();
+    let mut result = 0;
+    // Top
+    for x in 0..=x_max {
+        let mut map_copy = map.clone();
+        search((x, 0), BeamDirection::Down, &mut map_copy);
+        result = result.max(map_copy.iter().filter(|(_, (_, visit_count))| *visit_count > 0).count());
+    }
+    // Bottom
+    for x in 0..=x_max {
+        let mut map_copy = map.clone();
+        search((x, y_max), BeamDirection::Up, &mut map_copy);
+        result = result.max(map_copy.iter().filter(|(_, (_, visit_count))| *visit_count > 0).count());
+    }
+
+    // Left
+    for y in 0..=y_max {
+        let mut map_copy = map.clone();
+        search((0, y), BeamDirection::Right, &mut map_copy);
+        result = result.max(map_copy.iter().filter(|(_, (_, visit_count))| *visit_count > 0).count());
+    }
+
+    // Right
+    for y in 0..=y_max {
+        let mut map_copy = map.clone();
+        search((x_max, y), BeamDirection::Left, &mut map_copy);
+        result = result.max(map_copy.iter().filter(|(_, (_, visit_count))| *visit_count > 0).count());
+    }
+    Some(result as u32)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 16: The Floor Will Be Lava ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
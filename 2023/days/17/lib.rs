@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use aoc_geometry::{Vector2, DOWN, LEFT, RIGHT, UP};
+use aoc_graph::astar;
+use aoc_parse::{lines, Result};
+
+type Position = Vector2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn get_delta(&self) -> Position {
+        match self {
+            Direction::Up => UP,
+            Direction::Down => DOWN,
+            Direction::Right => RIGHT,
+            Direction::Left => LEFT,
+        }
+    }
+
+    fn get_neighbours(&self) -> Vec<Direction> {
+        match self {
+            Direction::Up => vec![Direction::Up, Direction::Left, Direction::Right],
+            Direction::Down => vec![Direction::Down, Direction::Left, Direction::Right],
+            Direction::Right => vec![Direction::Right, Direction::Up, Direction::Down],
+            Direction::Left => vec![Direction::Left, Direction::Up, Direction::Down],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Block {
+    heat_loss: i32,
+    position: Position,
+}
+
+fn read_input(input: &str) -> Result<(HashMap<Position, Block>, isize, isize)> {
+    let mut map = HashMap::new();
+    let mut x_max = 0;
+    let mut y_max = 0;
+    for (y, line) in lines(input).enumerate() {
+        let heat_losses = line.map_chars(|c| c.to_digit(10))?;
+        for (x, heat_loss) in heat_losses.into_iter().enumerate() {
+            let position = Vector2(x as isize, y as isize);
+            map.insert(
+                position,
+                Block {
+                    position,
+                    heat_loss: heat_loss as i32,
+                },
+            );
+            x_max = x as isize;
+        }
+        y_max = y as isize;
+    }
+    Ok((map, x_max, y_max))
+}
+
+type State = (Position, Direction, i32);
+
+fn get_neighbours(
+    position: Position,
+    direction: Direction,
+    map: &HashMap<Position, Block>,
+) -> Vec<(Direction, Block)> {
+    direction
+        .get_neighbours()
+        .into_iter()
+        .filter_map(|direction| {
+            map.get(&(position + direction.get_delta()))
+                .map(|block| (direction, *block))
+        })
+        .collect()
+}
+
+fn find_path(
+    start_block: &Block,
+    end_block: &Block,
+    max_line_length: i32,
+    min_line_length: i32,
+    ultra: bool,
+    map: &HashMap<Position, Block>,
+) -> Option<u32> {
+    let successors = |&(position, direction, direction_count): &State| {
+        get_neighbours(position, direction, map)
+            .into_iter()
+            .filter_map(move |(neighbour_direction, neighbour)| {
+                let same_direction = neighbour_direction == direction;
+                // Try turn
+                if ultra && !same_direction && direction_count < min_line_length {
+                    return None;
+                }
+
+                let next_direction_count = if same_direction {
+                    direction_count + 1
+                } else {
+                    0
+                };
+                if same_direction && next_direction_count >= max_line_length {
+                    return None;
+                }
+
+                Some((
+                    (neighbour.position, neighbour_direction, next_direction_count),
+                    neighbour.heat_loss as u32,
+                ))
+            })
+            .collect::<Vec<_>>()
+    };
+    let heuristic =
+        |&(position, _, _): &State| position.manhattan_distance(&end_block.position) as u32;
+    let is_goal = |&(position, _, direction_count): &State| {
+        position == end_block.position && (!ultra || direction_count >= min_line_length)
+    };
+
+    let (_, heat_loss) = astar::get_path(
+        [
+            (start_block.position, Direction::Right, -1),
+            (start_block.position, Direction::Down, -1),
+        ],
+        successors,
+        heuristic,
+        is_goal,
+    )?;
+
+    Some(heat_loss)
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let (map, x_max, y_max) = read_input(input).unwrap();
+    let start_block = map.get(&Vector2(0, 0)).unwrap().clone();
+    let end_block = map.get(&Vector2(x_max, y_max)).unwrap().clone();
+
+    if let Some(result) = find_path(&start_block, &end_block, 3, 0, false, &map) {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let (map, x_max, y_max) = read_input(input).unwrap();
+    let start_block = map.get(&Vector2(0, 0)).unwrap().clone();
+    let end_block = map.get(&Vector2(x_max, y_max)).unwrap().clone();
+
+    if let Some(result) = find_path(&start_block, &end_block, 10, 3, true, &map) {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 17: Clumsy Crucible ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
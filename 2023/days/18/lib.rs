@@ -0,0 +1,92 @@
+use aoc_geometry::{Polygon, DOWN, LEFT, RIGHT, UP, ZERO};
+use aoc_parse::{parse_lines, Pattern, Result, Span};
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Trench(u32),
+    Terrain,
+}
+
+type Instruction = (char, isize, Tile);
+
+fn from_hex(hex: Span) -> Result<isize> {
+    isize::from_str_radix(hex.as_str(), 16)
+        .map_err(|_| hex.error(format!("Expected hex, got {:?}", hex.as_str())))
+}
+
+fn read_input(input: &str, flipped: bool) -> Result<Vec<Instruction>> {
+    let pattern = Pattern::new("<direction> <count> (#<colour>)");
+    parse_lines(input, |line| {
+        let captures = pattern.captures(line)?;
+        let hex = captures.get("colour");
+
+        if flipped {
+            let (count, direction) = hex.split_at(5)?;
+            let direction = match direction.as_str() {
+                "0" => 'R',
+                "1" => 'D',
+                "2" => 'L',
+                "3" => 'U',
+                dir => return Err(direction.error(format!("Unknown direction {dir}"))),
+            };
+
+            Ok((direction, from_hex(count)?, Tile::Trench(0)))
+        } else {
+            Ok((
+                captures.parse("direction")?,
+                captures.parse("count")?,
+                Tile::Trench(from_hex(hex)? as u32),
+            ))
+        }
+    })
+}
+
+fn create_polygon(instructions: &[Instruction]) -> Polygon {
+    let mut current_position = ZERO;
+    let mut vertices = Vec::new();
+
+    for &(direction, count, _) in instructions.iter() {
+        vertices.push(current_position);
+        let direction = match direction {
+            'R' => RIGHT,
+            'L' => LEFT,
+            'U' => UP,
+            'D' => DOWN,
+            _ => panic!("Unkown direction {direction}"),
+        };
+        current_position += direction * count;
+    }
+
+    Polygon::new(vertices)
+}
+
+fn calculate_lagoon_size(instructions: &[Instruction]) -> u64 {
+    let polygon = create_polygon(instructions);
+    (polygon.interior_points() + polygon.boundary_points()) as u64
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let instructions = read_input(input, false).unwrap();
+    Some(calculate_lagoon_size(&instructions))
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let instructions = read_input(input, true).unwrap();
+    Some(calculate_lagoon_size(&instructions))
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 18: Lavaduct Lagoon ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,277 @@
+use rayon::prelude::*;
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_interval::{Hyperrectangle, Interval};
+use aoc_parse::{sections, Error, Result, Span};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Part {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl Part {
+    fn parse(part: Span) -> Result<Self> {
+        match part.as_str() {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(part.error(format!("Unknown part {part}"))),
+        }
+    }
+
+    fn axis(&self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::M => 1,
+            Self::A => 2,
+            Self::S => 3,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::X => 'x',
+            Self::M => 'm',
+            Self::A => 'a',
+            Self::S => 's',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Debug)]
+enum Rule {
+    LT {
+        part: Part,
+        count: u32,
+        to_workflow: String,
+    },
+    GT {
+        part: Part,
+        count: u32,
+        to_workflow: String,
+    },
+    Else {
+        to_workflow: String,
+    },
+}
+
+impl Rule {
+    fn parse(rule: Span) -> Result<Self> {
+        let Ok((condition, to_workflow)) = rule.split_once(":") else {
+            return Ok(Self::Else {
+                to_workflow: rule.to_string(),
+            });
+        };
+
+        if let Ok((part, count)) = condition.split_once(">") {
+            Ok(Self::GT {
+                part: Part::parse(part)?,
+                count: count.parse()?,
+                to_workflow: to_workflow.to_string(),
+            })
+        } else {
+            let (part, count) = condition.split_once("<")?;
+            Ok(Self::LT {
+                part: Part::parse(part)?,
+                count: count.parse()?,
+                to_workflow: to_workflow.to_string(),
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn parse(line: Span) -> Result<Self> {
+        let (workflow_name, rules) = line.split_once("{")?;
+        let rules = rules
+            .strip_suffix("}")?
+            .split(",")
+            .map(Rule::parse)
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            rules,
+            name: workflow_name.to_string(),
+        })
+    }
+
+    fn process_part_rating(&self, part_rating: &HashMap<Part, u32>) -> String {
+        for rule in self.rules.iter() {
+            match rule {
+                Rule::GT {
+                    part,
+                    count,
+                    to_workflow,
+                } => {
+                    let part_count = part_rating.get(part).unwrap();
+                    if part_count > count {
+                        return to_workflow.clone();
+                    }
+                }
+                Rule::LT {
+                    part,
+                    count,
+                    to_workflow,
+                } => {
+                    let part_count = part_rating.get(part).unwrap();
+                    if part_count < count {
+                        return to_workflow.clone();
+                    }
+                }
+                Rule::Else { to_workflow } => {
+                    return to_workflow.clone();
+                }
+            }
+        }
+        panic!("Unable to process!")
+    }
+}
+
+fn read_input(input: &str) -> Result<(HashMap<String, Workflow>, Vec<HashMap<Part, u32>>)> {
+    let sections = sections(input);
+    let [workflow_section, part_rating_section] = &sections[..] else {
+        return Err(Error::new(1, 1, "Expected workflows and part ratings"));
+    };
+
+    let workflows = workflow_section
+        .lines()
+        .map(|line| {
+            let workflow = Workflow::parse(line)?;
+            Ok((workflow.name.clone(), workflow))
+        })
+        .collect::<Result<_>>()?;
+    let part_ratings = part_rating_section
+        .lines()
+        .map(|line| {
+            line.strip_prefix("{")?
+                .strip_suffix("}")?
+                .split(",")
+                .map(|part_rating| {
+                    let (part, count) = part_rating.split_once("=")?;
+                    Ok((Part::parse(part)?, count.parse()?))
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+    Ok((workflows, part_ratings))
+}
+
+fn process_part_rating(
+    workflow_name: String,
+    part_rating: &HashMap<Part, u32>,
+    workflows: &HashMap<String, Workflow>,
+) -> String {
+    if let Some(workflow) = workflows.get(&workflow_name) {
+        let next_workflow = workflow.process_part_rating(part_rating);
+        return process_part_rating(next_workflow, part_rating, workflows);
+    } else {
+        return workflow_name;
+    }
+}
+
+type Ranges = Hyperrectangle<u64, 4>;
+
+fn get_ranges(
+    workflow_name: &str,
+    part_ranges: Ranges,
+    workflows: &HashMap<String, Workflow>,
+) -> Vec<Ranges> {
+    let Some(workflow) = workflows.get(workflow_name) else {
+        return Vec::new();
+    };
+
+    let mut ranges = Vec::new();
+    let mut accept = |to_workflow: &String, accepted: Ranges| {
+        if to_workflow == "A" {
+            ranges.push(accepted);
+        } else if to_workflow != "R" {
+            ranges.extend(get_ranges(to_workflow, accepted, workflows));
+        }
+    };
+    let mut else_ranges = Some(part_ranges);
+    for rule in workflow.rules.iter() {
+        let Some(current) = else_ranges else {
+            break;
+        };
+        match rule {
+            Rule::LT {
+                part,
+                count,
+                to_workflow,
+            } => {
+                let (below, above) = current.split_at(part.axis(), *count as u64);
+                below.into_iter().for_each(|below| accept(to_workflow, below));
+                else_ranges = above;
+            }
+            Rule::GT {
+                part,
+                count,
+                to_workflow,
+            } => {
+                let (below, above) = current.split_at(part.axis(), *count as u64 + 1);
+                above.into_iter().for_each(|above| accept(to_workflow, above));
+                else_ranges = below;
+            }
+            Rule::Else { to_workflow } => {
+                accept(to_workflow, current);
+                else_ranges = None;
+            }
+        };
+    }
+    ranges
+}
+
+fn sum_part_rating(part_rating: &HashMap<Part, u32>) -> u64 {
+    let res: u32 = part_rating.iter().map(|(_, count)| count).sum();
+    res as u64
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let (workflows, part_ratings) = read_input(input).unwrap();
+
+    let mut result = 0_u64;
+    for part_rating in part_ratings.iter() {
+        let res = process_part_rating("in".to_string(), part_rating, &workflows);
+        if res == "A".to_string() {
+            result += sum_part_rating(part_rating);
+        }
+    }
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let (workflows, _) = read_input(input).unwrap();
+    let part_ranges = Hyperrectangle::new([Interval::inclusive(1, 4000); 4]);
+
+    let ranges = get_ranges("in", part_ranges, &workflows);
+    let sum: u64 = ranges.iter().map(|range| range.volume()).sum();
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 19: Aplenty ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use aoc_parse::{parse_lines, Result};
+
+type Game = HashMap<String, u32>;
+type GameSets = Vec<Game>;
+
+fn read_input(input: &str) -> Result<Vec<GameSets>> {
+    parse_lines(input, |line| {
+        let (_, sets) = line.split_once(": ")?;
+        sets.split("; ")
+            .map(|cubes| {
+                let mut game: Game = HashMap::default();
+                for cube in cubes.split(", ") {
+                    let (amount, color) = cube.split_once(" ")?;
+                    *game.entry(color.to_string()).or_default() += amount.parse::<u32>()?;
+                }
+                Ok(game)
+            })
+            .collect()
+    })
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let games = read_input(input).unwrap();
+
+    let result = games
+        .iter()
+        .enumerate()
+        .filter(|(_, game_sets)| {
+            if game_sets.iter().any(|game| {
+                if let Some(count) = game.get(&"red".to_string()) {
+                    *count > 12
+                } else {
+                    false
+                }
+            }) {
+                return false;
+            }
+            
+            if game_sets.iter().any(|game| {
+                if let Some(count) = game.get(&"green".to_string()) {
+                    *count > 13
+                } else {
+                    false
+                }
+            }) {
+                return false;
+            }
+
+            if game_sets.iter().any(|game| {
+                if let Some(count) = game.get(&"blue".to_string()) {
+                    *count > 14
+                } else {
+                    false
+                }
+            }) {
+                return false;
+            }
+
+            true
+        })
+        .map(|(i, _)| (i + 1) as u32)
+        .sum();
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let games = read_input(input).unwrap();
+
+    let result = games
+        .iter()
+        .map(|game_set| {
+            let red_count = game_set
+                .iter()
+                .map(|game| game.get(&"red".to_string()).unwrap_or(&0_u32))
+                .max()
+                .unwrap();
+            let green_count = game_set
+                .iter()
+                .map(|game| game.get(&"green".to_string()).unwrap_or(&0_u32))
+                .max()
+                .unwrap();
+            let blue_count = game_set
+                .iter()
+                .map(|game| game.get(&"blue".to_string()).unwrap_or(&0_u32))
+                .max()
+                .unwrap();
+
+            red_count * green_count * blue_count
+        })
+        .sum();
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 2: Cube Conundrum ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,203 @@
+use hashbrown::{HashMap, HashSet};
+use std::fmt::Display;
+
+use aoc_algebra::lagrange;
+use aoc_parse::{lines, Result};
+
+type Position = (i64, i64);
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Start,
+    Plot,
+    Rock,
+    Visited,
+}
+
+impl Tile {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '.' => Some(Self::Plot),
+            '#' => Some(Self::Rock),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Self::Plot => '.',
+            Self::Rock => '#',
+            Self::Start => 'S',
+            Self::Visited => 'O',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+fn read_input(input: &str) -> Result<(HashMap<Position, Tile>, i64, i64)> {
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut map = HashMap::new();
+    for (y, line) in lines(input).enumerate() {
+        max_y = (y + 1) as i64;
+        for (x, tile) in line.map_chars(Tile::from_char)?.into_iter().enumerate() {
+            max_x = (x + 1) as i64;
+            map.insert((x as i64, y as i64), tile);
+        }
+    }
+
+    Ok((map, max_x, max_y))
+}
+
+const DELTAS: [Position; 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+fn visit(
+    position: &Position,
+    map: &mut HashMap<Position, Tile>,
+    original_map: &HashMap<Position, Tile>,
+    max_x: i64,
+    max_y: i64,
+) -> Vec<Position> {
+    let mut visited = Vec::new();
+
+    if let Some(tile) = map.get_mut(position) {
+        *tile = Tile::Plot;
+    }
+
+    DELTAS.iter().for_each(|(dx, dy)| {
+        let neighbour = (position.0 + dx, position.1 + dy);
+        if map.get(&neighbour).is_none() {
+            let map_delta_x = if neighbour.0 > -1 && neighbour.0 < max_x {
+                0
+            } else {
+                ((neighbour.0.abs() as f64) / (max_x as f64)).ceil() as i64
+                    * if neighbour.0 < 0 { -1 } else { 1 }
+            };
+            let map_delta_y = if neighbour.1 > -1 && neighbour.1 < max_y {
+                0
+            } else {
+                ((neighbour.1.abs() as f64) / (max_y as f64)).ceil() as i64
+                    * if neighbour.1 < 0 { -1 } else { 1 }
+            };
+
+            let new_min_x = map_delta_x * max_x;
+            let new_min_y = map_delta_y * max_y;
+
+            original_map.iter().for_each(|((x, y), tile)| {
+                let position = (x + new_min_x, y + new_min_y);
+                if matches!(tile, Tile::Start) {
+                    map.insert(position, Tile::Plot);
+                } else {
+                    map.insert(position, tile.clone());
+                }
+            })
+        }
+        if let Some(tile) = map.get(&neighbour) {
+            match tile {
+                Tile::Rock => {}
+                _ => {
+                    *map.get_mut(&neighbour).unwrap() = Tile::Visited;
+                    visited.push(neighbour);
+                }
+            }
+        }
+    });
+
+    visited
+}
+
+fn print_map(map: &HashMap<Position, Tile>) {
+    let min_x = *map.iter().map(|((x, _), _)| x).min().unwrap();
+    let min_y = *map.iter().map(|((_, y), _)| y).min().unwrap();
+
+    let max_x = *map.iter().map(|((x, _), _)| x).max().unwrap();
+    let max_y = *map.iter().map(|((_, y), _)| y).max().unwrap();
+
+    print!("\n");
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            if let Some(tile) = map.get(&(x, y)) {
+                print!("{tile}");
+            } else {
+                print!(" ");
+            }
+        }
+        print!("\n")
+    }
+}
+
+fn step(steps: i64, map: &HashMap<Position, Tile>, max_x: i64, max_y: i64) -> u64 {
+    let mut mut_map = map.clone();
+    let start_position = mut_map
+        .iter()
+        .find(|(_, tile)| matches!(tile, Tile::Start))
+        .unwrap()
+        .0
+        .clone();
+    let mut positions = vec![start_position];
+    let original_map = mut_map.clone();
+    
+    (1..=steps).for_each(|_| {
+        let mut new_positions = HashSet::new();
+        while let Some(visit_position) = positions.pop() {
+            let visited_positions = visit(
+                &visit_position,
+                &mut mut_map,
+                &original_map,
+                max_x,
+                max_y,
+            );
+            visited_positions.into_iter().for_each(|position| {
+                new_positions.insert(position);
+            })
+        }
+        positions = new_positions.into_iter().collect();
+    });
+    // print_map(&mut_map);
+    positions.len() as u64
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let (map, max_x, max_y) = read_input(input).unwrap();
+    let result = step(64, &map, max_x, max_y);
+
+    Some(result as u64)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let (map, max_x, max_y) = read_input(input).unwrap();
+    let max_steps = 26501365;
+    
+    let points: Vec<(i128, i128)> = (0..3)
+        .map(|i| {
+            let steps = (i * max_x) + max_steps % max_x;
+            (i as i128, step(steps, &map, max_x, max_y) as i128)
+        })
+        .collect();
+    let p = (max_steps / max_x) as i128;
+    let result = lagrange(&points, p)
+        .to_integer()
+        .expect("Plots reached should be a whole number");
+
+    Some(result as u64)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 21: Step Counter ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,148 @@
+use aoc_graph::{contract, longest_path, Graph, NodeId};
+use aoc_parse::{parse_lines, Result};
+
+type Map = Vec<Vec<Node>>;
+type Position = (usize, usize);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum SlopeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(SlopeDirection),
+}
+
+impl Tile {
+    fn from_char(char: char) -> Option<Self> {
+        match char {
+            '#' => Some(Tile::Forest),
+            '.' => Some(Tile::Path),
+            '>' => Some(Tile::Slope(SlopeDirection::Right)),
+            '<' => Some(Tile::Slope(SlopeDirection::Left)),
+            '^' => Some(Tile::Slope(SlopeDirection::Up)),
+            'v' => Some(Tile::Slope(SlopeDirection::Down)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    tile: Tile,
+}
+
+fn get_trails(map: &Map, follow_slopes: bool) -> Graph<Position> {
+    let mut trails = Graph::new();
+    for (y, row) in map.iter().enumerate() {
+        for (x, node) in row.iter().enumerate() {
+            if !matches!(node.tile, Tile::Forest) {
+                trails.add_node((x, y));
+            }
+        }
+    }
+
+    let positions: Vec<(NodeId, Position)> = trails
+        .nodes()
+        .map(|(id, &position)| (id, position))
+        .collect();
+    for (id, (x, y)) in positions {
+        let neighbours = [
+            (x.checked_sub(1).map(|x| (x, y)), SlopeDirection::Left),
+            (Some((x + 1, y)), SlopeDirection::Right),
+            (y.checked_sub(1).map(|y| (x, y)), SlopeDirection::Up),
+            (Some((x, y + 1)), SlopeDirection::Down),
+        ];
+        for (neighbour, to_direction) in neighbours {
+            let Some(neighbour) = neighbour.and_then(|position| trails.get_id(&position)) else {
+                continue;
+            };
+            let (neighbour_x, neighbour_y) = *trails.get_node(neighbour);
+            let walkable = match &map[neighbour_y][neighbour_x].tile {
+                Tile::Slope(direction) if follow_slopes => *direction == to_direction,
+                _ => true,
+            };
+            if walkable {
+                trails.add_edge(id, neighbour, 1);
+            }
+        }
+    }
+
+    trails
+}
+
+fn get_longest_path(
+    start_position: Position,
+    goal_position: Position,
+    map: &Map,
+    follow_slopes: bool,
+) -> usize {
+    let trails = get_trails(map, follow_slopes);
+    // Only junctions leave a choice, every corridor between them becomes one edge
+    let junctions = contract(&trails, |_, &(x, y)| {
+        let open_neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter(|position| trails.get_id(position).is_some())
+        .count();
+        (x, y) == start_position || (x, y) == goal_position || open_neighbours > 2
+    });
+
+    let junction = |position| {
+        junctions
+            .get_id(&trails.get_id(&position).unwrap())
+            .unwrap()
+    };
+    let start = junction(start_position);
+    let goal = junction(goal_position);
+
+    longest_path::get_length(&junctions, start, goal).unwrap()
+}
+
+fn read_input(input: &str) -> Result<Map> {
+    parse_lines(input, |line| {
+        line.map_chars(|char| {
+            Some(Node {
+                tile: Tile::from_char(char)?,
+            })
+        })
+    })
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let map = read_input(input).unwrap();
+    let path = get_longest_path((1, 0), (map.len() - 1 - 1, map.len() - 1), &map, true);
+    Some(path)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let map = read_input(input).unwrap();
+    let path = get_longest_path((1, 0), (map.len() - 1 - 1, map.len() - 1), &map, false);
+    Some(path)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 23: A Long Walk ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
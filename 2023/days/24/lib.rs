@@ -0,0 +1,154 @@
+use aoc_algebra::{gcd, solve_integer};
+use aoc_geometry::Vector3;
+use aoc_parse::{parse_lines, Result, Span};
+
+const TEST_AREA: (f64, f64) = (200000000000000_f64, 400000000000000_f64);
+
+#[derive(Debug)]
+struct Hail {
+    position: Vector3,
+    vector: Vector3,
+    a: f64,
+    b: f64,
+    c: f64,
+}
+
+impl Hail {
+    fn parse(line: Span) -> Result<Self> {
+        let (position_str, vector_str) = line.split_once(" @ ")?;
+
+        let vector: Vector3 = vector_str.parse()?;
+        let position: Vector3 = position_str.parse()?;
+        let a = vector.1 as f64;
+        let b = -vector.0 as f64;
+        let c = vector.1 as f64 * position.0 as f64 - vector.0 as f64 * position.1 as f64;
+
+        Ok(Self {
+            position,
+            vector,
+            a,
+            b,
+            c,
+        })
+    }
+
+    fn is_parallel(&self, other: &Hail) -> bool {
+        self.a * other.b == other.a * self.b
+    }
+
+    fn get_intersection(&self, other: &Hail) -> (f64, f64) {
+        let x = (self.c * other.b - other.c * self.b) / (self.a * other.b - other.a * self.b);
+        let y = (other.c * self.a - self.c * other.a) / (self.a * other.b - other.a * self.b);
+
+        (x, y)
+    }
+
+    fn is_in_future(&self, (x, y): &(f64, f64)) -> bool {
+        ((x - self.position.0 as f64) * self.vector.0 as f64) >= 0_f64
+            && ((y - self.position.1 as f64) * self.vector.1 as f64) >= 0_f64
+    }
+}
+
+fn widen(vector: Vector3) -> [i128; 3] {
+    [vector.0 as i128, vector.1 as i128, vector.2 as i128]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn get_intersection_point(hailstones: &[Hail]) -> Vector3 {
+    let [first, second, third, ..] = hailstones else {
+        panic!("Need at least three hailstones");
+    };
+    // Moving along with the first hailstone, it stays put at the origin. The
+    // rock has to pass through there, so its path lies in the plane spanned by
+    // the origin and any other hailstone's path, and two such planes meet
+    // along it.
+    let relative = |hail: &Hail| (hail.position - first.position, hail.vector - first.vector);
+    let (second, third) = (relative(second), relative(third));
+    let plane = |(position, vector): (Vector3, Vector3)| cross(widen(position), widen(vector));
+    let direction = cross(plane(second), plane(third));
+    let divisor = direction
+        .iter()
+        .fold(0, |divisor, value| gcd(divisor, *value));
+    let direction = direction.map(|value| value / divisor);
+
+    // position + time * vector = distance * direction
+    let hit = |(position, vector): (Vector3, Vector3)| {
+        let coefficients: Vec<Vec<i128>> = widen(vector)
+            .iter()
+            .zip(direction)
+            .map(|(vector, direction)| vec![*vector, -direction])
+            .collect();
+        let constants = widen(position).map(|position| -position);
+        let solution = solve_integer(&coefficients, &constants)
+            .expect("The rock should hit every hailstone at a whole time");
+        let time = solution[0] as isize;
+        (time, position + vector * time)
+    };
+    let (second_time, second_hit) = hit(second);
+    let (third_time, third_hit) = hit(third);
+
+    let vector = (third_hit - second_hit) / (third_time - second_time);
+    first.position + second_hit - vector * second_time
+}
+
+fn read_input(input: &str) -> Result<Vec<Hail>> {
+    parse_lines(input, Hail::parse)
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let hailstones = read_input(input).unwrap();
+
+    let (from, to) = TEST_AREA;
+    let mut result = 0;
+    for i in 0..hailstones.len() {
+        for j in i + 1..hailstones.len() {
+            let a = &hailstones[i];
+            let b = &hailstones[j];
+
+            if a.is_parallel(b) {
+                continue;
+            }
+
+            let intersection = a.get_intersection(b);
+            if !(from..=to).contains(&intersection.0) || !(from..=to).contains(&intersection.1) {
+                continue;
+            }
+
+            if a.is_in_future(&intersection) && b.is_in_future(&intersection) {
+                result += 1;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let hailstones = read_input(input).unwrap();
+    let intersection = get_intersection_point(&hailstones);
+    let result = (intersection.0 + intersection.1 + intersection.2) as u64;
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 24: Never Tell Me The Odds ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
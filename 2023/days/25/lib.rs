@@ -0,0 +1,47 @@
+use aoc_graph::{min_cut, Graph};
+use aoc_parse::{lines, Result};
+
+fn read_input(input: &str) -> Result<Graph<String>> {
+    let mut wires = Graph::new();
+
+    for line in lines(input) {
+        let (name, connections) = line.split_once(": ")?;
+        let component = wires.add_node(name.to_string());
+
+        for connection in connections.split_whitespace() {
+            let connection = wires.add_node(connection.to_string());
+            wires.add_undirected_edge(component, connection, 1);
+        }
+    }
+
+    Ok(wires)
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let wires = read_input(input).unwrap();
+    let cut = min_cut::stoer_wagner(&wires)?;
+    assert_eq!(cut.weight, 3, "Expected three wires to disconnect");
+
+    let (left, right) = cut.partition;
+    Some(left.len() * right.len())
+}
+
+fn part2(_input: &str) -> Option<u32> {
+    Some(2023)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 25: Snowverload ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,176 @@
+use core::num;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use aoc_geometry::{Grid, Vector2};
+use aoc_parse::{grid, Result};
+
+type Map = Grid<Tile>;
+type Position = Vector2;
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Empty,
+    Number(u8),
+    Symbol(char),
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        Some(match c {
+            '.' => Tile::Empty,
+            _ => match c.to_digit(10) {
+                Some(digit) => Tile::Number(digit as u8),
+                None => Tile::Symbol(c),
+            },
+        })
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Tile::Empty => ".".to_string(),
+            Tile::Number(digit) => digit.to_string(),
+            Tile::Symbol(symbol) => symbol.to_string(),
+        };
+        write!(f, "{c}")
+    }
+}
+
+fn get_adjacent_tiles(position: Position, map: &Map) -> Vec<(Position, Tile)> {
+    map.neighbors8(position)
+        .map(|(position, tile)| (position, *tile))
+        .collect()
+}
+
+fn read_input(input: &str) -> Result<Map> {
+    grid(input, Tile::from_char)
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let map = read_input(input).unwrap();
+    let mut part_numbers = Vec::new();
+    let mut part_number = String::new();
+    let mut is_part = false;
+
+    for (y, row) in map.rows().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            match tile {
+                Tile::Number(digit) => {
+                    part_number.push_str(digit.to_string().as_str());
+                    if !is_part {
+                        is_part = get_adjacent_tiles(Vector2(x as isize, y as isize), &map).iter().any(
+                            |(_, tile)| match tile {
+                                Tile::Symbol(_) => true,
+                                _ => false,
+                            },
+                        );
+                    }
+                }
+                _ => {
+                    if is_part {
+                        part_numbers.push(part_number.clone().parse::<u32>().unwrap());
+                        is_part = false;
+                    }
+                    part_number.clear();
+                }
+            }
+        }
+        if is_part {
+            part_numbers.push(part_number.clone().parse::<u32>().unwrap());
+            is_part = false;
+        }
+    }
+
+    let result = part_numbers.iter().sum();
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let map = read_input(input).unwrap();
+    let mut part_numbers: HashMap<Position, u32> = HashMap::default();
+    let mut part_number = String::new();
+    let mut part_number_pos = Vec::new();
+    let mut is_gear_part = false;
+    let mut gears: HashMap<Position, HashSet<Position>> = HashMap::default();
+
+    for (y, row) in map.rows().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            let tile_position = Vector2(x as isize, y as isize);
+            match tile {
+                Tile::Number(digit) => {
+                    part_number.push_str(digit.to_string().as_str());
+                    part_number_pos.push(tile_position);
+                    if !is_gear_part {
+                        is_gear_part = get_adjacent_tiles(tile_position, &map).iter().any(
+                            |(position, tile)| match tile {
+                                Tile::Symbol('*') => {
+                                    if let Some(part_numbers) = gears.get_mut(position) {
+                                        part_numbers.insert(tile_position);
+                                    } else {
+                                        gears.insert(*position, HashSet::from([tile_position]));
+                                    }
+                                    true
+                                }
+                                _ => false,
+                            },
+                        );
+                    }
+                }
+                _ => {
+                    if is_gear_part {
+                        let part_no = part_number.clone().parse::<u32>().unwrap();
+                        part_number_pos.iter().for_each(|pos| {
+                            part_numbers.insert(*pos, part_no);
+                        });
+                    }
+                    is_gear_part = false;
+                    part_number_pos.clear();
+                    part_number.clear();
+                }
+            }
+        }
+        if is_gear_part {
+            let part_no = part_number.clone().parse::<u32>().unwrap();
+            part_number_pos.iter().for_each(|pos| {
+                part_numbers.insert(*pos, part_no);
+            });
+        }
+        is_gear_part = false;
+        part_number_pos.clear();
+        part_number.clear();
+    }
+
+    let mut sum: u32 = 0;
+    for (_, digit_position) in gears.iter() {
+        if digit_position.len() > 2 || digit_position.len() < 2 {
+            continue;
+        }
+        
+        sum += digit_position
+            .iter()
+            .map(|digit_position| *part_numbers.get(digit_position).unwrap())
+            .fold(1, |acc, num| acc * num);
+    }
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 3: Gear Ratios ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
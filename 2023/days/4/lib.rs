@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use aoc_parse::{parse_lines, Result};
+
+type Card = (Vec<u32>, Vec<u32>);
+
+fn read_input(input: &str) -> Result<Vec<Card>> {
+    parse_lines(input, |line| {
+        let (_, numbers) = line.split_once(":")?;
+        let (my_numbers, winning_numbers) = numbers.split_once("|")?;
+        Ok((my_numbers.list(" ")?, winning_numbers.list(" ")?))
+    })
+}
+
+fn part1(input: &str) -> Option<u32> {
+    let cards = read_input(input).unwrap();
+    let result: u32 = cards
+        .iter()
+        .map(|(mine, winning)| mine.iter().filter(|num| winning.contains(num)).count() as u32)
+        .filter(|count| *count > 0)
+        .map(|count| {
+            if count == 1 {
+                1
+            } else {
+                (0..(count - 1)).fold(1 as u32, |prev, _| prev * 2)
+            }
+        })
+        .sum();
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u32> {
+    let cards = read_input(input).unwrap();
+    let mut results: HashMap<usize, (u32, u32)> = cards
+        .iter()
+        .map(|(mine, winning)| mine.iter().filter(|num| winning.contains(num)).count() as u32)
+        .enumerate()
+        .map(|(id, count)| {
+            (
+                id,
+                (
+                    1,
+                    count,
+                ),
+            )
+        })
+        .collect();
+
+    for card_id in 0..cards.len() {
+        let (card_count, card_result) = results.get(&card_id).unwrap().clone();
+
+        (1..=card_result).for_each(|i| {
+            if let Some(card) = results.get_mut(&(card_id + i as usize)) {
+                card.0 += card_count;
+            }
+        })
+    }
+
+    let result = results.iter().map(|(_, (count, _))| count).sum();
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 4: Scratchcards ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use aoc_interval::{Interval, IntervalSet, PiecewiseMap};
+use aoc_parse::{sections, single_line, Result, Section};
+
+#[derive(Debug)]
+struct Almenac {
+    source: String,
+    destination: String,
+    map: PiecewiseMap<u64>,
+}
+
+impl Almenac {
+    fn parse(section: &Section) -> Result<Self> {
+        let mut lines = section.lines();
+        let source_destination_line = lines
+            .next()
+            .ok_or_else(|| section.error("Expected a map"))?;
+        let (source, destination) = source_destination_line
+            .strip_suffix(" map:")?
+            .split_once("-to-")?;
+
+        let mut map = PiecewiseMap::new();
+        for range_line in lines {
+            let [destination_start, source_start, range_count] = range_line.ints_array()?;
+            map.insert(
+                Interval::with_length(source_start, range_count),
+                destination_start,
+            );
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            map,
+        })
+    }
+}
+
+fn read_input(input: &str) -> Result<(Vec<u64>, HashMap<String, Almenac>)> {
+    let seeds = single_line(input)?
+        .strip_prefix("seeds:")?
+        .list::<u64>(" ")?;
+
+    let mut almenacs: HashMap<String, Almenac> = HashMap::default();
+    for section in sections(input).iter().skip(1) {
+        let almenac = Almenac::parse(section)?;
+        almenacs.insert(almenac.source.clone(), almenac);
+    }
+
+    Ok((seeds, almenacs))
+}
+
+/// Every almenac from seed to location, in order.
+fn get_chain(almenacs: &HashMap<String, Almenac>) -> Vec<&Almenac> {
+    let mut chain = Vec::new();
+    let mut almenac_name = "seed";
+    while let Some(almenac) = almenacs.get(almenac_name) {
+        chain.push(almenac);
+        almenac_name = &almenac.destination;
+    }
+    chain
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let (seeds, almenacs) = read_input(input).unwrap();
+    let chain = get_chain(&almenacs);
+    let result = seeds
+        .iter()
+        .map(|seed| {
+            chain
+                .iter()
+                .fold(*seed, |number, almenac| almenac.map.get(number))
+        })
+        .min();
+
+    result
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let (seeds, almenacs) = read_input(input).unwrap();
+    let seed_ranges: IntervalSet<u64> = seeds
+        .chunks(2)
+        .map(|range| Interval::with_length(range[0], range[1]))
+        .collect();
+
+    let locations = get_chain(&almenacs)
+        .iter()
+        .fold(seed_ranges, |ranges, almenac| almenac.map.apply(&ranges));
+
+    locations.min()
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 5: If You Give A Seed A Fertilizer ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
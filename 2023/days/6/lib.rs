@@ -0,0 +1,73 @@
+use aoc_parse::{lines, Result};
+
+fn read_input(input: &str) -> Result<(Vec<u64>, Vec<u64>)> {
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for line in lines(input) {
+        let (key, value) = line.key_value()?;
+        match key.as_str() {
+            "Time" => times = value.list(" ")?,
+            "Distance" => distances = value.list(" ")?,
+            _ => return Err(key.error(format!("Unknown key {key}"))),
+        }
+    }
+
+    Ok((times, distances))
+}
+
+fn get_duration(distance: u64, race_time: u64) -> f64 {
+    ((race_time as f64 - ((race_time.pow(2) - 4 * distance) as f64).sqrt()) / 2 as f64).floor()
+}
+
+fn get_max_duration(race_time: u64) -> f64 {
+    race_time as f64 / 2_f64
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let (times, distances) = read_input(input).unwrap();
+    
+    let races = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(a, b)| (*a, *b))
+        .collect::<Vec<(u64, u64)>>();
+
+    let result: f64 = races
+        .iter()
+        .map(|(race_time, record_distance)| {
+            let record_duration = get_duration(*record_distance, *race_time);
+            let max_duration = get_max_duration(*race_time);
+            ((max_duration - record_duration) * 2_f64) - 1_f64
+        })
+        .product();
+
+    Some(result as u64)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let (times, distances) = read_input(input).unwrap();
+    let race_time = times.iter().fold("".to_string(),|prev, time| format!("{prev}{time}")).parse::<u64>().unwrap();
+    let record_distance = distances.iter().fold("".to_string(),|prev, distance| format!("{prev}{distance}")).parse::<u64>().unwrap();
+
+    let record_duration = get_duration(record_distance, race_time);
+    let max_duration = get_max_duration(race_time);
+    let result = (((max_duration - record_duration) * 2_f64) - 1_f64) as u64;
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 6: Wait For It ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use aoc_parse::{lines, single_line, Pattern, Result, Span};
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn parse(pattern: &Pattern, line: Span) -> Result<Self> {
+        let [id, left, right] = pattern.parse(line)?;
+
+        Ok(Self { id, left, right })
+    }
+}
+
+fn read_input(input: &str) -> Result<(Vec<char>, HashMap<String, Node>)> {
+    let directions = single_line(input)?.as_str().chars().collect();
+
+    let pattern = Pattern::new("<id> = (<left>, <right>)");
+    let nodes = lines(input)
+        .skip(1)
+        .map(|line| Node::parse(&pattern, line))
+        .map(|node| node.map(|node| (node.id.clone(), node)))
+        .collect::<Result<_>>()?;
+
+    Ok((directions, nodes))
+}
+
+fn gcd(one: u64, another: u64) -> u64 {
+    let mut a = one;
+    let mut b = another;
+
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+fn lcm(one: u64, another: u64) -> u64 {
+    let gcd = gcd(one, another);
+    (one * another) / gcd
+}
+
+fn lcm_vec(numbers: Vec<u64>) -> u64 {
+    numbers
+        .iter()
+        .map(|num| *num)
+        .reduce(|one, another| lcm(one, another))
+        .unwrap()
+}
+
+fn walk(
+    from: &String,
+    direction_index: u64,
+    nodes: &HashMap<String, Node>,
+    directions: &Vec<char>,
+) -> String {
+    let node = nodes.get(from).unwrap();
+    let next_node = match directions[direction_index as usize % directions.len()] {
+        'R' => &node.right,
+        'L' => &node.left,
+        _ => panic!("Should not be here"),
+    };
+
+    next_node.clone()
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let (directions, nodes) = read_input(input).unwrap();
+    let mut from = "AAA".to_string();
+    let destination = "ZZZ".to_string();
+    let mut result = 0;
+
+    for direction_index in 0.. {
+        from = walk(&from, direction_index, &nodes, &directions);
+        if from == destination {
+            result = direction_index + 1;
+            break;
+        }
+    }
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let (directions, nodes) = read_input(input).unwrap();
+    let mut ghost_cycles = nodes
+        .iter()
+        .filter(|(id, _)| id.chars().last().unwrap() == 'A')
+        .map(|(id, _)| (id.clone(), 0_u64))
+        .collect::<Vec<(String, u64)>>();
+
+    let mut ghosts = ghost_cycles
+        .iter()
+        .map(|(id, _)| id.clone())
+        .collect::<Vec<String>>();
+
+    let mut ghosts_found_exit = 0;
+    for direction_index in 0.. {
+        for ghost_index in 0..ghosts.len() {
+            if ghost_cycles[ghost_index].1 > 0 {
+                continue;
+            }
+
+            let ghost = ghosts[ghost_index].clone();
+            let next_node = walk(&ghost, direction_index, &nodes, &directions);
+            if next_node.chars().last().unwrap() == 'Z' {
+                ghost_cycles[ghost_index].1 = direction_index + 1;
+                ghosts_found_exit += 1;
+            }
+
+            ghosts[ghost_index] = next_node;
+        }
+
+        if ghosts_found_exit == ghosts.len() {
+            break;
+        }
+    }
+    
+    let cycles = ghost_cycles
+        .iter()
+        .map(|(_, cycle)| *cycle)
+        .collect::<Vec<u64>>();
+
+    let result = lcm_vec(cycles);
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 8: Haunted Wasteland ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
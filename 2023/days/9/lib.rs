@@ -0,0 +1,46 @@
+use aoc_parse::{parse_lines, Result};
+
+fn read_input(input: &str) -> Result<Vec<Vec<i32>>> {
+  parse_lines(input, |line| line.list(" "))
+}
+
+fn find_prev_next(sequence: &Vec<i32>) -> (i32, i32) {
+  let new_sequence = sequence.windows(2).map(|nums| nums[1] - nums[0]).collect::<Vec<i32>>();
+  
+  if new_sequence.iter().any(|num| *num != 0) {
+    let (next_prev, next_last) = find_prev_next(&new_sequence);
+    (sequence.first().unwrap() - next_prev, next_last + sequence.last().unwrap())
+  } else {
+    (*sequence.first().unwrap(), *sequence.last().unwrap())
+  }
+}
+
+fn part1(input: &str) -> Option<i32> {
+  let sequences = read_input(input).unwrap();
+  let result = sequences.iter().map(|sequence| find_prev_next(&sequence).1).sum();
+
+  Some(result)
+}
+
+fn part2(input: &str) -> Option<i32> {
+  let sequences = read_input(input).unwrap();
+  let result = sequences.iter().map(|sequence| find_prev_next(&sequence).0).sum();
+
+  Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 9: Mirage Maintenance ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
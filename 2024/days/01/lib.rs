@@ -0,0 +1,55 @@
+use aoc_parse::{lines, Result};
+
+fn read_input(input: &str) -> Result<(Vec<isize>, Vec<isize>)> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for line in lines(input) {
+        let [left_id, right_id] = line.ints_array()?;
+
+        left.push(left_id);
+        right.push(right_id);
+    }
+
+    Ok((left, right))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let (mut left, mut right) = read_input(input).unwrap();
+
+    left.sort();
+    right.sort();
+
+    Some(
+        left.iter()
+            .zip(right.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum(),
+    )
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let (left, right) = read_input(input).unwrap();
+
+    Some(
+        left.iter()
+            .map(|id| id * right.iter().filter(|&right_id| id == right_id).count() as isize)
+            .sum(),
+    )
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 1: Historian Hysteria ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
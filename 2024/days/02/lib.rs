@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+use aoc_parse::{parse_lines, Result};
+
+fn read_input(input: &str) -> Result<Vec<Vec<isize>>> {
+    parse_lines(input, |line| line.list(" "))
+}
+
+fn get_failed_indices(report: &Vec<isize>) -> Vec<usize> {
+    let ordering = report.first().unwrap().cmp(report.last().unwrap());
+    let mut failed_index = HashSet::new();
+
+    for i in 0..report.len() {
+        match (report[i], report.get(i + 1)) {
+            (level, Some(next_level)) => {
+                let diff = (level - next_level).abs();
+                if level.cmp(next_level) != ordering || diff < 1 || diff > 3 {
+                    failed_index.insert(i);
+                    failed_index.insert(i + 1);
+                }
+            }
+            (_, None) => {}
+        }
+    }
+    failed_index.into_iter().collect()
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let reports = read_input(input).unwrap();
+    let safe_reports = reports.iter().filter(|report| get_failed_indices(&report).len() == 0).count();
+    Some(safe_reports)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let reports = read_input(input).unwrap();
+    let mut safe_reports = 0;
+    for report in reports {
+        let failed_indices = get_failed_indices(&report);
+        if failed_indices.len() == 0 {
+            safe_reports += 1;
+        } else {
+            for skip_index in failed_indices.iter() {
+                let modified_report = report
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, v)| if *skip_index == i { None } else { Some(*v) })
+                    .collect();
+
+                if get_failed_indices(&modified_report).len() == 0 {
+                    safe_reports += 1;
+                    break;
+                }
+            }
+        }
+    }
+    Some(safe_reports)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 2: Red-Nosed Reports ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
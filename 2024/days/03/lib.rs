@@ -0,0 +1,64 @@
+use aoc_parse::{lines, Result, Span};
+use regex::Regex;
+
+#[derive(Debug)]
+enum Instruction {
+    Mul(isize, isize),
+}
+
+impl Instruction {
+    fn exec(instruction: Instruction) -> isize {
+        match instruction {
+            Self::Mul(a, b) => a * b,
+        }
+    }
+}
+
+fn read_input(input: &str, mul_override: bool) -> Result<Vec<Instruction>> {
+    let mut is_enabled = true;
+    let mut instructions = Vec::new();
+    let instruction_regex = Regex::new(r"mul\(\d+,\d+\)|do\(\)|don't\(\)").unwrap();
+
+    for line in lines(input) {
+        for found in instruction_regex.find_iter(line.as_str()) {
+            match found.as_str() {
+                "do()" => is_enabled = true,
+                "don't()" => is_enabled = false,
+                _ if is_enabled || mul_override => {
+                    let mul = Span::new(found.as_str(), line.line(), line.column() + found.start());
+                    let [a, b] = mul.ints_array()?;
+                    instructions.push(Instruction::Mul(a, b));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(instructions)
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let instructions = read_input(input, true).unwrap();
+    Some(instructions.into_iter().map(Instruction::exec).sum())
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let instructions = read_input(input, false).unwrap();
+    Some(instructions.into_iter().map(Instruction::exec).sum())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 3: Mull It Over ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
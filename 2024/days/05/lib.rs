@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use aoc_graph::{topo_sort, Graph};
+use aoc_parse::{sections, Error, Result, Span};
+
+type Rules = HashMap<usize, Vec<usize>>;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Manual(Vec<usize>);
+
+impl Manual {
+    fn parse(line: Span) -> Result<Self> {
+        Ok(Self(line.list(",")?))
+    }
+
+    /// Pages reordered to follow every rule between them. The full rule set
+    /// loops, only the rules between pages of one manual form an order.
+    fn to_sorted(&self, rules: &Rules) -> Self {
+        let mut order: Graph<usize> = Graph::new();
+        for page in self.0.iter() {
+            order.add_node(*page);
+        }
+        for (id, page) in self.0.iter().enumerate() {
+            for before in rules.get(page).into_iter().flatten() {
+                if let Some(before) = order.get_id(before) {
+                    order.add_edge(before, id, 1);
+                }
+            }
+        }
+
+        let pages = topo_sort(&order).expect("Rules of a manual don't loop");
+        Self(pages.into_iter().map(|id| *order.get_node(id)).collect())
+    }
+
+    fn value(&self) -> usize {
+        self.0[(self.0.len() - 1) / 2]
+    }
+}
+
+fn read_input(input: &str) -> Result<(Rules, Vec<Manual>)> {
+    let sections = sections(input);
+    let [rule_section, manual_section] = &sections[..] else {
+        return Err(Error::new(1, 1, "Expected rules and manuals"));
+    };
+
+    let mut rules: Rules = HashMap::new();
+    for line in rule_section.lines() {
+        let (x, y) = line.split_once("|")?;
+
+        let rule_entry = rules.entry(y.parse()?).or_insert(Vec::new());
+        rule_entry.push(x.parse()?);
+    }
+    let pages = manual_section
+        .lines()
+        .map(Manual::parse)
+        .collect::<Result<_>>()?;
+
+    Ok((rules, pages))
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let (rules, manuals) = read_input(input).unwrap();
+    let mut result = 0;
+
+    for manual in manuals {
+        let sorted_manual = manual.to_sorted(&rules);
+        if manual == sorted_manual {
+            result += manual.value();
+        }
+    }
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let (rules, manuals) = read_input(input).unwrap();
+    let mut result = 0;
+
+    for manual in manuals {
+        let sorted_manual = manual.to_sorted(&rules);
+        if manual != sorted_manual {
+            result += sorted_manual.value();
+        }
+    }
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 5: Print Queue ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,155 @@
+use rayon::prelude::*;
+use std::{collections::HashSet, fmt::Display};
+use aoc_geometry::{Grid, Vector2, DOWN, LEFT, RIGHT, UP};
+use aoc_parse::grid;
+
+type Map = Grid<Tile>;
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Guard(Vector2),
+    Air,
+    Obstruction,
+    Visited,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Tile::Air),
+            '#' => Some(Tile::Obstruction),
+            '^' => Some(Tile::Guard(UP)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Obstruction => write!(f, "#"),
+            Tile::Guard(direction) => match *direction {
+                UP => write!(f, "^"),
+                DOWN => write!(f, "v"),
+                LEFT => write!(f, "<"),
+                RIGHT => write!(f, ">"),
+                _ => panic!("Invalid direction"),
+            },
+            Tile::Visited => write!(f, "X"),
+        }
+    }
+}
+
+fn step(guard: &Vector2, map: &mut Map) -> Result<Vector2, ()> {
+    let mut guard_position = *guard;
+    loop {
+        match map.get(&guard_position).copied() {
+            Some(Tile::Guard(direction)) => {
+                let new_position = guard_position + direction;
+                match map.get(&new_position).copied() {
+                    Some(Tile::Air) | Some(Tile::Visited) => {
+                        map[guard_position] = Tile::Visited;
+                        map[new_position] = Tile::Guard(direction);
+
+                        guard_position = new_position;
+                    }
+                    Some(Tile::Obstruction) => {
+                        map[guard_position] = Tile::Guard(direction.rot_right_90());
+                        return Ok(guard_position);
+                    }
+                    Some(_) => {
+                        panic!("Invalid tile")
+                    }
+                    None => {
+                        map[guard_position] = Tile::Visited;
+                        return Err(());
+                    }
+                }
+            }
+            _ => {
+                panic!("Invalid guard tile")
+            }
+        }
+    }
+}
+
+fn is_looped(guard: &Vector2, map: &mut Map) -> bool {
+    let mut corner_mem = HashSet::new();
+    let mut guard_position = *guard;
+
+    while let Ok(new_position) = step(&guard_position, map) {
+        guard_position = new_position;
+        match map[guard_position] {
+            Tile::Guard(dir) => {
+                if !corner_mem.insert((guard_position, dir)) {
+                    return true;
+                }
+            }
+            _ => panic!("Oh dear"),
+        }
+    }
+    return false;
+}
+
+fn read_input(input: &str) -> aoc_parse::Result<(Map, Vector2)> {
+    let map = grid(input, Tile::from_char)?;
+    let guard = map
+        .find(|tile| matches!(tile, Tile::Guard(_)))
+        .unwrap_or(UP);
+
+    Ok((map, guard))
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let (mut map, mut guard) = read_input(input).unwrap();
+    while let Ok(new_position) = step(&guard, &mut map) {
+        guard = new_position;
+    }
+    let result = map
+        .tiles()
+        .filter(|tile| match tile {
+            Tile::Visited => true,
+            _ => false,
+        })
+        .count();
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let (map, guard) = read_input(input).unwrap();
+    let map_clone = map.clone();
+
+    let candidates = map_clone.iter().filter_map(|(position, tile)| match tile {
+        Tile::Air => Some(position),
+        _ => None,
+    });
+
+    let result = candidates
+        .par_bridge()
+        .filter(|position| {
+            let mut map_clone = map.clone();
+            map_clone[*position] = Tile::Obstruction;
+            is_looped(&guard, &mut map_clone)
+        })
+        .count();
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 6: Guard Gallivant ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_parse::{parse_lines, Span};
+
+#[derive(Debug)]
+struct Equation {
+  test_value: usize,
+  values: Vec<usize>
+}
+
+enum Operator {
+  Mul,
+  Add,
+  Concat
+}
+
+impl Operator {
+    fn exec(&self, one: usize, another: usize) -> usize {
+      match self {
+          Operator::Add => one + another,
+          Operator::Mul => one * another,
+          Operator::Concat => format!("{one}{another}").parse().unwrap()
+      }
+    }
+}
+
+impl Equation {
+    fn parse(line: Span) -> aoc_parse::Result<Self> {
+      let (test_value_str, values_str) = line.split_once(": ")?;
+      let values = values_str.list(" ")?;
+      let test_value = test_value_str.parse()?;
+
+      Ok(Self {
+        test_value,
+        values
+      })
+    }
+}
+
+fn validate_equation(target_value: usize, a: usize, values: &[usize], operators: &[Operator]) -> Result<(), ()> {
+  if target_value == a && values.len() == 0 {
+    return Ok(())
+  } else if values.len() == 0 {
+    return Err(())
+  }
+
+  let b = values[0];
+  let new_values = &values[1..];
+
+  for op in operators {
+    let new_a = op.exec(a, b);
+    match validate_equation(target_value, new_a, new_values, operators) {
+      Ok(_) => return Ok(()),
+      Err(_) => {}
+    }
+  }
+
+  Err(())
+}
+
+fn read_input(input: &str) -> aoc_parse::Result<Vec<Equation>> {
+  parse_lines(input, Equation::parse)
+}
+
+fn part1(input: &str) -> Option<isize> {
+  let equations = read_input(input).unwrap();
+  let mut result = 0;
+  for eq in equations.iter() {
+    match validate_equation(eq.test_value, eq.values[0], &eq.values[1..], &[Operator::Mul, Operator::Add]) {
+        Ok(_) => {
+          result += eq.test_value as isize;
+        },
+        Err(_) => {}
+    }
+  }
+  Some(result)
+}
+
+fn part2(input: &str) -> Option<isize> {
+  let equations = read_input(input).unwrap();
+  let mut result = 0;
+  for eq in equations.iter() {
+    match validate_equation(eq.test_value, eq.values[0], &eq.values[1..], &[Operator::Mul, Operator::Add, Operator::Concat]) {
+        Ok(_) => {
+          result += eq.test_value as isize;
+        },
+        Err(_) => {}
+    }
+  }
+  Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 7: Bridge Repair ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,219 @@
+#[cfg(feature = "visualize")]
+use crossterm::{
+    cursor,
+    style::{self, Stylize},
+    terminal, ExecutableCommand, QueueableCommand,
+};
+use std::{collections::HashMap, fmt::Display};
+#[cfg(feature = "visualize")]
+use std::{
+    io::{self, Write},
+    thread::sleep,
+    time::Duration,
+};
+use aoc_geometry::{Grid, Vector2};
+use aoc_parse::grid_with_position;
+#[cfg(feature = "visualize")]
+use aoc_geometry::ZERO;
+
+
+#[derive(Debug)]
+enum Tile {
+    Space,
+    Antenna(char, usize),
+    Antinode,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Space => write!(f, "."),
+            Tile::Antinode => write!(f, "#"),
+            Tile::Antenna(name, _) => write!(f, "{name}"),
+        }
+    }
+}
+
+type Map = Grid<Tile>;
+type Antennas = HashMap<char, Vec<Vector2>>;
+
+fn read_input(input: &str) -> aoc_parse::Result<(Map, Antennas)> {
+    let mut antennas: Antennas = HashMap::new();
+    let map = grid_with_position(input, |position, tile_char| match tile_char {
+        '.' => Some(Tile::Space),
+        antenna_name if antenna_name.is_ascii_alphanumeric() => {
+            let antenna = antennas.entry(antenna_name).or_insert(Vec::new());
+            antenna.push(position);
+            Some(Tile::Antenna(antenna_name, 0))
+        }
+        _ => None,
+    })?;
+
+    Ok((map, antennas))
+}
+
+#[cfg(feature = "visualize")]
+fn print_map(map: &Map, a: &Vector2, b: &Vector2, c: &Vector2) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout();
+
+    for (position, tile) in map.iter() {
+        stdout.queue(cursor::MoveTo(position.0 as u16, position.1 as u16))?;
+
+        match tile {
+            Tile::Space => stdout.queue(style::PrintStyledContent(".".dark_grey())),
+            Tile::Antinode => {
+                if *c == position {
+                    stdout.queue(style::PrintStyledContent("#".red()))
+                } else {
+                    stdout.queue(style::PrintStyledContent("#".dark_red()))
+                }
+            }
+            Tile::Antenna(name, count) => {
+                if *a == position || *b == position {
+                    stdout.queue(style::PrintStyledContent(name.green()))
+                } else if *count > 0 {
+                    stdout.queue(style::PrintStyledContent(name.dark_red()))
+                } else {
+                    stdout.queue(style::PrintStyledContent(name.grey()))
+                }
+            }
+        }?;
+    }
+    println!("\n");
+    stdout.flush()?;
+
+    sleep(Duration::from_millis(50));
+
+    Ok(())
+}
+
+fn add_antinode<'a>(position: &Vector2, map: &'a mut Map) -> Result<(), ()> {
+    match map.get_mut(position) {
+        Some(ref mut tile) => match tile {
+            Tile::Space => {
+                **tile = Tile::Antinode;
+                Ok(())
+            }
+            Tile::Antenna(_, ref mut count) => {
+                *count += 1;
+                Ok(())
+            }
+            Tile::Antinode => Ok(()),
+        },
+        None => Err(()),
+    }
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let (mut map, antennas) = read_input(input).unwrap();
+    #[cfg(feature = "visualize")]
+    io::stdout()
+        .execute(terminal::Clear(terminal::ClearType::All))
+        .unwrap();
+
+    for positions in antennas.values() {
+        let positions_clone = positions.clone();
+
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions_clone[i + 1..] {
+                let c_direction = a.get_direction(b);
+                let normal = c_direction.normalize();
+
+                let c_1 = *b + normal;
+                match add_antinode(&c_1, &mut map) {
+                    Ok(_) => {
+                        #[cfg(feature = "visualize")]
+                        print_map(&map, a, b, &c_1).unwrap();
+                    }
+                    Err(_) => {}
+                }
+
+                let c_2 = *a - normal;
+                match add_antinode(&c_2, &mut map) {
+                    Ok(_) => {
+                        #[cfg(feature = "visualize")]
+                        print_map(&map, a, b, &c_2).unwrap();
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+    }
+
+    let mut res = 0;
+    for tile in map.tiles() {
+        match tile {
+            Tile::Antinode => {
+                res += 1;
+            }
+            Tile::Antenna(_, count) => {
+                if *count > 0 {
+                    res += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(res)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let (mut map, antennas) = read_input(input).unwrap();
+
+    #[cfg(feature = "visualize")]
+    io::stdout()
+        .execute(terminal::Clear(terminal::ClearType::All))
+        .unwrap();
+
+    for positions in antennas.values() {
+        let positions_clone = positions.clone();
+
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions_clone[i + 1..] {
+                let direction = a.get_direction(b);
+                let normal = direction.normalize();
+
+                let mut c_1 = *b + normal;
+                while let Ok(_) = add_antinode(&c_1, &mut map) {
+                    c_1 += normal;
+                }
+
+                let mut c_2 = *a - normal;
+                while let Ok(_) = add_antinode(&c_2, &mut map) {
+                    c_2 -= normal;
+                }
+
+                #[cfg(feature = "visualize")]
+                print_map(&map, a, b, &ZERO).unwrap();
+            }
+        }
+    }
+
+    let mut res = 0;
+    for tile in map.tiles() {
+        match tile {
+            Tile::Antinode | Tile::Antenna(_, _) => {
+                res += 1;
+            }
+            _ => {}
+        }
+    }
+
+    Some(res)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 8: Resonant Collinearity ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
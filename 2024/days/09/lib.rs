@@ -0,0 +1,145 @@
+use std::rc::Rc;
+
+use aoc_parse::{single_line, Result};
+
+type Mem = Vec<Option<Rc<File>>>;
+type Files = Vec<Rc<File>>;
+
+#[derive(Debug, Clone)]
+struct File {
+    id: usize,
+    mem_ptr: Vec<usize>,
+    size: usize,
+}
+
+fn read_input(input: &str) -> Result<(Mem, Files)> {
+    let mem_line = single_line(input)?;
+
+    let mut mem = Vec::new();
+    let mut files = Vec::new();
+
+    for (id, sizes) in mem_line
+        .map_chars(|c| c.to_digit(10))?
+        .chunks(2)
+        .enumerate()
+    {
+        let mem_ptr = mem.len();
+
+        let size = sizes[0] as usize;
+
+        let file = Rc::new(File {
+            id,
+            mem_ptr: (mem_ptr..mem_ptr + size).collect(),
+            size,
+        });
+
+        for _ in 0..file.size {
+            mem.push(Some(Rc::clone(&file)));
+        }
+        files.push(file);
+
+        if let Some(&free_size) = sizes.get(1) {
+            for _ in 0..free_size {
+                mem.push(None);
+            }
+        }
+    }
+
+    Ok((mem, files))
+}
+
+fn file_fragment(mem: &mut Mem, files: &mut Files) {
+    files.reverse();
+    let mut empty_mem_ptr = 0;
+
+    for file in files.iter_mut() {
+        for file_mem_ptr in 0..file.size {
+            if empty_mem_ptr >= file.mem_ptr[file_mem_ptr] {
+                return;
+            }
+
+            for mem_ptr in empty_mem_ptr..mem.len() {
+                match mem.get(mem_ptr).unwrap() {
+                    Some(_) => {
+                        empty_mem_ptr += 1;
+                    }
+                    None => {
+                        *mem.get_mut(mem_ptr).unwrap() = Some(Rc::clone(file));
+                        *mem.get_mut(file.mem_ptr[file_mem_ptr]).unwrap() = None;
+
+                        Rc::make_mut(file).mem_ptr[file_mem_ptr] = empty_mem_ptr;
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn file_move(mem: &mut Mem, files: &mut Files) {
+    files.reverse();
+
+    for file in files.iter_mut() {
+        for mem_ptr in 0..mem.len() {
+            if mem_ptr >= file.mem_ptr[0] {
+                break;
+            }
+            if (mem_ptr..mem_ptr + file.size)
+                .all(|block_mem_ptr| mem.get(block_mem_ptr).is_some_and(|block| block.is_none()))
+            {
+                for i in 0..file.size {
+                    mem[mem_ptr + i] = Some(Rc::clone(&file));
+                    mem[file.mem_ptr[i]] = None;
+                    Rc::make_mut(file).mem_ptr[i] = mem_ptr + i;
+                }
+                break;
+            }
+        }
+    }
+}
+
+fn checksum(mem: &Mem) -> usize {
+    mem.iter()
+        .enumerate()
+        .filter_map(|(pos, space)| {
+            if let Some(file) = space {
+                Some(file.id * pos)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let (mut mem, mut files) = read_input(input).unwrap();
+    file_fragment(&mut mem, &mut files);
+
+    let result = checksum(&mem);
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let (mut mem, mut files) = read_input(input).unwrap();
+    file_move(&mut mem, &mut files);
+
+    let result = checksum(&mem);
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 9: Disk Fragmenter ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_geometry::{Vector2, DIRECTIONS};
+use aoc_graph::{bfs, dijkstra, Edge, Graph, NodeId};
+use aoc_parse::{lines, Result};
+use hashbrown::HashMap;
+use rayon::prelude::*;
+use std::usize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CaveTile(Vector2, u8);
+
+fn read_input(input: &str) -> Result<(Graph<CaveTile>, HashMap<u8, Vec<NodeId>>)> {
+    let mut graph = Graph::new();
+    let mut nodes = HashMap::new();
+    let mut tiles = HashMap::new();
+    for (y, row) in lines(input).enumerate() {
+        let heights = row.map_chars(|height| match height {
+            '.' => Some(None),
+            _ => height.to_digit(10).map(Some),
+        })?;
+        for (x, height) in heights.into_iter().enumerate() {
+            let Some(height) = height else {
+                continue;
+            };
+
+            let tile = CaveTile(Vector2(x as isize, y as isize), height as u8);
+            let node = graph.add_node(tile.clone());
+            nodes.insert(tile.0, node);
+
+            let same_height = tiles.entry(tile.1).or_insert(Vec::new());
+            same_height.push(node);
+        }
+    }
+
+    for (&position, &node) in nodes.iter() {
+        for direction in DIRECTIONS.iter() {
+            match nodes.get(&(position + *direction)) {
+                Some(&destination)
+                    if graph.get_node(destination).1.abs_diff(graph.get_node(node).1) == 1 =>
+                {
+                    graph.add_edge(node, destination, 1);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok((graph, tiles))
+}
+
+fn is_uphill(graph: &Graph<CaveTile>, edge: &Edge) -> bool {
+    graph.get_node(edge.origin).1 < graph.get_node(edge.destination).1
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let (graph, tiles) = read_input(input).unwrap();
+    let mut trails = Vec::new();
+
+    for start_tile in tiles.get(&0).unwrap().iter() {
+        for end_tile in tiles.get(&9).unwrap().iter() {
+            trails.push((*start_tile, *end_tile));
+        }
+    }
+
+    let result = trails
+        .par_iter()
+        .filter(|(start, end)| {
+            dijkstra::get_path(&graph, *start, *end, |edge| is_uphill(&graph, edge)).is_some()
+        })
+        .count() as isize;
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let (graph, tiles) = read_input(input).unwrap();
+    let mut trails = Vec::new();
+
+    for start_tile in tiles.get(&0).unwrap().iter() {
+        for end_tile in tiles.get(&9).unwrap().iter() {
+            trails.push((*start_tile, *end_tile));
+        }
+    }
+
+    let result = trails
+        .into_par_iter()
+        .filter(|(start, end)| {
+            dijkstra::get_path(&graph, *start, *end, |edge| is_uphill(&graph, edge)).is_some()
+        })
+        .map(|(start, end)| {
+            bfs::get_paths(&graph, start, end, usize::MAX, |edge| {
+                is_uphill(&graph, edge)
+            })
+            .len()
+        })
+        .sum::<usize>() as isize;
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 10: Hoof It ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
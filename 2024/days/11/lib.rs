@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use aoc_parse::{single_line, Result};
+
+type Stones = Vec<usize>;
+
+fn read_input(input: &str) -> Result<Stones> {
+    single_line(input)?.list(" ")
+}
+
+fn step(state: &HashMap<usize, isize>) -> HashMap<usize, isize> {
+    let mut new_state = HashMap::new();
+
+    for stone in state.keys() {
+        match stone {
+            stone if *stone == 0 => {
+                *new_state.entry(1).or_insert(0) += state.get(stone).or_else(|| Some(&1)).unwrap();
+            }
+            stone if format!("{stone}").len() % 2 == 0 => {
+                let stone_str = format!("{stone}");
+                let (left_str, right_str) = stone_str.split_at(stone_str.len() / 2);
+
+                *new_state.entry(left_str.parse().unwrap()).or_insert(0) +=
+                    state.get(stone).or_else(|| Some(&1)).unwrap();
+                *new_state.entry(right_str.parse().unwrap()).or_insert(0) +=
+                    state.get(stone).or_else(|| Some(&1)).unwrap();
+            }
+            stone => {
+                *new_state.entry(stone * 2024).or_insert(0) +=
+                    state.get(stone).or_else(|| Some(&1)).unwrap();
+            }
+        }
+    }
+
+    new_state
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let stones = read_input(input).unwrap();
+    let state = stones.into_iter().map(|stone| (stone, 1)).collect();
+    let result = (0..25).fold(state, |state, _| step(&state)).values().sum();
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let stones = read_input(input).unwrap();
+    let state = stones.into_iter().map(|stone| (stone, 1)).collect();
+    let result = (0..75).fold(state, |state, _| step(&state)).values().sum();
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 11: Plutonian Pebbles ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
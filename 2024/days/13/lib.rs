@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use aoc_algebra::cramer;
+use aoc_geometry::{Vector2, ZERO};
+use aoc_parse::{sections, Pattern, Result, Span};
+
+#[derive(Debug)]
+struct ClawMachine {
+    a: Vector2,
+    b: Vector2,
+    prize: Vector2,
+}
+
+fn get_vector(pattern: &Pattern, line: Span) -> Result<Vector2> {
+    let [x, y] = pattern.parse(line)?;
+    Ok(Vector2(x, y))
+}
+
+fn read_input(input: &str) -> Result<Vec<ClawMachine>> {
+    let a_pattern = Pattern::new("Button A: X+<x>, Y+<y>");
+    let b_pattern = Pattern::new("Button B: X+<x>, Y+<y>");
+    let prize_pattern = Pattern::new("Prize: X=<x>, Y=<y>");
+
+    sections(input)
+        .iter()
+        .map(|section| {
+            let lines: Vec<Span> = section.lines().collect();
+            let [a_line, b_line, prize_line] = lines[..] else {
+                return Err(section.error("Expected two buttons and a prize"));
+            };
+
+            Ok(ClawMachine {
+                a: get_vector(&a_pattern, a_line)?,
+                b: get_vector(&b_pattern, b_line)?,
+                prize: get_vector(&prize_pattern, prize_line)?,
+            })
+        })
+        .collect()
+}
+
+fn find_path(machine: &ClawMachine) -> Option<(isize, isize)> {
+    let start_position = ZERO;
+
+    let mut g_map = HashMap::new();
+    let mut f_map = HashMap::new();
+
+    g_map.insert(start_position, 0);
+    f_map.insert(
+        start_position,
+        start_position.manhattan_distance(&machine.prize),
+    );
+
+    let mut visit: Vec<(Vector2, isize, isize)> = vec![(start_position, 0, 0)];
+
+    while let Some((point, a_presses, b_presses)) = visit.pop() {
+        if point.0 > machine.prize.0 || point.1 > machine.prize.1 {
+            continue;
+        }
+
+        if point == machine.prize {
+            return Some((a_presses, b_presses));
+        }
+        for (direction, cost, a, b) in [(machine.a, 1, 1, 0), (machine.b, 3, 0, 1)].iter() {
+            let neighbor_point = point + *direction;
+
+            if !g_map.contains_key(&neighbor_point) {
+                g_map.insert(neighbor_point, isize::MAX);
+            }
+
+            let new_distance = g_map.get(&point).unwrap() + cost;
+            let neighbor_distance = g_map.get(&neighbor_point).unwrap();
+
+            if new_distance < *neighbor_distance {
+                *g_map.get_mut(&neighbor_point).unwrap() = new_distance;
+                *f_map.entry(neighbor_point).or_insert(isize::MAX) =
+                    new_distance + neighbor_point.manhattan_distance(&machine.prize);
+
+                visit.push((neighbor_point, a_presses + a, b_presses + b));
+            }
+        }
+
+        visit.sort_by(|a, b| {
+            let a_f = f_map.get(&a.0).unwrap();
+            let b_f = f_map.get(&b.0).unwrap();
+
+            b_f.cmp(a_f)
+        })
+    }
+    None
+}
+
+fn calc_path(machine: &ClawMachine, offset: isize) -> Option<(isize, isize)> {
+    // Solve:
+    // A*a_x + B*b_x = p_x
+    // A*a_y + B*b_y = p_y
+    let prize = machine.prize.add_scalar(offset);
+    let coefficients = [
+        vec![machine.a.0, machine.b.0],
+        vec![machine.a.1, machine.b.1],
+    ];
+    let presses = cramer(&coefficients, &[prize.0, prize.1])?;
+
+    let a = presses[0].to_integer()?;
+    let b = presses[1].to_integer()?;
+    if a < 0 || b < 0 {
+        return None;
+    }
+    Some((a, b))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let machines = read_input(input).unwrap();
+    let result = machines
+        .iter()
+        .filter_map(|machine| find_path(machine))
+        .map(|(a, b)| a * 3 + b)
+        .sum();
+
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let machines = read_input(input).unwrap();
+    let result = machines
+        .iter()
+        .filter_map(|machine| calc_path(machine, 10000000000000))
+        .map(|(a, b)| a * 3 + b)
+        .sum();
+
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 13: Claw Contraption ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_automaton::Swarm;
+use aoc_geometry::{Grid, Vector2, DOWN, RIGHT, ZERO};
+use aoc_parse::{lines, Pattern, Result, Span};
+
+const SIZE: Vector2 = Vector2(101, 103);
+
+#[derive(Debug)]
+struct Robot {
+    position: Vector2,
+    velocity: Vector2,
+}
+
+impl Robot {
+    fn parse(pattern: &Pattern, line: Span) -> Result<Self> {
+        let [x, y, vx, vy] = pattern.parse(line)?;
+
+        Ok(Self {
+            position: Vector2(x, y),
+            velocity: Vector2(vx, vy),
+        })
+    }
+}
+
+fn get_quadrant_values(tiles: &Grid<u16>) -> Vec<usize> {
+    let width = tiles.width() as isize / 2;
+    let height = tiles.height() as isize / 2;
+
+    let mut quadrants = Vec::new();
+    for quadrant in [ZERO, RIGHT, DOWN, Vector2(1, 1)] {
+        let mut value = 0;
+        for y in (quadrant.1 + quadrant.1 * height)..(height + (quadrant.1 + quadrant.1 * height)) {
+            for x in (quadrant.0 + quadrant.0 * width)..(width + (quadrant.0 + quadrant.0 * width)) {
+                value += tiles[Vector2(x, y)] as usize;
+            }
+        }
+        quadrants.push(value);
+    }
+
+    quadrants
+}
+
+fn safety_factor(tiles: &Grid<u16>) -> usize {
+    get_quadrant_values(tiles).into_iter().product()
+}
+
+fn read_input(input: &str) -> Result<Swarm> {
+    let pattern = Pattern::new("p=<x>,<y> v=<vx>,<vy>");
+    let robots = lines(input)
+        .map(|line| Robot::parse(&pattern, line))
+        .collect::<Result<Vec<Robot>>>()?;
+
+    Ok(Swarm::new(
+        SIZE,
+        robots
+            .into_iter()
+            .map(|robot| (robot.position, robot.velocity)),
+    ))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let mut swarm = read_input(input).unwrap();
+
+    swarm.advance(100);
+    Some(safety_factor(swarm.counts()) as isize)
+}
+
+const CYCLE_LENGTH: isize = 10403;
+
+fn part2(input: &str) -> Option<isize> {
+    let mut swarm = read_input(input).unwrap();
+
+    // Most robots bunch up in the tree, leaving the quadrants lopsided, so
+    // its second has the lowest safety factor of the whole cycle
+    let mut best = (usize::MAX, 0);
+    for sec in 0..CYCLE_LENGTH {
+        best = best.min((safety_factor(swarm.counts()), sec));
+        swarm.step();
+    }
+
+    Some(best.1)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 14: Restroom Redoubt ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
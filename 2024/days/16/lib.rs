@@ -0,0 +1,243 @@
+use aoc_geometry::{Vector2, DOWN, LEFT, RIGHT, UP, ZERO};
+use aoc_graph::astar;
+use aoc_parse::{grid_with_position, Result};
+use hashbrown::HashSet;
+#[cfg(feature = "visualize")]
+use ratatui::{layout::Rect, style::Stylize, widgets::Paragraph, Frame};
+use std::fmt::Display;
+#[cfg(feature = "visualize")]
+use std::{thread::sleep, time::Duration};
+
+
+type Node = (Vector2, Vector2);
+
+const NORTH: Vector2 = UP;
+const SOUTH: Vector2 = DOWN;
+const EAST: Vector2 = RIGHT;
+const WEST: Vector2 = LEFT;
+
+// Visited and Reindeer are only placed by the animation
+#[cfg_attr(not(feature = "visualize"), allow(dead_code))]
+#[derive(Clone)]
+enum Tile {
+    Air(Vector2),
+    Visited(Vector2, Vector2),
+    Reindeer(Vector2),
+    Start(Vector2),
+    End(Vector2),
+    Wall(Vector2),
+}
+
+impl Tile {
+    fn get_position(&self) -> &Vector2 {
+        match self {
+            Self::Air(position) => position,
+            Self::Visited(position, _) => position,
+            Self::Reindeer(position) => position,
+            Self::Start(position) => position,
+            Self::End(position) => position,
+            Self::Wall(position) => position,
+        }
+    }
+}
+
+struct Maze {
+    map: Vec<Tile>,
+    size: Vector2,
+}
+
+impl Maze {
+    fn get_tile_index(&self, position: &Vector2) -> usize {
+        (position.0 + position.1 * self.size.0) as usize
+    }
+
+    fn get_tile(&self, position: &Vector2) -> Option<&Tile> {
+        let index = self.get_tile_index(position);
+        self.map.get(index)
+    }
+
+    #[cfg(feature = "visualize")]
+    fn get_tile_mut(&mut self, position: &Vector2) -> Option<&mut Tile> {
+        let index = self.get_tile_index(position);
+        self.map.get_mut(index)
+    }
+
+    #[cfg(feature = "visualize")]
+    fn animate(&mut self, paths: &[Vec<Node>]) {
+        let mut terminal = ratatui::init();
+        terminal.clear().unwrap();
+
+        for path in paths {
+            let mut previous = path.first().unwrap();
+            *self.get_tile_mut(&previous.0).unwrap() = Tile::Reindeer(previous.0);
+
+            for position in &path[1..] {
+                terminal.draw(|frame| self.draw(frame)).unwrap();
+                sleep(Duration::from_millis(100));
+                *self.get_tile_mut(&previous.0).unwrap() = Tile::Visited(previous.0, previous.1);
+                previous = position;
+                *self.get_tile_mut(&previous.0).unwrap() = Tile::Reindeer(previous.0);
+            }
+        }
+
+        ratatui::restore();
+    }
+
+    #[cfg(feature = "visualize")]
+    fn draw(&self, frame: &mut Frame) {
+        for y in 0..self.size.1 {
+            for x in 0..self.size.0 {
+                let paragraph = match self.get_tile(&Vector2(x, y)) {
+                    Some(tile) => match tile {
+                        Tile::Visited(_, direction) => match *direction {
+                            NORTH => Paragraph::new("^").white(),
+                            SOUTH => Paragraph::new("v").white(),
+                            EAST => Paragraph::new(">").white(),
+                            WEST => Paragraph::new("<").white(),
+                            ZERO => Paragraph::new("O").white(),
+                            _ => panic!("Bad visited"),
+                        },
+                        Tile::Reindeer(_) => Paragraph::new("@").red().bold(),
+                        Tile::Air(_) => Paragraph::new(" ").gray(),
+                        Tile::Start(_) => Paragraph::new("S").gray(),
+                        Tile::End(_) => Paragraph::new("E").gray(),
+                        Tile::Wall(_) => Paragraph::new("#").green(),
+                    },
+                    None => panic!("ooops"),
+                };
+                let rect = Rect::new(x as u16, y as u16, 1, 1);
+                if rect.x >= frame.area().width || rect.y >= frame.area().height {
+                  continue;
+                }
+                frame.render_widget(paragraph, rect);
+            }
+        }
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.size.1 {
+            for x in 0..self.size.0 {
+                match self.get_tile(&Vector2(x, y)) {
+                    Some(tile) => match tile {
+                        Tile::Visited(_, direction) => match *direction {
+                            NORTH => write!(f, "^"),
+                            SOUTH => write!(f, "v"),
+                            EAST => write!(f, ">"),
+                            WEST => write!(f, "<"),
+                            ZERO => write!(f, "O"),
+                            _ => panic!("Bad visited"),
+                        },
+                        Tile::Reindeer(_) => write!(f, "@"),
+                        Tile::Air(_) => write!(f, "."),
+                        Tile::Start(_) => write!(f, "S"),
+                        Tile::End(_) => write!(f, "E"),
+                        Tile::Wall(_) => write!(f, "#"),
+                    },
+                    None => panic!("ooops"),
+                }?;
+            }
+            write!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn read_input(input: &str) -> Result<Maze> {
+    let tiles = grid_with_position(input, |position, tile_char| match tile_char {
+        '#' => Some(Tile::Wall(position)),
+        '.' => Some(Tile::Air(position)),
+        'E' => Some(Tile::End(position)),
+        'S' => Some(Tile::Start(position)),
+        _ => None,
+    })?;
+
+    Ok(Maze {
+        map: tiles.tiles().cloned().collect(),
+        size: tiles.size(),
+    })
+}
+
+fn find_best_path(maze: &mut Maze) -> (usize, HashSet<Node>) {
+    let start_position = maze
+        .map
+        .iter()
+        .find(|t| matches!(t, Tile::Start(_)))
+        .unwrap()
+        .get_position()
+        .clone();
+
+    let end_position = maze
+        .map
+        .iter()
+        .find(|t| matches!(t, Tile::End(_)))
+        .unwrap()
+        .get_position()
+        .clone();
+
+    let successors = |&(position, direction): &Node| {
+        let mut next = vec![
+            ((position, direction.rot_right_90()), 1000),
+            ((position, direction.rot_left_90()), 1000),
+        ];
+
+        let new_position = position + direction;
+        match maze.get_tile(&new_position) {
+            Some(Tile::Air(_) | Tile::End(_) | Tile::Start(_)) => {
+                next.push(((new_position, direction), 1))
+            }
+            Some(_) => {}
+            None => panic!("Oh dear"),
+        }
+        next
+    };
+
+    let search = astar::get_best_paths(
+        [(start_position, EAST)],
+        successors,
+        |(position, _)| position.manhattan_distance(&end_position) as usize,
+        |(position, _)| *position == end_position,
+    );
+
+    #[cfg(feature = "visualize")]
+    maze.animate(&search.paths());
+
+    (
+        search.cost().unwrap_or(usize::MAX),
+        search.on_best_paths().cloned().collect(),
+    )
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut maze = read_input(input).unwrap();
+    let (cost, _) = find_best_path(&mut maze);
+    Some(cost)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let mut maze = read_input(input).unwrap();
+    let (_, path) = find_best_path(&mut maze);
+
+    let mut points = HashSet::new();
+    for (position, _) in path.iter() {
+        points.insert(*position);
+    }
+    Some(points.len())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 16: Reindeer Maze ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,334 @@
+#![allow(non_camel_case_types)]
+
+use std::fmt::Display;
+
+use aoc_elfcode::{Compare, Condition, Flow, Line, Listing, Profile};
+use aoc_parse::{lines, Span};
+
+type Registry = [usize; 3];
+
+const RA: usize = 0;
+const RB: usize = 1;
+const RC: usize = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Error {
+    /// Combo operand 7 is reserved and never appears in valid programs.
+    ReservedCombo,
+    /// Register A has to be shifted right by three bits, once every time
+    /// around the program, for [`Computer::solve_register_a`] to work.
+    NotOctal,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ReservedCombo => write!(f, "Combo operand 7 is reserved"),
+            Error::NotOctal => write!(f, "Program does not consume RA three bits at a time"),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operand {
+    Value(usize),
+}
+
+impl Operand {
+    fn print_combo(&self) -> Result<String, Error> {
+        match self {
+            Self::Value(value) => match value {
+                _ if value < &4 => Ok(value.to_string()),
+                4 => Ok("RA".to_string()),
+                5 => Ok("RB".to_string()),
+                6 => Ok("RC".to_string()),
+                7 => Err(Error::ReservedCombo),
+                _ => panic!("Unknown combo value"),
+            },
+        }
+    }
+    fn literal(&self) -> usize {
+        match self {
+            Self::Value(value) => *value,
+        }
+    }
+
+    fn combo(&self, registry: &Registry) -> Result<usize, Error> {
+        match self {
+            Self::Value(value) => match value {
+                _ if value < &4 => Ok(*value),
+                4 => Ok(registry[RA]),
+                5 => Ok(registry[RB]),
+                6 => Ok(registry[RC]),
+                7 => Err(Error::ReservedCombo),
+                _ => panic!("Unknown combo value"),
+            },
+        }
+    }
+}
+
+struct Computer {
+    pointer: usize,
+    registry: Registry,
+    program: Vec<usize>,
+}
+
+impl Computer {
+    fn new(program: Vec<usize>, registry: Registry) -> Self {
+        Self {
+            pointer: 0,
+            registry,
+            program,
+        }
+    }
+
+    fn reset(&mut self, registry: Registry) {
+        self.pointer = 0;
+        self.registry = registry;
+    }
+
+    fn run(&mut self) -> Result<Vec<usize>, Error> {
+        self.run_profiled(None)
+    }
+
+    fn run_profiled(&mut self, mut profile: Option<&mut Profile>) -> Result<Vec<usize>, Error> {
+        let mut output = Vec::new();
+        while let Some(code) = self.program.get(self.pointer) {
+            let pointer = self.pointer;
+            let op_code = &OP_CODES[*code];
+            let operand = Operand::Value(self.program[self.pointer + 1]);
+
+            if let Some(value) = op_code.exec(operand, self)? {
+                output.push(value);
+            }
+            if let Some(profile) = profile.as_deref_mut() {
+                profile.record(pointer, &self.registry.map(|value| value as isize), self.pointer);
+            }
+        }
+        Ok(output)
+    }
+
+    fn listing(&self) -> Result<Listing, Error> {
+        let lines = self
+            .program
+            .chunks(2)
+            .enumerate()
+            .map(|(line, chunk)| OP_CODES[chunk[0]].line(Operand::Value(chunk[1]), line * 2))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Listing::new(lines))
+    }
+
+    /// Smallest RA making the program output `expected`, `None` if there is
+    /// none. Every time around, the program shifts RA right by three bits
+    /// and outputs something based on what is left of it, so the last output
+    /// only depends on the top three bits of RA. RA is built from those down
+    /// three bits at a time, backtracking whenever none of the 8 candidates
+    /// produce the tail of `expected` they have to.
+    fn solve_register_a(&mut self, expected: &[usize]) -> Result<Option<usize>, Error> {
+        let shifts: Vec<usize> = self
+            .program
+            .chunks(2)
+            .filter(|chunk| matches!(OP_CODES[chunk[0]], OPCode::adv))
+            .map(|chunk| chunk[1])
+            .collect();
+        if shifts != [3] || !self.program.ends_with(&[3, 0]) {
+            return Err(Error::NotOctal);
+        }
+
+        let registry = self.registry;
+        let result = self.solve_from(0, expected, expected.len(), registry);
+        self.reset(registry);
+        result
+    }
+
+    fn solve_from(
+        &mut self,
+        prefix: usize,
+        expected: &[usize],
+        solved: usize,
+        registry: Registry,
+    ) -> Result<Option<usize>, Error> {
+        if solved == 0 {
+            return Ok(Some(prefix));
+        }
+        let Some(prefix) = prefix.checked_mul(8) else {
+            return Ok(None);
+        };
+
+        for ra in prefix..prefix + 8 {
+            self.reset([ra, registry[RB], registry[RC]]);
+            if self.run()? != expected[solved - 1..] {
+                continue;
+            }
+            if let Some(ra) = self.solve_from(ra, expected, solved - 1, registry)? {
+                return Ok(Some(ra));
+            }
+        }
+        Ok(None)
+    }
+}
+
+const OP_CODES: [OPCode; 8] = [
+    OPCode::adv,
+    OPCode::bxl,
+    OPCode::bst,
+    OPCode::jnz,
+    OPCode::bxc,
+    OPCode::out,
+    OPCode::bdv,
+    OPCode::cdv,
+];
+
+#[derive(Debug)]
+enum OPCode {
+    adv,
+    bxl,
+    bst,
+    jnz,
+    bxc,
+    out,
+    bdv,
+    cdv,
+}
+
+impl OPCode {
+    fn exec(
+        &self,
+        operand: Operand,
+        Computer {
+            pointer,
+            registry,
+            program: _
+        }: &mut Computer,
+    ) -> Result<Option<usize>, Error> {
+        Ok(match self {
+            OPCode::adv => {
+                let denominator = (2 as i64).pow(operand.combo(registry)? as u32);
+                registry[RA] = ((registry[RA] as i64) / denominator) as usize;
+                *pointer += 2;
+
+                None
+            }
+            OPCode::bxl => {
+                registry[RB] = registry[RB] ^ operand.literal();
+                *pointer += 2;
+
+                None
+            }
+            OPCode::bst => {
+                registry[RB] = operand.combo(registry)? % 8;
+                *pointer += 2;
+
+                None
+            }
+            OPCode::jnz => {
+                if registry[RA] != 0 {
+                    *pointer = operand.literal()
+                } else {
+                    *pointer += 2;
+                }
+
+                None
+            }
+            OPCode::bxc => {
+                registry[RB] = registry[RB] ^ registry[RC];
+                *pointer += 2;
+
+                None
+            }
+            OPCode::out => {
+                *pointer += 2;
+
+                Some(operand.combo(registry)? % 8)
+            }
+            OPCode::bdv => {
+                let denominator = (2 as i64).pow(operand.combo(registry)? as u32);
+                registry[RB] = ((registry[RA] as i64) / denominator) as usize;
+                *pointer += 2;
+
+                None
+            }
+            OPCode::cdv => {
+                let denominator = (2 as i64).pow(operand.combo(registry)? as u32);
+                registry[RC] = ((registry[RA] as i64) / denominator) as usize;
+                *pointer += 2;
+
+                None
+            }
+        })
+    }
+
+    fn line(&self, operand: Operand, address: usize) -> Result<Line, Error> {
+        let statement = match self {
+            OPCode::adv => format!("RA >>= {}", operand.print_combo()?),
+            OPCode::bxl => format!("RB ^= {}", operand.literal()),
+            OPCode::bst => format!("RB = {} % 8", operand.print_combo()?),
+            OPCode::jnz => {
+                let condition = Condition::new("RA", Compare::Ne, "0");
+                let target = operand.literal();
+                return Ok(Line::new(address, None, Flow::Branch { condition, target }));
+            }
+            OPCode::bxc => "RB ^= RC".to_string(),
+            OPCode::out => format!("out({} % 8)", operand.print_combo()?),
+            OPCode::bdv => format!("RB = RA >> {}", operand.print_combo()?),
+            OPCode::cdv => format!("RC = RA >> {}", operand.print_combo()?),
+        };
+
+        Ok(Line::new(address, Some(statement), Flow::Next))
+    }
+}
+
+fn read_input(input: &str) -> aoc_parse::Result<Computer> {
+    let values = lines(input)
+        .map(|line| Ok(line.key_value()?.1))
+        .collect::<aoc_parse::Result<Vec<Span>>>()?;
+    let [ra_str, rb_str, rc_str, program_str] = values[..] else {
+        return Err(aoc_parse::Error::new(1, 1, "Expected three registers and a program"));
+    };
+
+    Ok(Computer::new(
+        program_str.list(",")?,
+        [ra_str.parse()?, rb_str.parse()?, rc_str.parse()?],
+    ))
+}
+
+fn part1(input: &str) -> Option<String> {
+    let mut computer = read_input(input).unwrap();
+
+    let output = computer
+        .run()
+        .unwrap_or_else(|error| panic!("Unable to run the program: {error}"))
+        .into_iter()
+        .map(|o| o.to_string())
+        .collect::<Vec<String>>();
+    let output_str = output.join(",");
+    Some(output_str)
+}
+
+fn part2(input: &str) -> Option<String> {
+    let mut computer = read_input(input).unwrap();
+
+    // Every pass shifts RA right three bits, so solve it from the last output back
+    let program = computer.program.clone();
+    computer
+        .solve_register_a(&program)
+        .unwrap_or_else(|error| panic!("Unable to solve register A: {error}"))
+        .map(|ra| ra.to_string())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 17: Chronospatial Computer ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use aoc_geometry::{Grid, Vector2, DIRECTIONS};
+use aoc_graph::{dijkstra, Graph};
+use aoc_parse::{parse_lines, Result};
+
+fn read_input(input: &str) -> Result<Vec<Vector2>> {
+    parse_lines(input, |line| {
+        let [x, y] = line.ints_array()?;
+        Ok(Vector2(x, y))
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Byte,
+    Empty,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Byte => write!(f, "#"),
+            Tile::Empty => write!(f, "."),
+        }
+    }
+}
+
+struct Memory {
+    graph: Graph<Vector2>,
+    tiles: Grid<Tile>,
+}
+
+impl Memory {
+    fn new(size: Vector2) -> Self {
+        let mut graph = Graph::new();
+        for x in 0..size.0 {
+            for y in 0..size.1 {
+                let position = Vector2(x, y);
+                let node = graph.add_node(position);
+
+                for direction in DIRECTIONS.iter() {
+                    let neighbor = position + *direction;
+                    if !neighbor.is_within(&size) {
+                        continue;
+                    }
+
+                    let neighbor_node = graph.add_node(neighbor);
+                    graph.add_edge(node, neighbor_node, 1);
+                }
+            }
+        }
+
+        Memory {
+            graph,
+            tiles: Grid::new(size.0 as usize, size.1 as usize, Tile::Empty),
+        }
+    }
+
+    fn insert_byte(&mut self, position: Vector2) {
+        if let Some(tile) = self.tiles.get_mut(&position) {
+            *tile = Tile::Byte
+        }
+    }
+
+    fn find_path(&self) -> Option<Vec<Vector2>> {
+        let size = self.tiles.size();
+        let start = self.graph.get_id(&Vector2(0, 0))?;
+        let goal = self.graph.get_id(&(size - Vector2(1, 1)))?;
+
+        let path = dijkstra::get_path(&self.graph, start, goal, |edge| {
+            match self.tiles[*self.graph.get_node(edge.destination)] {
+                Tile::Empty => true,
+                Tile::Byte => false,
+            }
+        })?;
+
+        Some(
+            path.into_iter()
+                .map(|(node, _)| *self.graph.get_node(node))
+                .collect(),
+        )
+    }
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n", self.tiles)
+    }
+}
+
+fn part1(input: &str) -> Option<String> {
+    let mut memory = Memory::new(Vector2(71, 71));
+    for byte in read_input(input).unwrap().into_iter().take(1024) {
+        memory.insert_byte(byte);
+    }
+
+    if let Some(path) = memory.find_path() {
+        Some((path.len() - 1).to_string())
+    } else {
+        None
+    }
+}
+
+fn part2(input: &str) -> Option<String> {
+    let mut memory = Memory::new(Vector2(71, 71));
+    for byte in read_input(input).unwrap() {
+        memory.insert_byte(byte);
+
+        if memory.find_path().is_none() {
+            return Some(format!("{},{}", byte.0, byte.1));
+        }
+    }
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 18: RAM Run ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
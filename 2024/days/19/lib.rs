@@ -0,0 +1,82 @@
+use hashbrown::HashSet;
+
+use aoc_parse::{sections, Error, Result};
+
+const MAX_TOWEL_LEN: usize = 64;
+
+type PatternSet = HashSet<String>;
+
+fn read_input(input: &str) -> Result<(Counter, Vec<String>)> {
+    let sections = sections(input);
+    let [pattern_section, towel_section] = &sections[..] else {
+        return Err(Error::new(1, 1, "Expected patterns and towels"));
+    };
+
+    let mut patterns = PatternSet::new();
+    for line in pattern_section.lines() {
+        patterns.extend(line.list(",")?);
+    }
+    let towels = towel_section.lines().map(|line| line.to_string()).collect();
+
+    Ok((Counter::new(patterns), towels))
+}
+
+struct Counter {
+    patterns: PatternSet,
+    max_pat_len: usize,
+    tally: [usize; MAX_TOWEL_LEN],
+}
+
+impl Counter {
+    fn new(patterns: PatternSet) -> Self {
+        let max_pat_len = patterns.iter().map(|p| p.len()).max().expect("no patterns");
+        Self { patterns, max_pat_len, tally: [0; MAX_TOWEL_LEN] }
+    }
+
+    fn count_permutations(&mut self, towel: &String) -> usize {
+        let towel_len = towel.len();
+        self.tally.fill(0);
+        self.tally[0] = 1;
+
+        for start in 0..towel_len {
+            if self.tally[start] > 0 {
+                for pat_len in 1..=self.max_pat_len.min(towel_len - start) {
+                    let end = start + pat_len;
+                    if self.patterns.contains(&towel[start..end]) {
+                        self.tally[end] += self.tally[start];
+                    }
+                }
+            }
+        }
+
+        self.tally[towel_len]
+    }
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let (mut counter, towels) = read_input(input).unwrap();
+    let completed = towels.iter().filter(|towel| counter.count_permutations(towel) > 0).count();
+    Some(completed)
+}
+
+fn part2(input: &str) -> Option<usize> {
+  let (mut counter, towels) = read_input(input).unwrap();
+  let sum = towels.iter().map(|towel| counter.count_permutations(towel)).sum();
+  Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 19: Linen Layout ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
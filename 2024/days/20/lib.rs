@@ -0,0 +1,126 @@
+use aoc_geometry::{Grid, Vector2, DIRECTIONS};
+use aoc_graph::{dijkstra, Graph};
+use aoc_parse::{grid_with_position, Result};
+
+#[derive(Debug, Clone)]
+enum Tile {
+    Wall(Vector2),
+    Track(Vector2),
+    Start(Vector2),
+    Goal(Vector2),
+}
+
+impl Tile {
+    fn get_position(&self) -> &Vector2 {
+        match self {
+            Tile::Wall(position) => position,
+            Tile::Track(position) => position,
+            Tile::Start(position) => position,
+            Tile::Goal(position) => position,
+        }
+    }
+}
+
+struct RaceTrack {
+    graph: Graph<Vector2>,
+    tiles: Grid<Tile>,
+    start: Vector2,
+    goal: Vector2,
+}
+
+impl RaceTrack {
+    fn run(&mut self, cheat_duration: isize, min_time_reduction: isize) -> usize {
+        let race_path = dijkstra::get_path(
+            &self.graph,
+            self.graph.get_id(&self.start).unwrap(),
+            self.graph.get_id(&self.goal).unwrap(),
+            |edge| match self.tiles[*self.graph.get_node(edge.destination)] {
+                Tile::Wall(_) => false,
+                _ => true,
+            },
+        )
+        .expect("Should have a race path");
+
+        let mut result = 0;
+        for s_i in 0..race_path.len() {
+            let (source_node, source_cost) = race_path[s_i];
+            let source = self.tiles[*self.graph.get_node(source_node)].get_position();
+            for d_i in 0..race_path.len() {
+                let (destination_node, destination_cost) = race_path[d_i];
+                let destination =
+                    self.tiles[*self.graph.get_node(destination_node)].get_position();
+
+                let cheat_distance = source.manhattan_distance(destination);
+
+                // Calculate the cost reduction
+                let saved = destination_cost as isize - source_cost as isize - cheat_distance;
+                if cheat_distance <= cheat_duration && saved >= min_time_reduction {
+                    result += 1;
+                }
+            }
+        }
+        result
+    }
+}
+
+fn read_input(input: &str) -> Result<RaceTrack> {
+    let tiles = grid_with_position(input, |position, tile_char| match tile_char {
+        '#' => Some(Tile::Wall(position)),
+        '.' => Some(Tile::Track(position)),
+        'S' => Some(Tile::Start(position)),
+        'E' => Some(Tile::Goal(position)),
+        _ => None,
+    })?;
+    let start = tiles.find(|tile| matches!(tile, Tile::Start(_))).unwrap();
+    let goal = tiles.find(|tile| matches!(tile, Tile::Goal(_))).unwrap();
+
+    let mut graph = Graph::new();
+    for position in tiles.positions() {
+        graph.add_node(position);
+    }
+    for position in tiles.positions() {
+        let node = graph.get_id(&position).unwrap();
+        for direction in DIRECTIONS.iter() {
+            if let Some(neighbor_node) = graph.get_id(&(position + *direction)) {
+                graph.add_edge(node, neighbor_node, 1);
+            }
+        }
+    }
+
+    Ok(RaceTrack {
+        graph,
+        tiles,
+        start,
+        goal,
+    })
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut race = read_input(input).unwrap();
+
+    let result = race.run(2, 100);
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let mut race = read_input(input).unwrap();
+
+    let result = race.run(20, 100);
+    Some(result)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 20: Race Condition ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
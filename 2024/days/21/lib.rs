@@ -0,0 +1,378 @@
+use std::usize;
+
+use hashbrown::HashMap;
+
+use aoc_geometry::{Vector2, DOWN, LEFT, RIGHT, UP, ZERO};
+use aoc_graph::{bfs, Graph, NodeId};
+use aoc_parse::{parse_lines, Result};
+
+type TileSequence = Vec<Tile>;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Tile {
+    Number(u8, Vector2),
+    Up(Vector2),
+    Down(Vector2),
+    Left(Vector2),
+    Right(Vector2),
+    Activate(Vector2),
+    Gap,
+}
+
+impl Tile {
+    fn get_position(&self) -> &Vector2 {
+        match self {
+            Tile::Number(_, position) => position,
+            Tile::Activate(position) => position,
+            Tile::Up(position) => position,
+            Tile::Down(position) => position,
+            Tile::Left(position) => position,
+            Tile::Right(position) => position,
+            _ => panic!("Tile does not have a position"),
+        }
+    }
+}
+
+impl Tile {
+    fn to_name(&self) -> String {
+        match self {
+            Tile::Number(number, _) => format!("{number}"),
+            Tile::Up(_) => "^".to_string(),
+            Tile::Down(_) => "v".to_string(),
+            Tile::Left(_) => "<".to_string(),
+            Tile::Right(_) => ">".to_string(),
+            Tile::Activate(_) => "A".to_string(),
+            Tile::Gap => "G".to_string(),
+        }
+    }
+}
+
+const NUMERIC_KEYPAD: [[Tile; 3]; 4] = [
+    [
+        Tile::Number(7, Vector2(0, 0)),
+        Tile::Number(8, Vector2(1, 0)),
+        Tile::Number(9, Vector2(2, 0)),
+    ],
+    [
+        Tile::Number(4, Vector2(0, 1)),
+        Tile::Number(5, Vector2(1, 1)),
+        Tile::Number(6, Vector2(2, 1)),
+    ],
+    [
+        Tile::Number(1, Vector2(0, 2)),
+        Tile::Number(2, Vector2(1, 2)),
+        Tile::Number(3, Vector2(2, 2)),
+    ],
+    [
+        Tile::Gap,
+        Tile::Number(0, Vector2(1, 3)),
+        Tile::Activate(Vector2(2, 3)),
+    ],
+];
+
+const ARROW_KEYPAD: [[Tile; 3]; 2] = [
+    [
+        Tile::Gap,
+        Tile::Up(Vector2(1, 0)),
+        Tile::Activate(Vector2(2, 0)),
+    ],
+    [
+        Tile::Left(Vector2(0, 1)),
+        Tile::Down(Vector2(1, 1)),
+        Tile::Right(Vector2(2, 1)),
+    ],
+];
+
+const NUMERIC_KEYPAD_TYPE: usize = 0;
+const ARROW_KEYPAD_TYPE: usize = 1;
+
+struct Robot {
+    keypads: [Graph<Tile>; 2],
+    keypad_mem: HashMap<(usize, Tile, Tile), usize>,
+    mem: HashMap<(usize, u64), usize>,
+}
+
+impl Robot {
+    fn new() -> Self {
+        let numeric_keypad = Robot::build_keypad(
+            NUMERIC_KEYPAD.iter().flatten(),
+            &[
+                ("7", "8"),
+                ("7", "4"),
+                ("8", "7"),
+                ("8", "9"),
+                ("8", "5"),
+                ("9", "8"),
+                ("9", "6"),
+                ("4", "7"),
+                ("4", "5"),
+                ("4", "1"),
+                ("5", "4"),
+                ("5", "8"),
+                ("5", "6"),
+                ("5", "2"),
+                ("6", "5"),
+                ("6", "3"),
+                ("6", "9"),
+                ("1", "4"),
+                ("1", "2"),
+                ("2", "1"),
+                ("2", "5"),
+                ("2", "3"),
+                ("2", "0"),
+                ("3", "2"),
+                ("3", "6"),
+                ("3", "A"),
+                ("0", "2"),
+                ("0", "A"),
+                ("A", "0"),
+                ("A", "3"),
+            ],
+        );
+        let arrow_keypad = Robot::build_keypad(
+            ARROW_KEYPAD.iter().flatten(),
+            &[
+                ("^", "A"),
+                ("^", "v"),
+                ("A", "^"),
+                ("A", ">"),
+                ("<", "v"),
+                ("v", "<"),
+                ("v", "^"),
+                ("v", ">"),
+                (">", "v"),
+                (">", "A"),
+            ],
+        );
+
+        Self {
+            keypads: [numeric_keypad, arrow_keypad],
+            mem: HashMap::new(),
+            keypad_mem: HashMap::new(),
+        }
+    }
+
+    fn build_keypad<'a>(
+        tiles: impl Iterator<Item = &'a Tile>,
+        edges: &[(&str, &str)],
+    ) -> Graph<Tile> {
+        let mut keypad = Graph::new();
+        for tile in tiles {
+            keypad.add_node(tile.clone());
+        }
+        for (from, to) in edges {
+            let from = Robot::get_key(&keypad, from);
+            let to = Robot::get_key(&keypad, to);
+            keypad.add_edge(from, to, 1);
+        }
+        keypad
+    }
+
+    fn get_key(keypad: &Graph<Tile>, name: &str) -> NodeId {
+        keypad
+            .find_node(|tile| tile.to_name() == name)
+            .unwrap_or_else(|| panic!("No key named {name}"))
+    }
+
+    fn tile_path_to_sequence(&self, start: &Vector2, path: &Vec<Tile>) -> TileSequence {
+        let mut current_position = *start;
+        let mut arm_sequence = path
+            .iter()
+            .map(|tile| {
+                let next_position = tile.get_position();
+                let direction_tile = match current_position.get_direction(next_position) {
+                    UP => Tile::Up,
+                    DOWN => Tile::Down,
+                    LEFT => Tile::Left,
+                    RIGHT => Tile::Right,
+                    direction => panic!(
+                        "Unknown direction: {direction}, {current_position} => {next_position}"
+                    ),
+                };
+                current_position = next_position.clone();
+                direction_tile(ZERO)
+            })
+            .collect::<TileSequence>();
+
+        arm_sequence.push(Tile::Activate(ZERO));
+        arm_sequence
+    }
+
+    fn run(&mut self, sequence: &TileSequence, keypad_type: usize, depth: usize) -> usize {
+        if let Some(&result_length) = self.mem.get(&(depth, Robot::hash_sequence(sequence))) {
+            return result_length;
+        }
+
+        if depth == 0 {
+            return sequence.len();
+        }
+
+        let keypad = &self.keypads[keypad_type];
+        let mut arm_position = keypad.get_node(Robot::get_key(keypad, "A")).clone();
+
+        let subsequences = sequence.split_inclusive(|tile| matches!(tile, Tile::Activate(_)));
+        let mut total_length = 0;
+
+        for subsequence in subsequences {
+            let subsequence_hash = Robot::hash_sequence(&subsequence.to_vec());
+
+            if let Some(&result_length) = self.mem.get(&(depth, subsequence_hash)) {
+                total_length += result_length;
+            } else {
+                let mut subsequence_length = 0;
+                for goal in subsequence {
+                    let best_path_length = if let Some(&best_length) =
+                        self.keypad_mem
+                            .get(&(depth, arm_position.clone(), goal.clone()))
+                    {
+                        best_length
+                    } else {
+                        let keypad = &self.keypads[keypad_type];
+                        let mut paths: Vec<Vec<Tile>> = bfs::get_paths(
+                            keypad,
+                            Robot::get_key(keypad, &arm_position.to_name()),
+                            Robot::get_key(keypad, &goal.to_name()),
+                            usize::MAX,
+                            |edge| !matches!(keypad.get_node(edge.destination), Tile::Gap),
+                        )
+                        .into_iter()
+                        .map(|path| {
+                            path[1..]
+                                .iter()
+                                .map(|&node| keypad.get_node(node).clone())
+                                .collect()
+                        })
+                        .collect();
+
+                        paths.sort_by(|a, b| a.len().cmp(&b.len()));
+                        let min_len = paths[0].len();
+
+                        let mut best_len = usize::MAX;
+
+                        for tile_path in paths.iter().take_while(|p| p.len() == min_len) {
+                            let path_sequence = self
+                                .tile_path_to_sequence(&arm_position.get_position(), tile_path);
+
+                            let completed_length =
+                                self.run(&path_sequence, ARROW_KEYPAD_TYPE, depth - 1);
+                            if completed_length < best_len {
+                                best_len = completed_length;
+                            }
+                        }
+
+                        self.keypad_mem
+                            .insert((depth, arm_position.clone(), goal.clone()), best_len);
+                        best_len
+                    };
+
+                    let keypad = &self.keypads[keypad_type];
+                    arm_position = keypad
+                        .get_node(Robot::get_key(keypad, &goal.to_name()))
+                        .clone();
+
+                    subsequence_length += best_path_length;
+                }
+
+                self.mem
+                    .insert((depth, subsequence_hash), subsequence_length);
+
+                total_length += subsequence_length;
+            }
+        }
+
+        self.mem
+            .insert((depth, Robot::hash_sequence(sequence)), total_length);
+        total_length
+    }
+
+    fn hash_sequence(sequence: &TileSequence) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        sequence.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+fn sequence_to_string(sequence: &TileSequence) -> String {
+    format!(
+        "{} => {}",
+        sequence.len(),
+        sequence
+            .iter()
+            .map(|tile| tile.to_name())
+            .collect::<String>(),
+    )
+}
+
+fn read_input(input: &str) -> Result<Vec<TileSequence>> {
+    parse_lines(input, |line| {
+        line.map_chars(|c| match c {
+            'A' => Some(Tile::Activate(ZERO)),
+            _ => Some(Tile::Number(c.to_digit(10)? as u8, ZERO)),
+        })
+    })
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let sequences = read_input(input).unwrap();
+
+    let mut robot = Robot::new();
+    let mut sum = 0;
+    for sequence in sequences.iter() {
+        let result_sequence = robot.run(sequence, NUMERIC_KEYPAD_TYPE, 3);
+
+        let sequence_value_string: String = sequence
+            .iter()
+            .filter_map(|tile| match tile {
+                Tile::Number(value, _) => Some(value.to_string()),
+                _ => None,
+            })
+            .collect();
+        let sequence_value: isize = sequence_value_string.parse().unwrap();
+        let result = sequence_value * result_sequence as isize;
+        sum += result
+    }
+
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let sequences = read_input(input).unwrap();
+
+    let mut robot = Robot::new();
+    let mut sum = 0;
+    for sequence in sequences.iter() {
+        let result_sequence = robot.run(sequence, NUMERIC_KEYPAD_TYPE, 26);
+
+        let sequence_value_string: String = sequence
+            .iter()
+            .filter_map(|tile| match tile {
+                Tile::Number(value, _) => Some(value.to_string()),
+                _ => None,
+            })
+            .collect();
+        let sequence_value: isize = sequence_value_string.parse().unwrap();
+        let result = sequence_value * result_sequence as isize;
+        sum += result
+    }
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 21: Keypad Conundrum ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
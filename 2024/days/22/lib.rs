@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use aoc_parse::{parse_lines, Result};
+
+fn read_input(input: &str) -> Result<Vec<u64>> {
+    parse_lines(input, |line| line.parse())
+}
+
+fn mix(secret: u64, number: u64) -> u64 {
+    number ^ secret
+}
+
+fn prune(secret: u64) -> u64 {
+    secret % 16777216
+}
+
+fn step(secret: u64) -> u64 {
+    let step1 = prune(mix(secret, secret * 64));
+    let step2 = prune(mix(step1, step1 / 32));
+    let step3 = prune(mix(step2, step2 * 2048));
+
+    step3
+}
+
+fn get_digit(secret: u64) -> u64 {
+    secret % 10
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let secrets = read_input(input).unwrap();
+    let result = secrets
+        .into_iter()
+        .map(|secret| (0..2000).fold(secret, |secret, _| step(secret)))
+        .sum::<u64>();
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let secrets = read_input(input).unwrap();
+    let sequences = secrets
+        .into_iter()
+        .map(|secret| {
+            let mut secret_acc = secret;
+            let mut previous_digit = get_digit(secret_acc);
+            let mut digits = Vec::new();
+            for _ in 0..=2000 {
+                secret_acc = step(secret_acc);
+                let digit = get_digit(secret_acc);
+                let diff = (digit as i64) - (previous_digit as i64);
+                previous_digit = digit;
+                digits.push((digit, diff));
+            }
+            digits
+        })
+        .collect::<Vec<Vec<(u64, i64)>>>();
+
+    let mut all_sequences: HashMap<Vec<i64>, u64> = HashMap::new();
+    for sequence in sequences.iter() {
+        let mut this_sequences: HashMap<Vec<i64>, u64> = HashMap::new();
+        for subsequence in sequence.windows(4) {
+          let sequence_key = subsequence.iter().map(|(_, diff)| *diff).collect();
+
+          if this_sequences.contains_key(&sequence_key) {
+            continue;
+          }
+          this_sequences.insert(sequence_key, subsequence[3].0);
+        }
+        for (k, v) in this_sequences.into_iter() {
+            *all_sequences.entry(k).or_insert(0) += v;
+        }
+    }
+    let best = all_sequences
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1))
+        .expect("Should have a best sequence");
+
+    Some(*best.1)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 22: Monkey Market ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
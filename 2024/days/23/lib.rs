@@ -0,0 +1,67 @@
+use aoc_graph::{clique, Graph};
+use aoc_parse::{lines, Result};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Computer(String);
+
+struct Network(Graph<Computer>);
+
+impl Network {
+    fn name(&self, computer: usize) -> &str {
+        &self.0.get_node(computer).0
+    }
+}
+
+fn read_input(input: &str) -> Result<Network> {
+    let mut graph = Graph::new();
+
+    for line in lines(input) {
+        let (left, right) = line.split_once("-")?;
+        let left_computer = graph.add_node(Computer(left.to_string()));
+        let right_computer = graph.add_node(Computer(right.to_string()));
+
+        graph.add_undirected_edge(left_computer, right_computer, 1);
+    }
+
+    Ok(Network(graph))
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let network = read_input(input).unwrap();
+    let result = clique::k_cliques(&network.0, 3)
+        .into_iter()
+        .filter(|sub_network| {
+            sub_network
+                .iter()
+                .any(|computer| network.name(*computer).starts_with("t"))
+        })
+        .count();
+    Some(result)
+}
+
+fn part2(input: &str) -> Option<String> {
+    let network = read_input(input).unwrap();
+    let mut computers = clique::maximum_clique(&network.0)
+        .into_iter()
+        .map(|computer| network.name(computer))
+        .collect::<Vec<&str>>();
+
+    computers.sort();
+    Some(computers.join(","))
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 23: LAN Party ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::{btree_map::Keys, HashSet};
+
+use aoc_parse::sections;
+
+fn get_lock_code(lock: &Vec<String>) -> Vec<usize> {
+  let mut code = vec![0; 5];
+  for column in 0..5 {
+    for row in lock[1..].iter() {
+        if &row[column..column + 1] == "#" {
+          code[column] += 1;
+        }
+    }
+  }
+  code
+}
+
+fn get_key_code(key: &Vec<String>) -> Vec<usize> {
+  let mut code = vec![0; 5];
+  for column in 0..5 {
+    for row in key[0..6].iter() {
+        if &row[column..column + 1] == "#" {
+          code[column] += 1;
+        }
+    }
+  }
+  code
+}
+
+fn key_fits(key: &Vec<usize>, lock: &Vec<usize>) -> bool {
+  for column in 0..5 {
+      let key_col = key[column];
+      let lock_col = lock[column];
+
+      let val = key_col as isize + lock_col as isize;
+      if val > 5 {
+        return false;
+      }
+  }
+  return true
+}
+
+fn read_input(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+
+  let mut keys = Vec::new();
+  let mut locks = Vec::new();
+
+  for section in sections(input) {
+    let current: Vec<String> = section.lines().map(|row| row.to_string()).collect();
+    if current[0] == "#####" {
+      locks.push(get_lock_code(&current));
+    } else {
+      keys.push(get_key_code(&current));
+    }
+  }
+  (locks, keys)
+}
+
+fn part1(input: &str) -> Option<isize> {
+  let (locks, keys) = read_input(input);
+  let mut result = 0;
+  for lock in locks.iter() {
+      for key in keys.iter() {
+        if key_fits(key, lock) {
+          result += 1;
+        }
+      }
+  }
+  Some(result)
+}
+
+fn part2(input: &str) -> Option<isize> {
+  None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 25: Code Chronicle ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
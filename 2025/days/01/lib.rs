@@ -0,0 +1,123 @@
+use aoc_parse::{parse_lines, Result, Span};
+
+#[derive(Debug)]
+enum DialRotation {
+  Left(i64),
+  Right(i64)
+}
+
+impl DialRotation {
+  fn parse(line: Span) -> Result<Self> {
+    let (direction, amount_str) = line.split_at(1)?;
+    let amount = amount_str.parse::<i64>()?;
+
+    match direction.as_str() {
+        "L" => Ok(Self::Left(amount)),
+        "R" => Ok(Self::Right(amount)),
+        _ => Err(direction.error(format!("Unexpected direction '{direction}'")))
+    }
+  }
+
+  fn count(&self) -> i64 {
+    match self {
+        Self::Left(count) => *count,
+        Self::Right(count) => *count,
+    }
+  }
+
+  fn direction(&self) -> i64 {
+    match self {
+        Self::Left(count) => -1,
+        Self::Right(count) => 1,
+    }
+  }
+}
+
+struct Dial {
+  rotation_state: i64,
+  dial_size: i64,
+  hits: i64,
+  count_rotation_hits: bool
+}
+
+impl Dial {
+    fn new(initial_rotation: i64, dial_size: i64, count_rotation_hits: bool) -> Self   {
+      Self { rotation_state: initial_rotation, dial_size, hits: 0, count_rotation_hits }
+    }
+
+    fn rotate(&mut self, rotation: &DialRotation) {
+      let count = rotation.count();
+      let direction = rotation.direction();
+      let mut rotations = 0;
+      let mut started_at_zero = self.rotation_state == 0;
+      
+      for index in 0..count {
+        let new_state = self.rotation_state + (1 * direction);
+        if new_state > self.dial_size {
+          self.rotation_state = 0;
+          if started_at_zero || index == count - 1 {
+            started_at_zero = false;
+          }else {
+            rotations += 1;
+          }
+        } else if new_state < 0 {
+          self.rotation_state = self.dial_size;
+          if started_at_zero {
+            started_at_zero = false;
+          }else {
+            rotations += 1;
+          }
+        } else {
+          self.rotation_state = new_state;
+          started_at_zero = false;
+        }
+      }
+
+      if self.count_rotation_hits {
+        self.hits += rotations;
+      }
+      if self.rotation_state == 0 {
+        self.hits += 1;
+      }
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<DialRotation>> {
+  parse_lines(input, DialRotation::parse)
+}
+
+fn part1(input: &str) -> Option<i64> {
+  let rotations = read_input(input).unwrap();
+  let mut dial = Dial::new(50, 99, false);
+  for rotation in rotations.iter() {
+      dial.rotate(rotation);
+  }
+
+  Some(dial.hits)
+}
+
+fn part2(input: &str) -> Option<i64> {
+  let rotations = read_input(input).unwrap();
+  let mut dial = Dial::new(50, 99, true);
+  for rotation in rotations.iter() {
+      dial.rotate(rotation);
+  }
+
+  Some(dial.hits)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 1: Secret Entrance ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
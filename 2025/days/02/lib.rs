@@ -0,0 +1,96 @@
+use aoc_interval::Interval;
+use aoc_parse::{single_line, Result, Span};
+
+fn parse_range(range: Span) -> Result<Interval<i64>> {
+    let (left_str, right_str) = range.split_once("-")?;
+    Ok(Interval::inclusive(left_str.parse()?, right_str.parse()?))
+}
+
+fn sum_invalid_ids(range: &Interval<i64>, pred: impl Fn(i64) -> bool) -> i64 {
+    (range.start..range.end).filter(|num| pred(*num)).sum()
+}
+
+fn is_repeat(num: i64) -> bool {
+    let num_str = num.to_string();
+
+    if num_str.len() % 2 > 0 {
+        return false;
+    }
+    let (left, right) = num_str.split_at(num_str.len() / 2);
+
+    left == right
+}
+
+fn is_sequence(num: i64) -> bool {
+    let num_str = num.to_string();
+
+    let chars: Vec<char> = num_str.chars().collect();
+
+    for window_size in 1..chars.len() {
+        if chars.len() % window_size > 0 {
+            continue;
+        }
+        
+        let mut sequence_found = true;
+        for index in 0..(chars.len() - window_size) {
+            let left = chars[index];
+            let right = chars[index + window_size];
+
+            if left != right {
+                sequence_found = false;
+                break;
+            }
+        }
+
+        if sequence_found {
+            return true;
+        }
+    }
+    false
+}
+
+fn read_input(input: &str) -> Result<Vec<Interval<i64>>> {
+    let line = single_line(input)?;
+
+    line.split(",")
+        .map(|range| range.trim())
+        .filter(|range| !range.is_empty())
+        .map(parse_range)
+        .collect()
+}
+
+fn part1(input: &str) -> Option<i64> {
+    Some(
+        read_input(input)
+            .unwrap()
+            .iter()
+            .map(|range| sum_invalid_ids(range, is_repeat))
+            .sum(),
+    )
+}
+
+fn part2(input: &str) -> Option<i64> {
+    Some(
+        read_input(input)
+            .unwrap()
+            .iter()
+            .map(|range| sum_invalid_ids(range, is_sequence))
+            .sum(),
+    )
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 2: Gift Shop ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
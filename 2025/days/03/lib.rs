@@ -0,0 +1,88 @@
+use aoc_parse::{parse_lines, Result, Span};
+
+#[derive(Debug)]
+struct BatteryBank {
+    batteries: Vec<u64>,
+}
+
+impl BatteryBank {
+    fn parse(line: Span) -> Result<Self> {
+        Ok(Self {
+            batteries: line.map_chars(|c| c.to_digit(10).map(u64::from))?,
+        })
+    }
+
+    fn largest_pair(&self, jolt_size: usize) -> Option<u64> {
+        let mut with_index = Vec::default();
+        for (index, battery) in self.batteries.iter().enumerate() {
+            with_index.push((index, *battery));
+        }
+
+        with_index.sort_by(|a, b| b.1.cmp(&a.1));
+
+        let mut largest_pair: Option<(usize, u64)> = None;
+        let mut largest_battery = 0;
+        for &pair in with_index.iter() {
+            if (with_index.len() - pair.0) >= jolt_size && pair.1 > largest_battery {
+              largest_pair = Some(pair);
+              largest_battery = pair.1;
+            }
+        }
+
+        
+        let mut look_at_pair = largest_pair.expect("Should have found a fitting pair");
+        let mut jolt_sum = look_at_pair.1 * 10_u64.pow((jolt_size - 1) as u32);
+
+        for size in (1..jolt_size).rev() {
+          for &(index, battery) in with_index.iter() {
+              if look_at_pair.0 < index && (with_index.len() - index) >= size {
+                look_at_pair = (index, battery);
+                jolt_sum += battery * 10_u64.pow((size - 1) as u32);
+                break;
+              }
+          }   
+        }
+
+        Some(jolt_sum)
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<BatteryBank>> {
+    parse_lines(input, BatteryBank::parse)
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let banks = read_input(input).unwrap();
+    let mut sum = 0;
+    for bank in banks {
+        let jolt = bank.largest_pair(2).expect("Should have a largest pair");
+        sum += jolt
+    }
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let banks = read_input(input).unwrap();
+    let mut sum = 0;
+    for bank in banks {
+        let jolt = bank.largest_pair(12).expect("Should have a largest pair");
+        sum += jolt
+    }
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 3: Lobby ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
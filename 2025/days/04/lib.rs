@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use aoc_geometry::Vector2;
+use aoc_parse::{grid_with_position, Result};
+
+type Grid = aoc_geometry::Grid<Tile>;
+
+#[derive(Debug)]
+enum TileType {
+    Paper,
+    Empty,
+    Forklift,
+}
+
+impl Display for TileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "."),
+            Self::Paper => write!(f, "@"),
+            Self::Forklift => write!(f, "x"),
+        }
+    }
+}
+
+impl TileType {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '@' => Some(Self::Paper),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Tile {
+    position: Vector2,
+    tile_type: TileType,
+}
+
+impl Tile {
+    fn from_char(c: char, position: Vector2) -> Option<Self> {
+        Some(Self {
+            position,
+            tile_type: TileType::from_char(c)?,
+        })
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tile_type)
+    }
+}
+
+fn read_input(input: &str) -> Result<Grid> {
+    grid_with_position(input, |position, c| Tile::from_char(c, position))
+}
+
+fn neighbors(position: Vector2, grid: &Grid) -> Vec<&Tile> {
+    grid.neighbors8(position).map(|(_, tile)| tile).collect()
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let mut grid = read_input(input).unwrap();
+    let mut hits = 0;
+    for position in grid.positions() {
+        match grid[position] {
+            Tile {
+                position,
+                tile_type: TileType::Paper,
+            } => {
+                let papers = neighbors(position, &grid)
+                    .iter()
+                    .filter(|tile| {
+                        matches!(tile.tile_type, TileType::Paper)
+                            || matches!(tile.tile_type, TileType::Forklift)
+                    })
+                    .count();
+
+                if papers < 4 {
+                    grid[position].tile_type = TileType::Forklift;
+                    hits += 1
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(hits)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let mut grid = read_input(input).unwrap();
+    let mut hits = 0;
+
+    loop {
+        let mut did_remove = false;
+        for position in grid.positions() {
+            match grid[position] {
+                Tile {
+                    position,
+                    tile_type: TileType::Paper,
+                } => {
+                    let papers = neighbors(position, &grid)
+                        .iter()
+                        .filter(|tile| {
+                            matches!(tile.tile_type, TileType::Paper)
+                                || matches!(tile.tile_type, TileType::Forklift)
+                        })
+                        .count();
+
+                    if papers < 4 {
+                        did_remove = true;
+                        grid[position].tile_type = TileType::Forklift;
+                        hits += 1
+                    }
+                }
+                _ => {}
+            }
+        }
+        for (_, tile) in grid.iter_mut() {
+            match tile {
+                Tile {
+                    position: _,
+                    tile_type: TileType::Forklift,
+                } => tile.tile_type = TileType::Empty,
+                _ => {}
+            }
+        }
+        if !did_remove {
+            break;
+        }
+        }
+    Some(hits)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 4: Printing Department ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_interval::{Interval, IntervalSet};
+use aoc_parse::{sections, Error, Result, Span};
+
+fn parse_range(line: Span) -> Result<Interval<usize>> {
+    let (left, right) = line.split_once("-")?;
+    Ok(Interval::inclusive(left.parse()?, right.parse()?))
+}
+
+fn read_input(input: &str) -> Result<(IntervalSet<usize>, Vec<usize>)> {
+    let sections = sections(input);
+    let [range_section, id_section, ..] = &sections[..] else {
+        return Err(Error::new(1, 1, "Expected ranges and ids"));
+    };
+
+    let ranges = range_section
+        .lines()
+        .map(parse_range)
+        .collect::<Result<_>>()?;
+    let ids = id_section
+        .lines()
+        .map(|line| line.parse())
+        .collect::<Result<_>>()?;
+
+    Ok((ranges, ids))
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let (ranges, ids) = read_input(input).unwrap();
+    let valid_ids = ids.iter().filter(|id| ranges.contains(**id)).count();
+    Some(valid_ids)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let (ranges, _) = read_input(input).unwrap();
+
+    Some(ranges.len())
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 5: Cafeteria ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
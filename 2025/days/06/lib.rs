@@ -0,0 +1,158 @@
+use aoc_parse::{Result, Span};
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Multiply,
+    Add,
+}
+
+impl Operator {
+    fn parse(span: Span) -> Result<Self> {
+        match span.trim().as_str() {
+            "*" => Ok(Self::Multiply),
+            "+" => Ok(Self::Add),
+            _ => Err(span.error("Expected an operator")),
+        }
+    }
+
+    fn solve(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Self::Add => a + b,
+            Self::Multiply => a * b,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Problem {
+    digits: Vec<String>,
+    operator: Option<Operator>,
+    max_size: usize,
+}
+
+impl Problem {
+    fn new() -> Self {
+        Self {
+            digits: Vec::default(),
+            operator: None,
+            max_size: 0,
+        }
+    }
+
+    fn calc_max_size(&mut self, op_or_digit: &str) -> bool {
+        if op_or_digit.is_empty() {
+            false
+        } else if op_or_digit.parse::<u64>().is_ok() {
+            self.max_size = self.max_size.max(op_or_digit.len());
+            true
+        } else {
+            true
+        }
+    }
+
+    fn add(&mut self, op_or_digit: Span) -> Result<()> {
+        if op_or_digit.trim().parse::<u64>().is_ok() {
+            self.digits.push(op_or_digit.as_str().to_string());
+        } else {
+            self.operator = Some(Operator::parse(op_or_digit)?);
+        }
+        Ok(())
+    }
+
+    fn solve(&self, as_column: bool) -> u64 {
+        let operator = self.operator.clone().expect("Should have an operator");
+
+        let mut digits = self.digits.clone();
+
+        if as_column {
+            let mut new_digits = Vec::default();
+            for digit_index in 0..self.max_size {
+                let mut accumulated_digit = String::new();
+                for digit in digits.iter() {
+                    let digit_str = &digit[digit_index..=digit_index];
+                    if !digit_str.is_empty() {
+                        accumulated_digit = format!("{accumulated_digit}{digit_str}");
+                    }
+                }
+                new_digits.push(accumulated_digit);
+            }
+            digits = new_digits;
+        }
+        digits
+            .into_iter()
+            .map(|digit| digit.trim().parse::<u64>().unwrap())
+            .reduce(|a, b| operator.solve(a, b))
+            .unwrap()
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Problem>> {
+    let mut problems = Vec::default();
+    let mut lines = Vec::default();
+    // The digits line up by column, so lines are read untrimmed
+    for (index, line) in input.lines().enumerate() {
+        let mut column = 0;
+        lines.push(Span::new(line, index + 1, 1));
+        for op_or_digit in line.split(" ") {
+            if (column + 1) > problems.len() {
+                problems.push(Problem::new());
+            }
+
+            if problems[column].calc_max_size(op_or_digit) {
+                column += 1;
+            }
+        }
+    }
+
+    problems.pop();
+
+    for line in lines {
+        let mut line_start = 0;
+        for problem in problems.iter_mut() {
+            let (_, cell) = line.split_at(line_start)?;
+            let (op_or_digit, _) = cell.split_at(problem.max_size)?;
+            line_start += problem.max_size + 1;
+            problem.add(op_or_digit)?;
+        }
+    }
+
+    Ok(problems)
+}
+
+fn part1(input: &str) -> Option<u64> {
+    let problems = read_input(input).unwrap();
+
+    let sum = problems
+        .into_iter()
+        .map(|problem| problem.solve(false))
+        .sum();
+
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<u64> {
+    let problems = read_input(input).unwrap();
+    
+    let sum = problems
+        .into_iter()
+        .map(|problem| problem.solve(true))
+        .sum();
+
+    Some(sum)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 6: Trash Compactor ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
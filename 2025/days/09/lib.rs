@@ -0,0 +1,140 @@
+use std::fmt::Display;
+
+use aoc_geometry::{Location, Polygon, Vector2};
+use aoc_parse::{lines, Result, Span};
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+enum TileType {
+    Red,
+    Green,
+    Black,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct Tile {
+    position: Vector2,
+    tile_type: TileType,
+}
+
+impl Tile {
+    fn parse(line: Span) -> Result<Self> {
+        let [x, y] = line.ints_array()?;
+
+        Ok(Self {
+            position: Vector2::new(x, y),
+            tile_type: TileType::Red,
+        })
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.tile_type {
+            TileType::Red => write!(f, "O"),
+            TileType::Green => write!(f, "X"),
+            TileType::Black => write!(f, "."),
+        }
+    }
+}
+
+struct Theater {
+    tiles: Vec<Tile>,
+    polygon: Polygon,
+    height: usize,
+    width: usize,
+}
+
+impl Theater {
+    fn new(tiles: Vec<Tile>) -> Self {
+        let width = tiles.iter().map(|tile| tile.position.0 as usize).max();
+        let height = tiles.iter().map(|tile| tile.position.1 as usize).max();
+        let polygon = Polygon::new(tiles.iter().map(|tile| tile.position).collect());
+
+        Self {
+            tiles,
+            polygon,
+            height: height.unwrap_or_default(),
+            width: width.unwrap_or_default(),
+        }
+    }
+
+    fn get_largest_square(&self, inside: bool) -> (usize, usize, isize) {
+        let compressed = inside.then(|| self.polygon.compress());
+        let mut best: Option<(usize, usize, isize)> = None;
+        for a_index in 0..self.tiles.len() {
+            for b_index in a_index + 1..self.tiles.len() {
+                let a = self.tiles[a_index].position;
+                let b = self.tiles[b_index].position;
+
+                let size_vec = (a - b).abs() + Vector2(1, 1);
+                let size = size_vec.0 * size_vec.1;
+                if best.is_some_and(|best| size <= best.2) {
+                    continue;
+                }
+                if compressed
+                    .as_ref()
+                    .is_none_or(|compressed| compressed.contains_rectangle(a, b))
+                {
+                    best = Some((a_index, b_index, size))
+                }
+            }
+        }
+
+        best.expect("Should have a largest square")
+    }
+}
+
+impl Display for Theater {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "width({}) height({})\n", self.width, self.height)?;
+        for y in 0..=self.height {
+            for x in 0..=self.width {
+                let position = Vector2(x as isize, y as isize);
+                if let Some(tile) = self.tiles.iter().find(|tile| tile.position == position) {
+                    write!(f, "{tile}")?;
+                } else {
+                    match self.polygon.locate(position) {
+                        Location::Boundary => write!(f, "X")?,
+                        Location::Inside => write!(f, "#")?,
+                        Location::Outside => write!(f, " ")?,
+                    }
+                }
+            }
+            write!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+fn read_input(input: &str) -> Result<Theater> {
+    let tiles = lines(input).map(Tile::parse).collect::<Result<_>>()?;
+    Ok(Theater::new(tiles))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let theater = read_input(input).unwrap();
+    let square = theater.get_largest_square(false);
+    Some(square.2)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let theater = read_input(input).unwrap();
+    let square = theater.get_largest_square(true);
+    Some(square.2)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 9: Movie Theater ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
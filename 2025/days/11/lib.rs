@@ -0,0 +1,58 @@
+use aoc_graph::{count_paths, count_paths_through_a_then_b, Graph};
+use aoc_parse::{lines, Result};
+
+fn read_input(input: &str) -> Result<Graph<String>> {
+    let mut graph = Graph::new();
+
+    for line in lines(input) {
+        let (node_str, edge_str) = line.split_once(": ")?;
+        let node = graph.add_node(node_str.to_string());
+
+        for edge_node_str in edge_str.split_whitespace() {
+            let edge_node = graph.add_node(edge_node_str.to_string());
+            graph.add_edge(node, edge_node, 1);
+        }
+    }
+
+    Ok(graph)
+}
+
+fn part1(input: &str) -> Option<usize> {
+    let graph = read_input(input).unwrap();
+    let device = |name: &str| graph.get_id(&name.to_string());
+
+    let paths = match device("you").zip(device("out")) {
+        Some((you, out)) => count_paths(&graph, you, out).ok()?,
+        None => 0,
+    };
+    Some(paths as usize)
+}
+
+fn part2(input: &str) -> Option<usize> {
+    let graph = read_input(input).unwrap();
+    let device = |name: &str| graph.get_id(&name.to_string());
+
+    let count = match [device("svr"), device("out"), device("fft"), device("dac")] {
+        [Some(svr), Some(out), Some(fft), Some(dac)] => {
+            count_paths_through_a_then_b(&graph, svr, out, fft, dac).ok()?
+        }
+        _ => 0,
+    };
+    Some(count as usize)
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 11: Reactor ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
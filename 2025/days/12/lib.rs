@@ -0,0 +1,299 @@
+use std::{f32::consts::E, fmt::Display};
+
+use aoc_parse::{sections, Span};
+
+#[derive(Debug, Clone)]
+struct Present {
+    id: usize,
+    shape: Vec<Vec<bool>>,
+    width: usize,
+    height: usize,
+}
+
+impl Display for Present {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Present({})", self.id)?;
+        for row in self.shape.iter() {
+            for tile in row.iter() {
+                if *tile {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            write!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Present {
+    fn new(line: Span) -> Option<Self> {
+        let (id_str, _) = line.split_once(":").ok()?;
+        let id = id_str.parse().ok()?;
+
+        Some(Self {
+            shape: Vec::default(),
+            id,
+            width: 0,
+            height: 0,
+        })
+    }
+
+    fn add_shape_row(&mut self, row_str: Span) -> aoc_parse::Result<()> {
+        let row = row_str.map_chars(|c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        self.width = row.len();
+        self.height += 1;
+        self.shape.push(row);
+        Ok(())
+    }
+
+    fn rot_90(&mut self) {
+        let mut next_shape = self.shape.clone();
+
+        for (row_index, row) in self.shape.iter().enumerate() {
+            for (column_index, tile) in row.iter().enumerate() {
+                next_shape[column_index][(row.len() - 1) - row_index] = *tile;
+            }
+        }
+        self.shape = next_shape;
+    }
+
+    fn flip_horizontal(&mut self) {
+        for row in self.shape.iter_mut() {
+            row.reverse();
+        }
+    }
+
+    fn flip_vertical(&mut self) {
+        let mut next_shape = self.shape.clone();
+        for (row_index, row) in self.shape.iter().enumerate() {
+            next_shape[(self.shape.len() - 1) - row_index] = row.clone();
+        }
+        self.shape = next_shape;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    width: usize,
+    height: usize,
+    presents: Vec<usize>,
+    shape: Vec<Vec<bool>>,
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Region({},{})", self.width, self.height)?;
+        for row in self.shape.iter() {
+            for tile in row.iter() {
+                if *tile {
+                    write!(f, "#")?;
+                } else {
+                    write!(f, ".")?;
+                }
+            }
+            write!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl Region {
+    fn parse(line: Span) -> aoc_parse::Result<Self> {
+        let (size_str, presents_str) = line.split_once(": ")?;
+        let (width_str, height_str) = size_str.split_once("x")?;
+        let width = width_str.parse()?;
+        let height = height_str.parse()?;
+
+        let mut shape = Vec::default();
+        for _ in 0..height {
+            let mut row = Vec::default();
+            for _ in 0..width {
+                row.push(false);
+            }
+            shape.push(row);
+        }
+
+        let presents = presents_str.list(" ")?;
+
+        Ok(Self {
+            width,
+            height,
+            presents,
+            shape,
+        })
+    }
+
+    fn try_fit(&self, present: &Present) -> Vec<Vec<Vec<bool>>> {
+        // Need to find all possible positions
+        let mut fitting_shapes = Vec::new();
+        for region_row_index in 0..=(self.height - present.height) {
+            for region_column_index in 0..=(self.width - present.width) {
+                let mut did_fit = true;
+                let mut new_shape = self.shape.clone();
+
+                'present: for present_row_index in 0..present.height {
+                    let row_index = region_row_index + present_row_index;
+
+                    for present_column_index in 0..present.width {
+                        let column_index = region_column_index + present_column_index;
+                        if new_shape[row_index][column_index]
+                            && present.shape[present_row_index][present_column_index]
+                        {
+                            did_fit = false;
+                            break 'present;
+                        } else if present.shape[present_row_index][present_column_index] {
+                            new_shape[row_index][column_index] = true;
+                        }
+                    }
+                }
+                if did_fit {
+                    fitting_shapes.push(new_shape);
+                }
+            }
+        }
+
+        fitting_shapes
+    }
+
+    fn can_fit_all(&self, presents: &Vec<Present>) -> bool {
+        let present_area = self
+            .presents
+            .iter()
+            .enumerate()
+            .map(|(present_index, count)| {
+                count * (presents[present_index].width * presents[present_index].height)
+            })
+            .sum();
+        let region_area = self.width * self.height;
+        region_area >= present_area
+    }
+
+    fn can_optimally_fit_all(&self, presents: &Vec<Present>) -> bool {
+        let present_tile_count = self
+            .presents
+            .iter()
+            .enumerate()
+            .map(|(present_index, count)| {
+                let tile_count: usize = presents[present_index]
+                    .shape
+                    .iter()
+                    .flat_map(|row| {
+                        row.iter()
+                            .filter_map(|tile| if *tile { Some(1usize) } else { None })
+                    })
+                    .sum();
+                count * tile_count
+            })
+            .sum();
+        let region_area = self.width * self.height;
+
+        region_area >= present_tile_count
+    }
+}
+
+fn try_pack(region: &Region, presents: &Vec<Present>) -> Result<Region, ()> {
+    let mut my_region = region.clone();
+    for (present_id, count) in my_region.presents.clone().into_iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        // Rotate present 4 times
+        let mut present = presents[present_id].clone();
+        let mut shapes = Vec::new();
+        for _ in 0..4 {
+            for shape in my_region.try_fit(&present) {
+                shapes.push(shape);
+            }
+            present.rot_90();
+        }
+
+        if shapes.len() == 0 {
+            return Err(());
+        }
+
+        my_region.presents[present_id] -= 1;
+        let mut success = true;
+        for shape in shapes {
+            my_region.shape = shape;
+            if let Ok(finalized_region) = try_pack(&my_region, presents) {
+                return Ok(finalized_region);
+            } else {
+                success = false
+            }
+        }
+        if !success {
+            return Err(());
+        }
+    }
+    Ok(my_region)
+}
+
+fn try_fit_region(region: &Region, presents: &Vec<Present>) -> Result<Region, ()> {
+    if region.can_fit_all(presents) {
+        Ok(region.clone())
+    } else if region.can_optimally_fit_all(presents) {
+        Ok(region.clone())
+    } else {
+        //try_pack(region, presents)
+        Err(())
+    }
+}
+
+fn read_input(input: &str) -> aoc_parse::Result<(Vec<Present>, Vec<Region>)> {
+    let mut presents = Vec::default();
+    let mut regions = Vec::default();
+
+    for section in sections(input) {
+        let mut lines = section.lines();
+        if let Some(mut present) = lines.next().and_then(Present::new) {
+            for row in lines {
+                present.add_shape_row(row)?;
+            }
+            presents.push(present);
+        } else {
+            for line in section.lines() {
+                regions.push(Region::parse(line)?);
+            }
+        }
+    }
+
+    Ok((presents, regions))
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let (presents, regions) = read_input(input).unwrap();
+    let mut sum = 0;
+    for r in regions.iter() {
+        if try_fit_region(r, &presents).is_ok() {
+            sum += 1;
+        }
+    }
+    Some(sum)
+}
+
+fn part2(input: &str) -> Option<isize> {
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "--- Day 12: Christmas Tree Farm ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+/// Signed integers fractions can be made of. `i128` leaves the most room
+/// before products overflow.
+pub trait Integer:
+    Copy
+    + Ord
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+}
+
+impl<T> Integer for T where
+    T: Copy
+        + Ord
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + Neg<Output = T>
+{
+}
+
+/// Greatest common divisor, never negative and zero only when both are.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let zero = T::from(0);
+    let (mut a, mut b) = (a, b);
+    while b != zero {
+        (a, b) = (b, a % b);
+    }
+    if a < zero {
+        -a
+    } else {
+        a
+    }
+}
+
+/// An exact fraction, always in lowest terms with a positive denominator so
+/// equal values are equal field by field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fraction<T = i128> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Integer> Fraction<T> {
+    /// Panics when `denominator` is zero.
+    pub fn new(numerator: T, denominator: T) -> Self {
+        let zero = T::from(0);
+        assert!(denominator != zero, "Fraction with a zero denominator");
+
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < zero {
+            -divisor
+        } else {
+            divisor
+        };
+        Self {
+            numerator: numerator / sign,
+            denominator: denominator / sign,
+        }
+    }
+
+    pub fn zero() -> Self {
+        T::from(0).into()
+    }
+
+    pub fn one() -> Self {
+        T::from(1).into()
+    }
+
+    pub fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> T {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == T::from(0)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == T::from(1)
+    }
+
+    /// `None` unless the fraction is a whole number.
+    pub fn to_integer(&self) -> Option<T> {
+        self.is_integer().then_some(self.numerator)
+    }
+
+    /// Panics on zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+}
+
+impl<T: Integer> From<T> for Fraction<T> {
+    fn from(value: T) -> Self {
+        Self {
+            numerator: value,
+            denominator: T::from(1),
+        }
+    }
+}
+
+// Common factors are divided out before multiplying to put off overflowing
+// for as long as possible
+
+impl<T: Integer> Add for Fraction<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let divisor = gcd(self.denominator, rhs.denominator);
+        let (left, right) = (self.denominator / divisor, rhs.denominator / divisor);
+        Self::new(
+            self.numerator * right + rhs.numerator * left,
+            left * rhs.denominator,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Fraction<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Integer> Mul for Fraction<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let left = gcd(self.numerator, rhs.denominator);
+        let right = gcd(rhs.numerator, self.denominator);
+        Self::new(
+            (self.numerator / left) * (rhs.numerator / right),
+            (self.denominator / right) * (rhs.denominator / left),
+        )
+    }
+}
+
+impl<T: Integer> Div for Fraction<T> {
+    type Output = Self;
+
+    /// Panics when dividing by zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "Fraction divided by zero");
+        let top = gcd(self.numerator, rhs.numerator);
+        let bottom = gcd(self.denominator, rhs.denominator);
+        Self::new(
+            (self.numerator / top) * (rhs.denominator / bottom),
+            (self.denominator / bottom) * (rhs.numerator / top),
+        )
+    }
+}
+
+impl<T: Integer> Neg for Fraction<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl<T: Integer> Ord for Fraction<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
+impl<T: Integer> PartialOrd for Fraction<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer + Display> Display for Fraction<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod fraction;
+mod linear;
+mod polynomial;
+
+pub use fraction::{gcd, Fraction, Integer};
+pub use linear::{cramer, determinant, solve, solve_integer};
+pub use polynomial::{lagrange, Polynomial};
@@ -0,0 +1,116 @@
+use crate::{Fraction, Integer};
+
+/// Solves `coefficients · x = constants` by Gauss-Jordan elimination.
+/// More equations than unknowns are fine as long as they agree, `None` when
+/// there is no solution or more than one.
+pub fn solve<T: Integer>(coefficients: &[Vec<T>], constants: &[T]) -> Option<Vec<Fraction<T>>> {
+    assert_eq!(
+        coefficients.len(),
+        constants.len(),
+        "One constant per equation"
+    );
+    let unknowns = coefficients.first().map_or(0, |row| row.len());
+    let mut rows: Vec<Vec<Fraction<T>>> = coefficients
+        .iter()
+        .zip(constants)
+        .map(|(row, constant)| {
+            assert_eq!(row.len(), unknowns, "One coefficient per unknown");
+            row.iter()
+                .chain([constant])
+                .map(|value| Fraction::from(*value))
+                .collect()
+        })
+        .collect();
+
+    for column in 0..unknowns {
+        let pivot = (column..rows.len()).find(|row| !rows[*row][column].is_zero())?;
+        rows.swap(column, pivot);
+
+        let scale = rows[column][column].recip();
+        rows[column]
+            .iter_mut()
+            .for_each(|value| *value = *value * scale);
+
+        let pivot_row = rows[column].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            let factor = row[column];
+            if index == column || factor.is_zero() {
+                continue;
+            }
+            row.iter_mut()
+                .zip(&pivot_row)
+                .for_each(|(value, pivot)| *value = *value - factor * *pivot);
+        }
+    }
+
+    // Whatever is left over reads 0 = constant
+    if rows[unknowns..].iter().any(|row| !row[unknowns].is_zero()) {
+        return None;
+    }
+    Some(rows[..unknowns].iter().map(|row| row[unknowns]).collect())
+}
+
+/// Like [`solve`], but only whole-number solutions count.
+pub fn solve_integer<T: Integer>(coefficients: &[Vec<T>], constants: &[T]) -> Option<Vec<T>> {
+    solve(coefficients, constants)?
+        .iter()
+        .map(Fraction::to_integer)
+        .collect()
+}
+
+/// By cofactor expansion along the first row, which only stays cheap for the
+/// handful of unknowns puzzles have.
+pub fn determinant<T: Integer>(matrix: &[Vec<T>]) -> T {
+    let Some(first) = matrix.first() else {
+        return T::from(1);
+    };
+
+    let mut sum = T::from(0);
+    for (column, value) in first.iter().enumerate() {
+        if *value == T::from(0) {
+            continue;
+        }
+        let minor: Vec<Vec<T>> = matrix[1..]
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != column)
+                    .map(|(_, value)| *value)
+                    .collect()
+            })
+            .collect();
+        let term = *value * determinant(&minor);
+        sum = if column % 2 == 0 {
+            sum + term
+        } else {
+            sum - term
+        };
+    }
+    sum
+}
+
+/// Solves a square system by Cramer's rule, every unknown being a ratio of
+/// two integer determinants. `None` when the determinant is zero.
+pub fn cramer<T: Integer>(coefficients: &[Vec<T>], constants: &[T]) -> Option<Vec<Fraction<T>>> {
+    let divisor = determinant(coefficients);
+    if divisor == T::from(0) {
+        return None;
+    }
+
+    let solution = (0..constants.len())
+        .map(|column| {
+            let replaced: Vec<Vec<T>> = coefficients
+                .iter()
+                .zip(constants)
+                .map(|(row, constant)| {
+                    let mut row = row.clone();
+                    row[column] = *constant;
+                    row
+                })
+                .collect();
+            Fraction::new(determinant(&replaced), divisor)
+        })
+        .collect();
+    Some(solution)
+}
@@ -0,0 +1,66 @@
+use crate::{solve, Fraction, Integer};
+
+/// A polynomial with exact coefficients, lowest power first.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<T = i128> {
+    coefficients: Vec<Fraction<T>>,
+}
+
+impl<T: Integer> Polynomial<T> {
+    pub fn new(coefficients: Vec<Fraction<T>>) -> Self {
+        Self { coefficients }
+    }
+
+    /// The polynomial of the lowest degree going through every point, `None`
+    /// when two of them share an `x`. Solves for the `n` coefficients `n`
+    /// points pin down, then drops the top ones that come out zero, so points
+    /// on a line give a line.
+    pub fn fit(points: &[(T, T)]) -> Option<Self> {
+        let rows: Vec<Vec<T>> = points
+            .iter()
+            .map(|(x, _)| {
+                std::iter::successors(Some(T::from(1)), |power| Some(*power * *x))
+                    .take(points.len())
+                    .collect()
+            })
+            .collect();
+        let values: Vec<T> = points.iter().map(|(_, y)| *y).collect();
+
+        let mut coefficients = solve(&rows, &values)?;
+        while coefficients.last().is_some_and(Fraction::is_zero) {
+            coefficients.pop();
+        }
+        Some(Self::new(coefficients))
+    }
+
+    pub fn coefficients(&self) -> &[Fraction<T>] {
+        &self.coefficients
+    }
+
+    pub fn evaluate(&self, x: T) -> Fraction<T> {
+        let x = Fraction::from(x);
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Fraction::zero(), |sum, coefficient| sum * x + *coefficient)
+    }
+}
+
+/// The value at `x` of the polynomial through `points`, by Lagrange's
+/// formula and without working out the coefficients. Panics when two points
+/// share an `x`.
+pub fn lagrange<T: Integer>(points: &[(T, T)], x: T) -> Fraction<T> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, (xi, yi))| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| i != *j)
+                .fold(Fraction::from(*yi), |term, (_, (xj, _))| {
+                    term * Fraction::new(x - *xj, *xi - *xj)
+                })
+        })
+        .fold(Fraction::zero(), |sum, term| sum + term)
+}
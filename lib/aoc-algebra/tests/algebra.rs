@@ -0,0 +1,119 @@
+use aoc_algebra::{cramer, determinant, gcd, lagrange, solve, solve_integer, Fraction, Polynomial};
+
+fn fractions(values: &[(i128, i128)]) -> Vec<Fraction> {
+    values
+        .iter()
+        .map(|(numerator, denominator)| Fraction::new(*numerator, *denominator))
+        .collect()
+}
+
+#[test]
+fn fractions_stay_in_lowest_terms() {
+    let half = Fraction::new(3, 6);
+
+    assert_eq!(half, Fraction::new(-1, -2));
+    assert_eq!((half.numerator(), half.denominator()), (1, 2));
+    assert_eq!(Fraction::new(2, -4), -half);
+    assert_eq!(half + Fraction::new(1, 3), Fraction::new(5, 6));
+    assert_eq!(half - Fraction::new(3, 4), Fraction::new(-1, 4));
+    assert_eq!(half * Fraction::new(4, 3), Fraction::new(2, 3));
+    assert_eq!(half / Fraction::new(1, 4), Fraction::from(2));
+    assert_eq!(Fraction::zero() / half, Fraction::zero());
+    assert_eq!((half * Fraction::from(4)).to_integer(), Some(2));
+    assert_eq!(half.to_integer(), None);
+    assert!(Fraction::new(-1, 3) < Fraction::new(-1, 4));
+    assert_eq!(Fraction::new(-7, 14).to_string(), "-1/2");
+    assert_eq!(gcd(-12, 18), 6);
+}
+
+#[test]
+#[should_panic(expected = "zero denominator")]
+fn fractions_refuse_zero_denominators() {
+    Fraction::new(1, 0);
+}
+
+#[test]
+fn gaussian_elimination_solves_exactly() {
+    let coefficients = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+    let constants = [8, -11, -3];
+    assert_eq!(
+        solve_integer(&coefficients, &constants),
+        Some(vec![2, 3, -1])
+    );
+
+    // A zero where the first pivot would be, and a fractional answer
+    let coefficients = vec![vec![0, 3], vec![2, 1]];
+    assert_eq!(
+        solve(&coefficients, &[1, 1]),
+        Some(fractions(&[(1, 3), (1, 3)]))
+    );
+    assert_eq!(solve_integer(&coefficients, &[1, 1]), None);
+
+    // Extra equations have to agree with the rest
+    let coefficients = vec![vec![1, 1], vec![1, -1], vec![2, 1]];
+    assert_eq!(solve_integer(&coefficients, &[3, 1, 5]), Some(vec![2, 1]));
+    assert_eq!(solve(&coefficients, &[3, 1, 6]), None);
+
+    let singular = vec![vec![1, 2], vec![2, 4]];
+    assert_eq!(solve(&singular, &[3, 6]), None);
+}
+
+#[test]
+fn cramer_agrees_with_elimination() {
+    // The first claw machine from 2024 day 13
+    let coefficients = vec![vec![94, 22], vec![34, 67]];
+    let constants = [8400, 5400];
+
+    assert_eq!(determinant(&coefficients), 94 * 67 - 22 * 34);
+    assert_eq!(
+        cramer(&coefficients, &constants),
+        Some(fractions(&[(80, 1), (40, 1)]))
+    );
+    assert_eq!(
+        cramer(&coefficients, &constants),
+        solve(&coefficients, &constants)
+    );
+    assert_eq!(
+        determinant(&[vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 2]]),
+        6
+    );
+    assert_eq!(cramer(&[vec![1, 2], vec![2, 4]], &[3, 6]), None);
+}
+
+#[test]
+fn polynomials_fit_and_extrapolate() {
+    let points = [(0, 1), (1, 3), (2, 7)];
+    let polynomial = Polynomial::fit(&points).unwrap();
+
+    assert_eq!(
+        polynomial.coefficients(),
+        fractions(&[(1, 1), (1, 1), (1, 1)])
+    );
+    assert_eq!(polynomial.evaluate(10), Fraction::from(111));
+    assert_eq!(lagrange(&points, 10), Fraction::from(111));
+    assert_eq!(lagrange(&[(0, 0), (2, 1)], 1), Fraction::new(1, 2));
+    assert_eq!(Polynomial::fit(&[(1, 1), (1, 2)]), None);
+
+    let big = 26501365 / 131;
+    assert_eq!(
+        lagrange(&points, big),
+        polynomial.evaluate(big),
+        "{}",
+        big * big + big + 1
+    );
+}
+
+#[test]
+fn fitting_drops_zero_top_coefficients() {
+    // Four points on a line
+    let line = Polynomial::fit(&[(0, 1), (1, 3), (2, 5), (3, 7)]).unwrap();
+    assert_eq!(line.coefficients(), fractions(&[(1, 1), (2, 1)]));
+    assert_eq!(line.evaluate(10), Fraction::from(21));
+
+    let constant = Polynomial::fit(&[(-1, 4), (5, 4), (9, 4)]).unwrap();
+    assert_eq!(constant.coefficients(), fractions(&[(4, 1)]));
+
+    let zero = Polynomial::fit(&[(1, 0), (2, 0)]).unwrap();
+    assert!(zero.coefficients().is_empty());
+    assert_eq!(zero.evaluate(7), Fraction::zero());
+}
@@ -0,0 +1,9 @@
+mod line;
+mod plane;
+mod sparse;
+mod swarm;
+
+pub use line::{Line, LineRule};
+pub use plane::{Neighbours, Plane};
+pub use sparse::Sparse;
+pub use swarm::Swarm;
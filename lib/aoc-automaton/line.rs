@@ -0,0 +1,164 @@
+/// What becomes of a cell given itself and the two cells on either side,
+/// one entry for each of the 32 neighbourhoods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineRule([bool; 32]);
+
+impl LineRule {
+    /// `neighbourhood` goes left to right, the cell itself in the middle.
+    pub fn set(&mut self, neighbourhood: [bool; 5], alive: bool) {
+        self.0[Self::index(neighbourhood)] = alive;
+    }
+
+    pub fn get(&self, neighbourhood: [bool; 5]) -> bool {
+        self.0[Self::index(neighbourhood)]
+    }
+
+    fn index(neighbourhood: [bool; 5]) -> usize {
+        neighbourhood
+            .iter()
+            .fold(0, |index, alive| index << 1 | *alive as usize)
+    }
+}
+
+impl FromIterator<([bool; 5], bool)> for LineRule {
+    fn from_iter<I: IntoIterator<Item = ([bool; 5], bool)>>(iter: I) -> Self {
+        let mut rule = Self::default();
+        for (neighbourhood, alive) in iter {
+            rule.set(neighbourhood, alive);
+        }
+        rule
+    }
+}
+
+/// A one dimensional automaton on a line going on forever both ways, one
+/// bit per cell. Stepping writes into a second buffer that is swapped in
+/// afterwards, the buffers only being reallocated when the live cells no
+/// longer fit.
+#[derive(Debug, Clone)]
+pub struct Line {
+    rule: LineRule,
+    /// Position of the lowest bit of the first word
+    offset: isize,
+    words: Vec<u64>,
+    next: Vec<u64>,
+}
+
+impl Line {
+    /// Panics if `rule` brings empty stretches to life, that would fill the
+    /// whole line.
+    pub fn new(rule: LineRule, alive: impl IntoIterator<Item = isize>) -> Self {
+        assert!(!rule.get([false; 5]), "Rule brings empty cells to life");
+
+        let alive: Vec<isize> = alive.into_iter().collect();
+        let offset = alive.iter().min().copied().unwrap_or_default() - 64;
+        let span = alive.iter().max().map_or(0, |last| last - offset) as usize;
+        let mut line = Self {
+            rule,
+            offset,
+            words: vec![0; span / 64 + 2],
+            next: Vec::new(),
+        };
+        for position in alive {
+            line.set(position);
+        }
+        line
+    }
+
+    pub fn get(&self, position: isize) -> bool {
+        let index = position - self.offset;
+        if index < 0 {
+            return false;
+        }
+        let index = index as usize;
+        self.words
+            .get(index / 64)
+            .is_some_and(|word| word >> (index % 64) & 1 == 1)
+    }
+
+    fn set(&mut self, position: isize) {
+        let index = (position - self.offset) as usize;
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    /// The leftmost and rightmost live cells.
+    pub fn bounds(&self) -> Option<(isize, isize)> {
+        let first = self.words.iter().position(|word| *word != 0)?;
+        let last = self.words.iter().rposition(|word| *word != 0)?;
+        Some((
+            self.offset + (first * 64) as isize + self.words[first].trailing_zeros() as isize,
+            self.offset + (last * 64 + 63) as isize - self.words[last].leading_zeros() as isize,
+        ))
+    }
+
+    /// Live cells, left to right.
+    pub fn alive(&self) -> impl Iterator<Item = isize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, word)| {
+                let start = self.offset + (index * 64) as isize;
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros();
+                    word &= word - 1;
+                    Some(start + bit as isize)
+                })
+            })
+    }
+
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Live cells relative to the leftmost one, the same for lines that only
+    /// moved.
+    pub fn pattern(&self) -> Vec<isize> {
+        let first = self.bounds().map_or(0, |(first, _)| first);
+        self.alive().map(|position| position - first).collect()
+    }
+
+    pub fn step(&mut self) {
+        let Some((first, last)) = self.bounds() else {
+            return;
+        };
+        self.make_room(first - 2, last + 2);
+
+        self.next.clear();
+        self.next.resize(self.words.len(), 0);
+        let mut neighbourhood = 0;
+        for position in first..=last + 4 {
+            neighbourhood = (neighbourhood << 1 | self.get(position) as usize) & 31;
+            if self.rule.0[neighbourhood] {
+                let index = (position - 2 - self.offset) as usize;
+                self.next[index / 64] |= 1 << (index % 64);
+            }
+        }
+        std::mem::swap(&mut self.words, &mut self.next);
+    }
+
+    /// Moves the live cells to the middle of the buffers, doubling them when
+    /// needed, unless `start..=end` already fits.
+    fn make_room(&mut self, start: isize, end: isize) {
+        let capacity = (self.words.len() * 64) as isize;
+        if start >= self.offset && end < self.offset + capacity {
+            return;
+        }
+
+        let span = (end - start) as usize / 64 + 1;
+        let length = self.words.len().max(span * 2);
+        let offset = start - ((length - span) / 2 * 64) as isize;
+        let alive: Vec<isize> = self.alive().collect();
+        self.words.clear();
+        self.words.resize(length, 0);
+        self.offset = offset;
+        for position in alive {
+            self.set(position);
+        }
+    }
+}
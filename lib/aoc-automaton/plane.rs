@@ -0,0 +1,91 @@
+use aoc_geometry::{Grid, Vector2};
+
+/// The up to eight cells around one in a [`Plane`].
+#[derive(Debug, Clone, Copy)]
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    position: Vector2,
+}
+
+impl<'a, T> Neighbours<'a, T> {
+    /// Position of the cell they surround.
+    pub fn position(&self) -> Vector2 {
+        self.position
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.grid.neighbors8(self.position).map(|(_, cell)| cell)
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.iter().filter(|cell| predicate(cell)).count()
+    }
+
+    pub fn any(&self, predicate: impl Fn(&T) -> bool) -> bool {
+        self.iter().any(predicate)
+    }
+}
+
+/// A two dimensional automaton on a bounded grid, every cell only seeing its
+/// eight neighbours. Generations go back and forth between two grids, so
+/// stepping allocates nothing.
+#[derive(Debug, Clone)]
+pub struct Plane<T> {
+    current: Grid<T>,
+    next: Grid<T>,
+}
+
+impl<T: Clone> Plane<T> {
+    pub fn new(grid: Grid<T>) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+        }
+    }
+}
+
+impl<T> Plane<T> {
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+}
+
+impl<T: Send + Sync> Plane<T> {
+    /// Every cell replaced by what `rule` makes of it, all at once. Rows are
+    /// worked on in parallel with the `rayon` feature.
+    pub fn step(&mut self, rule: impl Fn(&T, Neighbours<T>) -> T + Sync + Send) {
+        let current = &self.current;
+        fill_rows(&mut self.next, |y, row| {
+            for (x, cell) in row.iter_mut().enumerate() {
+                let position = Vector2(x as isize, y as isize);
+                let neighbours = Neighbours {
+                    grid: current,
+                    position,
+                };
+                *cell = rule(&current[position], neighbours);
+            }
+        });
+        std::mem::swap(&mut self.current, &mut self.next);
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn fill_rows<T: Send>(grid: &mut Grid<T>, fill: impl Fn(usize, &mut [T]) + Sync + Send) {
+    use rayon::prelude::*;
+
+    grid.rows_mut()
+        .enumerate()
+        .par_bridge()
+        .for_each(|(y, row)| fill(y, row));
+}
+
+#[cfg(not(feature = "rayon"))]
+fn fill_rows<T: Send>(grid: &mut Grid<T>, fill: impl Fn(usize, &mut [T]) + Sync + Send) {
+    for (y, row) in grid.rows_mut().enumerate() {
+        fill(y, row);
+    }
+}
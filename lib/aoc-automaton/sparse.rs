@@ -0,0 +1,88 @@
+use hashbrown::{HashMap, HashSet};
+
+use aoc_geometry::Vector2;
+
+/// Live cells on a plane without edges, for automata that keep spreading
+/// out. The sets and the proposal table are kept around between steps and
+/// only ever grow.
+#[derive(Debug, Clone, Default)]
+pub struct Sparse {
+    cells: HashSet<Vector2>,
+    next: HashSet<Vector2>,
+    /// Where cells want to go, `None` once several of them want the same spot
+    proposals: HashMap<Vector2, Option<Vector2>>,
+}
+
+impl Sparse {
+    pub fn new(cells: impl IntoIterator<Item = Vector2>) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn contains(&self, position: &Vector2) -> bool {
+        self.cells.contains(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &Vector2> {
+        self.cells.iter()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle
+    /// holding every cell.
+    pub fn bounds(&self) -> Option<(Vector2, Vector2)> {
+        let mut cells = self.cells.iter();
+        let first = *cells.next()?;
+        Some(cells.fold((first, first), |(min, max), cell| {
+            (
+                Vector2(min.0.min(cell.0), min.1.min(cell.1)),
+                Vector2(max.0.max(cell.0), max.1.max(cell.1)),
+            )
+        }))
+    }
+
+    /// Asks every cell where it wants to go, all of them looking at the
+    /// plane as it was. Cells proposing the same spot as another one stay
+    /// put. Returns how many moved.
+    pub fn step(&mut self, propose: impl Fn(Vector2, &Self) -> Option<Vector2>) -> usize {
+        let mut proposals = std::mem::take(&mut self.proposals);
+        proposals.clear();
+        for cell in self.cells.iter() {
+            if let Some(target) = propose(*cell, self) {
+                proposals
+                    .entry(target)
+                    .and_modify(|origin| *origin = None)
+                    .or_insert(Some(*cell));
+            }
+        }
+
+        self.next.clear();
+        self.next.extend(self.cells.iter());
+        let moves = || {
+            proposals
+                .iter()
+                .filter_map(|(target, origin)| origin.map(|origin| (origin, *target)))
+        };
+        for (origin, _) in moves() {
+            self.next.remove(&origin);
+        }
+        let mut moved = 0;
+        for (_, target) in moves() {
+            self.next.insert(target);
+            moved += 1;
+        }
+
+        std::mem::swap(&mut self.cells, &mut self.next);
+        self.proposals = proposals;
+        moved
+    }
+}
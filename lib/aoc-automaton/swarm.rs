@@ -0,0 +1,63 @@
+use aoc_geometry::{Grid, Vector2};
+
+/// Agents each moving at their own fixed velocity across a board wrapping
+/// around at the edges, along with how many of them are on every tile.
+/// Agents pass through each other, so any number of steps can be taken at
+/// once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Swarm {
+    size: Vector2,
+    positions: Vec<Vector2>,
+    velocities: Vec<Vector2>,
+    counts: Grid<u16>,
+}
+
+impl Swarm {
+    /// `agents` as position and velocity pairs.
+    pub fn new(size: Vector2, agents: impl IntoIterator<Item = (Vector2, Vector2)>) -> Self {
+        let mut swarm = Self {
+            size,
+            positions: Vec::new(),
+            velocities: Vec::new(),
+            counts: Grid::new(size.0 as usize, size.1 as usize, 0),
+        };
+        for (position, velocity) in agents {
+            let position = swarm.wrap(position);
+            swarm.counts[position] += 1;
+            swarm.positions.push(position);
+            swarm.velocities.push(velocity);
+        }
+        swarm
+    }
+
+    fn wrap(&self, position: Vector2) -> Vector2 {
+        Vector2(
+            position.0.rem_euclid(self.size.0),
+            position.1.rem_euclid(self.size.1),
+        )
+    }
+
+    pub fn positions(&self) -> &[Vector2] {
+        &self.positions
+    }
+
+    /// Agents on every tile.
+    pub fn counts(&self) -> &Grid<u16> {
+        &self.counts
+    }
+
+    pub fn step(&mut self) {
+        self.advance(1);
+    }
+
+    /// Moves every agent `steps` steps, backwards for negative ones.
+    pub fn advance(&mut self, steps: isize) {
+        for index in 0..self.positions.len() {
+            let position = self.positions[index];
+            self.counts[position] -= 1;
+            let position = self.wrap(position + self.velocities[index] * steps);
+            self.counts[position] += 1;
+            self.positions[index] = position;
+        }
+    }
+}
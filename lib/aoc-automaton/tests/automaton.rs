@@ -0,0 +1,117 @@
+use aoc_automaton::{Line, LineRule, Neighbours, Plane, Sparse, Swarm};
+use aoc_geometry::{Grid, Vector2, EAST};
+
+/// Alive when exactly one of the cells right next to it is.
+fn xor_rule() -> LineRule {
+    (0..32)
+        .map(|index: usize| {
+            let neighbourhood: [bool; 5] = std::array::from_fn(|bit| index >> (4 - bit) & 1 == 1);
+            (neighbourhood, neighbourhood[1] != neighbourhood[3])
+        })
+        .collect()
+}
+
+#[test]
+fn lines_grow_both_ways() {
+    let mut line = Line::new(xor_rule(), [0]);
+
+    line.step();
+    assert_eq!(line.alive().collect::<Vec<_>>(), [-1, 1]);
+    line.step();
+    assert_eq!(line.alive().collect::<Vec<_>>(), [-2, 2]);
+    for _ in 2..200 {
+        line.step();
+    }
+    assert_eq!(line.bounds(), Some((-200, 200)));
+    assert!(!line.get(0));
+}
+
+#[test]
+fn gliders_keep_their_pattern_while_moving_far() {
+    let mut rule = LineRule::default();
+    for index in 0..32usize {
+        let neighbourhood: [bool; 5] = std::array::from_fn(|bit| index >> (4 - bit) & 1 == 1);
+        rule.set(neighbourhood, neighbourhood[1]);
+    }
+    let mut line = Line::new(rule, [3, 4, 6]);
+
+    for _ in 0..1000 {
+        line.step();
+    }
+    assert_eq!(line.alive().collect::<Vec<_>>(), [1003, 1004, 1006]);
+    assert_eq!(line.pattern(), [0, 1, 3]);
+    assert_eq!(line.count(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Rule brings empty cells to life")]
+fn lines_reject_rules_filling_everything() {
+    let rule: LineRule = [([false; 5], true)].into_iter().collect();
+    Line::new(rule, [0]);
+}
+
+#[test]
+fn blinkers_blink() {
+    let grid = Grid::from_str(".....\n..#..\n..#..\n..#..\n.....", |c| c == '#');
+    let mut plane = Plane::new(grid.clone());
+    let life = |alive: &bool, neighbours: Neighbours<bool>| {
+        matches!(
+            (alive, neighbours.count(|alive| *alive)),
+            (true, 2) | (_, 3)
+        )
+    };
+
+    plane.step(life);
+    let horizontal = Grid::from_str(".....\n.....\n.###.\n.....\n.....", |c| c == '#');
+    assert_eq!(plane.grid(), &horizontal);
+    plane.step(life);
+    assert_eq!(plane.into_grid(), grid);
+}
+
+#[test]
+fn contested_spots_stay_empty() {
+    let mut sparse = Sparse::new([Vector2(0, 0), Vector2(2, 0), Vector2(5, 5)]);
+
+    let moved = sparse.step(|cell, sparse| {
+        let target = if cell.1 == 0 {
+            Vector2(1, 0)
+        } else {
+            cell + EAST
+        };
+        (!sparse.contains(&target)).then_some(target)
+    });
+
+    assert_eq!(moved, 1);
+    assert_eq!(sparse.len(), 3);
+    assert!(sparse.contains(&Vector2(6, 5)));
+    assert!(!sparse.contains(&Vector2(1, 0)));
+    assert_eq!(sparse.bounds(), Some((Vector2(0, 0), Vector2(6, 5))));
+}
+
+#[test]
+fn swarms_wrap_and_skip_ahead() {
+    let agents = [
+        (Vector2(0, 0), Vector2(1, 1)),
+        (Vector2(2, 1), Vector2(-1, 3)),
+    ];
+    let mut stepped = Swarm::new(Vector2(3, 4), agents);
+    let mut skipped = stepped.clone();
+
+    for _ in 0..7 {
+        stepped.step();
+    }
+    skipped.advance(7);
+
+    assert_eq!(stepped, skipped);
+    assert_eq!(stepped.positions(), [Vector2(1, 3), Vector2(1, 2)]);
+    assert_eq!(
+        stepped
+            .counts()
+            .tiles()
+            .map(|count| *count as usize)
+            .sum::<usize>(),
+        2
+    );
+    skipped.advance(-7);
+    assert_eq!(skipped.counts()[Vector2(2, 1)], 1);
+}
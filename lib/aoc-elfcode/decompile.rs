@@ -0,0 +1,604 @@
+use std::{collections::HashSet, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compare {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Compare {
+    pub fn negate(self) -> Self {
+        match self {
+            Compare::Eq => Compare::Ne,
+            Compare::Ne => Compare::Eq,
+            Compare::Lt => Compare::Ge,
+            Compare::Le => Compare::Gt,
+            Compare::Gt => Compare::Le,
+            Compare::Ge => Compare::Lt,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Compare::Eq => "==",
+            Compare::Ne => "!=",
+            Compare::Lt => "<",
+            Compare::Le => "<=",
+            Compare::Gt => ">",
+            Compare::Ge => ">=",
+        }
+    }
+}
+
+/// What a branch tests, both sides already rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub left: String,
+    pub compare: Compare,
+    pub right: String,
+}
+
+impl Condition {
+    pub fn new(left: impl Into<String>, compare: Compare, right: impl Into<String>) -> Self {
+        Self {
+            left: left.into(),
+            compare,
+            right: right.into(),
+        }
+    }
+
+    pub fn negate(&self) -> Self {
+        Self::new(&self.left, self.compare.negate(), &self.right)
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.left, self.compare.symbol(), self.right)
+    }
+}
+
+/// Where control goes after a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Flow {
+    /// On to the following line.
+    Next,
+    Jump(usize),
+    /// To `target` when `condition` holds, on to the following line otherwise.
+    Branch {
+        condition: Condition,
+        target: usize,
+    },
+    Halt,
+    /// A jump computed at runtime.
+    Unknown,
+}
+
+/// One instruction lowered for the decompiler. Addresses that aren't the
+/// address of a line count as leaving the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub address: usize,
+    pub statement: Option<String>,
+    pub flow: Flow,
+}
+
+impl Line {
+    pub fn new(address: usize, statement: Option<String>, flow: Flow) -> Self {
+        Self {
+            address,
+            statement,
+            flow,
+        }
+    }
+
+    /// Does nothing but jump, so edges pass straight through it.
+    fn is_jump_only(&self) -> bool {
+        self.statement.is_none() && matches!(self.flow, Flow::Jump(_))
+    }
+}
+
+/// How a [`Block`] ends, targets being block indexes and `None` leaving the
+/// program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum End {
+    Goto(Option<usize>),
+    Branch {
+        condition: Condition,
+        taken: Option<usize>,
+        not_taken: Option<usize>,
+    },
+    Unknown,
+}
+
+impl End {
+    pub fn successors(&self) -> Vec<Option<usize>> {
+        match self {
+            End::Goto(target) => vec![*target],
+            End::Branch {
+                taken, not_taken, ..
+            } => vec![*taken, *not_taken],
+            End::Unknown => vec![],
+        }
+    }
+}
+
+/// Straight-line run of statements, only entered at the top.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    pub address: usize,
+    pub statements: Vec<String>,
+    pub end: End,
+}
+
+/// A lowered program, turned into basic blocks and from there into
+/// structured pseudo-code or a control flow graph.
+#[derive(Debug, Clone)]
+pub struct Listing {
+    lines: Vec<Line>,
+    blocks: Vec<Block>,
+    entry: Option<usize>,
+}
+
+impl Listing {
+    /// `lines` in increasing address order, execution starting at the first.
+    pub fn new(lines: Vec<Line>) -> Self {
+        let (blocks, entry) = build_blocks(&lines);
+        Self {
+            lines,
+            blocks,
+            entry,
+        }
+    }
+
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// Blocks in address order, jumps that do nothing else folded into the
+    /// edges. Code only reachable through computed jumps is kept.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Block execution starts in, `None` for an empty program.
+    pub fn entry(&self) -> Option<usize> {
+        self.entry
+    }
+
+    /// Statements nested in `loop`, `while` and `if` as far as the jumps
+    /// allow, `goto` taking care of the rest.
+    pub fn to_pseudo_code(&self) -> String {
+        // The first pass finds out which blocks need a label
+        let mut writer = Writer::new(&self.blocks, HashSet::new());
+        writer.write(self.entry);
+        let mut writer = Writer::new(&self.blocks, writer.goto_targets);
+        writer.write(self.entry);
+        writer.out
+    }
+
+    /// Control flow graph in graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        let escape = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+        let node = |target: Option<usize>| match target {
+            Some(block) => self.blocks[block].address.to_string(),
+            None => "exit".to_string(),
+        };
+
+        let mut dot = String::from("digraph {\n    node [shape=box fontname=\"monospace\"]\n");
+        let mut exits = false;
+        for block in &self.blocks {
+            let mut label = format!("{}:\\l", block.address);
+            for statement in &block.statements {
+                label += &format!("{}\\l", escape(statement));
+            }
+            dot += &format!("    {} [label=\"{label}\"]\n", block.address);
+
+            let edges = match &block.end {
+                End::Goto(target) => vec![(*target, None)],
+                End::Branch {
+                    condition,
+                    taken,
+                    not_taken,
+                } => vec![
+                    (*taken, Some(condition.to_string())),
+                    (*not_taken, Some(condition.negate().to_string())),
+                ],
+                End::Unknown => {
+                    dot += &format!("    {} -> unknown [style=dashed]\n", block.address);
+                    vec![]
+                }
+            };
+            for (target, label) in edges {
+                exits |= target.is_none();
+                dot += &format!("    {} -> {}", block.address, node(target));
+                if let Some(label) = label {
+                    dot += &format!(" [label=\"{}\"]", escape(&label));
+                }
+                dot += "\n";
+            }
+        }
+        if exits {
+            dot += "    exit [shape=oval]\n";
+        }
+        if self.blocks.iter().any(|block| block.end == End::Unknown) {
+            dot += "    unknown [shape=oval label=\"?\"]\n";
+        }
+        dot += "}\n";
+        dot
+    }
+}
+
+fn build_blocks(lines: &[Line]) -> (Vec<Block>, Option<usize>) {
+    let index_of = |address: usize| {
+        lines
+            .binary_search_by_key(&address, |line| line.address)
+            .ok()
+    };
+    // Skips over jump only lines, stopping if they go around in a circle
+    let resolve = |mut index: Option<usize>| {
+        let mut seen = HashSet::new();
+        while let Some(line) = index.map(|index| &lines[index]) {
+            let Flow::Jump(target) = line.flow else { break };
+            if !line.is_jump_only() || !seen.insert(line.address) {
+                break;
+            }
+            index = index_of(target);
+        }
+        index
+    };
+    let next = |index: usize| resolve((index + 1 < lines.len()).then_some(index + 1));
+    let successors = |index: usize| match &lines[index].flow {
+        Flow::Next => vec![next(index)],
+        Flow::Jump(target) => vec![resolve(index_of(*target))],
+        Flow::Branch { target, .. } => vec![resolve(index_of(*target)), next(index)],
+        Flow::Halt | Flow::Unknown => vec![],
+    };
+
+    // Jump only lines are left out, unless they jump around in a circle
+    let included: Vec<bool> = (0..lines.len())
+        .map(|index| resolve(Some(index)) == Some(index))
+        .collect();
+    let entry = resolve((!lines.is_empty()).then_some(0));
+    let mut jumped_to = vec![false; lines.len()];
+    if let Some(entry) = entry {
+        jumped_to[entry] = true;
+    }
+    for index in (0..lines.len()).filter(|&index| included[index]) {
+        let falls_through = matches!(lines[index].flow, Flow::Next | Flow::Branch { .. });
+        for successor in successors(index).into_iter().flatten() {
+            if !(falls_through && successor == index + 1) {
+                jumped_to[successor] = true;
+            }
+        }
+    }
+
+    let mut starts = Vec::new();
+    let mut block_of = vec![None; lines.len()];
+    for index in (0..lines.len()).filter(|&index| included[index]) {
+        let continues = index > 0
+            && !jumped_to[index]
+            && block_of[index - 1].is_some()
+            && lines[index - 1].flow == Flow::Next;
+        if !continues {
+            starts.push(index);
+        }
+        block_of[index] = Some(starts.len() - 1);
+    }
+
+    let blocks = starts
+        .iter()
+        .enumerate()
+        .map(|(block, &start)| {
+            let end = (start..lines.len())
+                .take_while(|&index| block_of[index] == Some(block))
+                .last()
+                .unwrap();
+            let to_block = |index: Option<usize>| index.and_then(|index| block_of[index]);
+            let end_line = &lines[end];
+            Block {
+                address: lines[start].address,
+                statements: lines[start..=end]
+                    .iter()
+                    .filter_map(|line| line.statement.clone())
+                    .collect(),
+                end: match &end_line.flow {
+                    Flow::Next => End::Goto(to_block(next(end))),
+                    Flow::Jump(target) => End::Goto(to_block(resolve(index_of(*target)))),
+                    Flow::Branch { condition, target } => End::Branch {
+                        condition: condition.clone(),
+                        taken: to_block(resolve(index_of(*target))),
+                        not_taken: to_block(next(end)),
+                    },
+                    Flow::Halt => End::Goto(None),
+                    Flow::Unknown => End::Unknown,
+                },
+            }
+        })
+        .collect();
+
+    (blocks, entry.and_then(|entry| block_of[entry]))
+}
+
+struct Loop {
+    header: usize,
+    /// Where leaving the loop goes, `None` when nothing does
+    exit: Option<Option<usize>>,
+}
+
+struct Writer<'a> {
+    blocks: &'a [Block],
+    /// Last block jumping back to each loop header
+    latches: Vec<Option<usize>>,
+    loops: Vec<Loop>,
+    written: Vec<bool>,
+    labels: HashSet<usize>,
+    goto_targets: HashSet<usize>,
+    out: String,
+}
+
+impl<'a> Writer<'a> {
+    fn new(blocks: &'a [Block], labels: HashSet<usize>) -> Self {
+        let mut latches = vec![None; blocks.len()];
+        for (block, contents) in blocks.iter().enumerate() {
+            for header in contents.end.successors().into_iter().flatten() {
+                if header <= block {
+                    latches[header] = Some(block);
+                }
+            }
+        }
+
+        Self {
+            blocks,
+            latches,
+            loops: Vec::new(),
+            written: vec![false; blocks.len()],
+            labels,
+            goto_targets: HashSet::new(),
+            out: String::new(),
+        }
+    }
+
+    fn write(&mut self, entry: Option<usize>) {
+        if entry.is_some_and(|entry| entry > 0) {
+            self.jump(entry, None, false, 0);
+        }
+        self.write_range(0, self.blocks.len(), None, 0);
+        // Blocks only reachable by goto, or not at all
+        while let Some(block) = self.written.iter().position(|&written| !written) {
+            self.goto_targets.insert(block);
+            self.write_range(block, self.blocks.len(), None, 0);
+        }
+    }
+
+    fn line(&mut self, indent: usize, text: &str) {
+        self.out += &format!("{}{text}\n", "    ".repeat(indent));
+    }
+
+    /// Writes blocks `from..until`, `follow` being where control goes after.
+    fn write_range(&mut self, from: usize, until: usize, follow: Option<usize>, indent: usize) {
+        let mut block = from;
+        while block < until {
+            if self.written[block] {
+                block += 1;
+                continue;
+            }
+            if let Some(latch) = self.latches[block] {
+                if self.loops.iter().all(|open| open.header != block) {
+                    block = self.write_loop(block, latch, until, follow, indent);
+                    continue;
+                }
+            }
+
+            self.written[block] = true;
+            // Loop headers get theirs in front of the loop
+            let header = self.loops.iter().any(|open| open.header == block);
+            if self.labels.contains(&block) && !header {
+                let label = format!("// {}:", self.blocks[block].address);
+                self.line(indent, &label);
+            }
+            for statement in &self.blocks[block].statements {
+                self.line(indent, &format!("{statement};"));
+            }
+
+            let next = block + 1;
+            let at_end = next >= until;
+            match &self.blocks[block].end {
+                End::Goto(target) => {
+                    if *target != Some(next) || at_end {
+                        self.jump(*target, follow, at_end, indent);
+                    }
+                    block = next;
+                }
+                End::Branch {
+                    condition,
+                    taken,
+                    not_taken,
+                } => {
+                    // Jumping to `until` only stays in the region when it ends there
+                    let forward = |target: Option<usize>| {
+                        target.filter(|&target| {
+                            target > next && (target < until || Some(target) == follow)
+                        })
+                    };
+                    // Going there needs no jump of its own
+                    let continues = |target: Option<usize>| {
+                        (at_end && target == follow)
+                            || (target == Some(next) && (!at_end || follow == Some(next)))
+                    };
+                    block = if *not_taken == Some(next) && forward(*taken).is_some() {
+                        self.write_if(
+                            &condition.negate(),
+                            next,
+                            taken.unwrap(),
+                            until,
+                            follow,
+                            indent,
+                        )
+                    } else if *taken == Some(next) && forward(*not_taken).is_some() {
+                        self.write_if(condition, next, not_taken.unwrap(), until, follow, indent)
+                    } else if continues(*taken) {
+                        self.write_jump_if(&condition.negate(), *not_taken, follow, indent);
+                        next
+                    } else if continues(*not_taken) {
+                        self.write_jump_if(condition, *taken, follow, indent);
+                        next
+                    } else {
+                        self.write_jump_if(condition, *taken, follow, indent);
+                        self.jump(*not_taken, follow, at_end, indent);
+                        next
+                    };
+                }
+                End::Unknown => {
+                    // Whatever comes next is only reached by the computed jump
+                    if next < self.blocks.len() {
+                        self.goto_targets.insert(next);
+                    }
+                    block = next;
+                }
+            }
+        }
+    }
+
+    /// `if condition { from..until }`, with an `else` when the end of that
+    /// jumps over a following region. Returns the block after both.
+    fn write_if(
+        &mut self,
+        condition: &Condition,
+        from: usize,
+        until: usize,
+        outer_until: usize,
+        outer_follow: Option<usize>,
+        indent: usize,
+    ) -> usize {
+        let skip = match self.blocks[until - 1].end {
+            End::Goto(Some(join))
+                if join > until && (join < outer_until || Some(join) == outer_follow) =>
+            {
+                Some(join)
+            }
+            _ => None,
+        };
+
+        self.line(indent, &format!("if {condition} {{"));
+        match skip {
+            Some(join) => {
+                self.write_range(from, until, Some(join), indent + 1);
+                self.line(indent, "} else {");
+                self.write_range(until, join, Some(join), indent + 1);
+                self.line(indent, "}");
+                join
+            }
+            None => {
+                self.write_range(from, until, Some(until), indent + 1);
+                self.line(indent, "}");
+                until
+            }
+        }
+    }
+
+    fn write_jump_if(
+        &mut self,
+        condition: &Condition,
+        target: Option<usize>,
+        follow: Option<usize>,
+        indent: usize,
+    ) {
+        self.line(indent, &format!("if {condition} {{"));
+        self.jump(target, follow, false, indent + 1);
+        self.line(indent, "}");
+    }
+
+    fn jump(&mut self, target: Option<usize>, follow: Option<usize>, at_end: bool, indent: usize) {
+        if at_end && target == follow {
+            return;
+        }
+        let text = match self.loops.last() {
+            Some(open) if target == Some(open.header) => "continue;".to_string(),
+            Some(open) if open.exit == Some(target) => "break;".to_string(),
+            _ => match target {
+                None => "return;".to_string(),
+                Some(block) => {
+                    self.goto_targets.insert(block);
+                    format!("goto {};", self.blocks[block].address)
+                }
+            },
+        };
+        self.line(indent, &text);
+    }
+
+    fn write_loop(
+        &mut self,
+        header: usize,
+        latch: usize,
+        until: usize,
+        follow: Option<usize>,
+        indent: usize,
+    ) -> usize {
+        let body = header..=latch;
+        let mut exits: Vec<Option<usize>> = body
+            .clone()
+            .flat_map(|block| self.blocks[block].end.successors())
+            .filter(|target| !target.is_some_and(|target| body.contains(&target)))
+            .collect();
+        // Straight on after the loop when possible
+        exits.sort_by_key(|target| match *target {
+            Some(target) if target > latch => (0, target),
+            None => (1, 0),
+            Some(target) => (2, target),
+        });
+        let exit = exits.first().copied();
+        self.loops.push(Loop { header, exit });
+
+        let test = match &self.blocks[header] {
+            Block {
+                statements,
+                end:
+                    End::Branch {
+                        condition,
+                        taken,
+                        not_taken,
+                    },
+                ..
+            } if statements.is_empty() && header < latch => {
+                if *taken == Some(header + 1) && exit == Some(*not_taken) {
+                    Some(condition.clone())
+                } else if *not_taken == Some(header + 1) && exit == Some(*taken) {
+                    Some(condition.negate())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        if self.labels.contains(&header) {
+            let label = format!("// {}:", self.blocks[header].address);
+            self.line(indent, &label);
+        }
+        match test {
+            Some(condition) => {
+                self.written[header] = true;
+                self.line(indent, &format!("while {condition} {{"));
+                self.write_range(header + 1, latch + 1, Some(header), indent + 1);
+            }
+            None => {
+                self.line(indent, "loop {");
+                self.write_range(header, latch + 1, Some(header), indent + 1);
+            }
+        }
+        self.line(indent, "}");
+        self.loops.pop();
+
+        let next = latch + 1;
+        if let Some(exit) = exit {
+            if exit != Some(next) {
+                self.jump(exit, follow, next >= until, indent);
+            }
+        }
+        next
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Instruction, OpCode};
+
+/// Registers before and after running one instruction given by number,
+/// `[number, a, b, c]`, with the op code behind the number unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample<const N: usize> {
+    pub before: [isize; N],
+    pub instruction: [isize; 4],
+    pub after: [isize; N],
+}
+
+impl<const N: usize> Sample<N> {
+    pub fn number(&self) -> isize {
+        self.instruction[0]
+    }
+
+    /// Op codes that turn `before` into `after`.
+    pub fn candidates(&self) -> impl Iterator<Item = OpCode> + '_ {
+        let [_, a, b, c] = self.instruction;
+        OpCode::ALL.into_iter().filter(move |&op_code| {
+            let mut registers = self.before;
+            Instruction::new(op_code, a, b, c).execute(&mut registers);
+            registers == self.after
+        })
+    }
+}
+
+fn assign(
+    numbers: &[(isize, Vec<OpCode>)],
+    assigned: &mut HashMap<isize, OpCode>,
+    used: &mut HashSet<OpCode>,
+) -> bool {
+    let Some(((number, candidates), rest)) = numbers.split_first() else {
+        return true;
+    };
+
+    for &op_code in candidates {
+        if !used.insert(op_code) {
+            continue;
+        }
+        assigned.insert(*number, op_code);
+        if assign(rest, assigned, used) {
+            return true;
+        }
+        assigned.remove(number);
+        used.remove(&op_code);
+    }
+    false
+}
+
+/// Works out which op code each sampled number stands for, every sample
+/// having to agree with its number's op code and no two numbers sharing
+/// one. `None` when the samples contradict each other.
+pub fn infer_op_codes<const N: usize>(samples: &[Sample<N>]) -> Option<HashMap<isize, OpCode>> {
+    let mut candidates: HashMap<isize, Vec<OpCode>> = HashMap::new();
+    for sample in samples {
+        let matching: Vec<OpCode> = sample.candidates().collect();
+        candidates
+            .entry(sample.number())
+            .and_modify(|candidates| candidates.retain(|op_code| matching.contains(op_code)))
+            .or_insert(matching);
+    }
+
+    // Numbers with the fewest options first, they settle the rest quickest
+    let mut numbers: Vec<(isize, Vec<OpCode>)> = candidates.into_iter().collect();
+    numbers.sort_by_key(|(number, candidates)| (candidates.len(), *number));
+
+    let mut assigned = HashMap::new();
+    assign(&numbers, &mut assigned, &mut HashSet::new()).then_some(assigned)
+}
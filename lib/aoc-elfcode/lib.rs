@@ -0,0 +1,13 @@
+mod decompile;
+mod infer;
+mod op_code;
+mod profile;
+mod program;
+mod vm;
+
+pub use decompile::{Block, Compare, Condition, End, Flow, Line, Listing};
+pub use infer::{infer_op_codes, Sample};
+pub use op_code::OpCode;
+pub use profile::{HotLoop, Profile};
+pub use program::{Instruction, ParseError, Program};
+pub use vm::{Step, Stop, Vm};
@@ -0,0 +1,141 @@
+use std::{fmt, str::FromStr};
+
+use crate::Compare;
+
+/// One of the sixteen device instructions. The suffix tells how `a` and `b`
+/// are read, `r` for a register and `i` for the value itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OpCode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl OpCode {
+    pub const ALL: [OpCode; 16] = [
+        OpCode::Addr,
+        OpCode::Addi,
+        OpCode::Mulr,
+        OpCode::Muli,
+        OpCode::Banr,
+        OpCode::Bani,
+        OpCode::Borr,
+        OpCode::Bori,
+        OpCode::Setr,
+        OpCode::Seti,
+        OpCode::Gtir,
+        OpCode::Gtri,
+        OpCode::Gtrr,
+        OpCode::Eqir,
+        OpCode::Eqri,
+        OpCode::Eqrr,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            OpCode::Addr => "addr",
+            OpCode::Addi => "addi",
+            OpCode::Mulr => "mulr",
+            OpCode::Muli => "muli",
+            OpCode::Banr => "banr",
+            OpCode::Bani => "bani",
+            OpCode::Borr => "borr",
+            OpCode::Bori => "bori",
+            OpCode::Setr => "setr",
+            OpCode::Seti => "seti",
+            OpCode::Gtir => "gtir",
+            OpCode::Gtri => "gtri",
+            OpCode::Gtrr => "gtrr",
+            OpCode::Eqir => "eqir",
+            OpCode::Eqri => "eqri",
+            OpCode::Eqrr => "eqrr",
+        }
+    }
+
+    /// Whether `a` and `b` name registers rather than values, `b` counting
+    /// as a value for the set instructions that ignore it.
+    pub fn reads_registers(&self) -> (bool, bool) {
+        match self {
+            OpCode::Addr | OpCode::Mulr | OpCode::Banr | OpCode::Borr => (true, true),
+            OpCode::Gtrr | OpCode::Eqrr => (true, true),
+            OpCode::Addi | OpCode::Muli | OpCode::Bani | OpCode::Bori => (true, false),
+            OpCode::Setr | OpCode::Gtri | OpCode::Eqri => (true, false),
+            OpCode::Gtir | OpCode::Eqir => (false, true),
+            OpCode::Seti => (false, false),
+        }
+    }
+
+    /// Operator combining `a` and `b`, `None` for the set instructions.
+    pub(crate) fn operator(&self) -> Option<Operator> {
+        match self {
+            OpCode::Addr | OpCode::Addi => Some(Operator::Arithmetic("+")),
+            OpCode::Mulr | OpCode::Muli => Some(Operator::Arithmetic("*")),
+            OpCode::Banr | OpCode::Bani => Some(Operator::Arithmetic("&")),
+            OpCode::Borr | OpCode::Bori => Some(Operator::Arithmetic("|")),
+            OpCode::Setr | OpCode::Seti => None,
+            OpCode::Gtir | OpCode::Gtri | OpCode::Gtrr => Some(Operator::Compare(Compare::Gt)),
+            OpCode::Eqir | OpCode::Eqri | OpCode::Eqrr => Some(Operator::Compare(Compare::Eq)),
+        }
+    }
+
+    /// Value the instruction stores in register `c`.
+    pub fn run(&self, a: isize, b: isize, registers: &[isize]) -> isize {
+        let register = |index: isize| registers[index as usize];
+        match self {
+            OpCode::Addr => register(a) + register(b),
+            OpCode::Addi => register(a) + b,
+            OpCode::Mulr => register(a) * register(b),
+            OpCode::Muli => register(a) * b,
+            OpCode::Banr => register(a) & register(b),
+            OpCode::Bani => register(a) & b,
+            OpCode::Borr => register(a) | register(b),
+            OpCode::Bori => register(a) | b,
+            OpCode::Setr => register(a),
+            OpCode::Seti => a,
+            OpCode::Gtir => (a > register(b)) as isize,
+            OpCode::Gtri => (register(a) > b) as isize,
+            OpCode::Gtrr => (register(a) > register(b)) as isize,
+            OpCode::Eqir => (a == register(b)) as isize,
+            OpCode::Eqri => (register(a) == b) as isize,
+            OpCode::Eqrr => (register(a) == register(b)) as isize,
+        }
+    }
+}
+
+/// The arithmetic operators all commute, which the decompiler relies on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    Arithmetic(&'static str),
+    /// Stores 1 when the comparison holds and 0 otherwise.
+    Compare(Compare),
+}
+
+impl fmt::Display for OpCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for OpCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OpCode::ALL
+            .into_iter()
+            .find(|op_code| op_code.name() == s)
+            .ok_or_else(|| format!("Unknown op code {s:?}"))
+    }
+}
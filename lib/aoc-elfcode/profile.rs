@@ -0,0 +1,142 @@
+use std::{collections::HashMap, fmt::Write};
+
+/// Iterations in a row a loop has to repeat itself before it counts as hot.
+const MIN_STREAK: usize = 3;
+
+/// A loop taking the same instructions every time around, with a single
+/// register moving by the same amount each time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotLoop {
+    /// Address the loop jumps back to.
+    pub header: usize,
+    /// Addresses one iteration runs through, in order.
+    pub body: Vec<usize>,
+    pub register: usize,
+    pub delta: isize,
+    /// Times control came back to `header`.
+    pub iterations: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Watch {
+    /// Registers the last time around
+    registers: Vec<isize>,
+    body: Vec<usize>,
+    change: Option<(usize, isize)>,
+    streak: usize,
+    iterations: usize,
+    /// Longest streak along with the change and body it had
+    best: Option<(usize, (usize, isize), Vec<usize>)>,
+}
+
+/// Hit counts per instruction, along with loops that repeat themselves,
+/// gathered while a program runs.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    hits: Vec<usize>,
+    /// Addresses run since the last jump back
+    path: Vec<usize>,
+    loops: HashMap<usize, Watch>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts the instruction at `ip`, which left `registers` behind and
+    /// handed control to `next`. Jumping back counts as going around a loop
+    /// starting at `next`.
+    pub fn record(&mut self, ip: usize, registers: &[isize], next: usize) {
+        if self.hits.len() <= ip {
+            self.hits.resize(ip + 1, 0);
+        }
+        self.hits[ip] += 1;
+        self.path.push(ip);
+        if next > ip {
+            return;
+        }
+
+        let path = std::mem::take(&mut self.path);
+        let watch = self.loops.entry(next).or_default();
+        watch.iterations += 1;
+        let mut changed = registers
+            .iter()
+            .zip(&watch.registers)
+            .enumerate()
+            .filter(|(_, (now, before))| now != before)
+            .map(|(register, (now, before))| (register, now - before));
+        let change = changed.next().filter(|_| changed.next().is_none());
+
+        let repeated = change.filter(|&change| Some(change) == watch.change && path == watch.body);
+        if let Some(change) = repeated {
+            watch.streak += 1;
+            if watch
+                .best
+                .as_ref()
+                .is_none_or(|(best, ..)| watch.streak > *best)
+            {
+                watch.best = Some((watch.streak, change, path.clone()));
+            }
+        } else {
+            watch.streak = 0;
+        }
+        watch.change = change;
+        watch.registers = registers.to_vec();
+        watch.body = path;
+    }
+
+    /// Times each address ran.
+    pub fn hits(&self) -> &[usize] {
+        &self.hits
+    }
+
+    /// Loops that went around unchanged but for one register often enough,
+    /// those going around the most first.
+    pub fn hot_loops(&self) -> Vec<HotLoop> {
+        let mut hot_loops: Vec<HotLoop> = self
+            .loops
+            .iter()
+            .filter_map(|(&header, watch)| {
+                let (streak, (register, delta), body) = watch.best.clone()?;
+                (streak >= MIN_STREAK).then_some(HotLoop {
+                    header,
+                    body,
+                    register,
+                    delta,
+                    iterations: watch.iterations,
+                })
+            })
+            .collect();
+        hot_loops.sort_by_key(|hot_loop| (std::cmp::Reverse(hot_loop.iterations), hot_loop.header));
+        hot_loops
+    }
+
+    /// Hit counts and hot loops as a table to read through.
+    pub fn report(&self) -> String {
+        let mut report = String::from("  ip       hits\n");
+        for (ip, hits) in self.hits.iter().enumerate().filter(|(_, &hits)| hits > 0) {
+            writeln!(report, "{ip:>4} {hits:>10}").unwrap();
+        }
+
+        let hot_loops = self.hot_loops();
+        if !hot_loops.is_empty() {
+            report += "\nhot loops\n";
+        }
+        for hot_loop in hot_loops {
+            let body: Vec<String> = hot_loop.body.iter().map(usize::to_string).collect();
+            writeln!(
+                report,
+                "{:>4}: r{} += {} over {} instructions, {} iterations ({})",
+                hot_loop.header,
+                hot_loop.register,
+                hot_loop.delta,
+                hot_loop.body.len(),
+                hot_loop.iterations,
+                body.join(" ")
+            )
+            .unwrap();
+        }
+        report
+    }
+}
@@ -0,0 +1,262 @@
+use std::{fmt, str::FromStr};
+
+use crate::{op_code::Operator, Condition, Flow, Line, Listing, OpCode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub op_code: OpCode,
+    pub a: isize,
+    pub b: isize,
+    pub c: isize,
+}
+
+impl Instruction {
+    pub fn new(op_code: OpCode, a: isize, b: isize, c: isize) -> Self {
+        Self { op_code, a, b, c }
+    }
+
+    /// Stores the result in register `c`.
+    pub fn execute(&self, registers: &mut [isize]) {
+        registers[self.c as usize] = self.op_code.run(self.a, self.b, registers);
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.op_code, self.a, self.b, self.c)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        let [op_code, a, b, c] = parts[..] else {
+            return Err(format!("Expected an op code and three values, got {s:?}"));
+        };
+        let value = |part: &str| {
+            part.parse::<isize>()
+                .map_err(|_| format!("Expected a number, got {part:?}"))
+        };
+
+        Ok(Self::new(op_code.parse()?, value(a)?, value(b)?, value(c)?))
+    }
+}
+
+/// Line of the source that didn't parse, counting from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Instructions along with the register the instruction pointer is bound
+/// to by an `#ip N` line, if any.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub ip: Option<usize>,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn new(ip: Option<usize>, instructions: Vec<Instruction>) -> Self {
+        Self { ip, instructions }
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    pub fn get(&self, ip: usize) -> Option<&Instruction> {
+        self.instructions.get(ip)
+    }
+
+    /// Ready to decompile, with registers named `r0`, `r1` and so on.
+    pub fn listing(&self) -> Listing {
+        let registers = (0..6)
+            .map(|register| format!("r{register}"))
+            .collect::<Vec<_>>();
+        let names: Vec<&str> = registers.iter().map(String::as_str).collect();
+        self.listing_with_names(&names)
+    }
+
+    /// Ready to decompile, register `i` going by `names[i]`. Writes to the
+    /// instruction pointer register become jumps, a comparison right before
+    /// a relative jump on its result becoming the condition of a branch.
+    /// Reading the instruction pointer gives the address of the instruction.
+    pub fn listing_with_names(&self, names: &[&str]) -> Listing {
+        let mut lines: Vec<Line> = self
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(address, instruction)| self.lower(address, instruction, names))
+            .collect();
+
+        for address in 1..self.instructions.len() {
+            let Some(condition) = self.branch_condition(address, names) else {
+                continue;
+            };
+            lines[address - 1].statement = None;
+            lines[address] = Line::new(
+                address,
+                None,
+                Flow::Branch {
+                    condition,
+                    target: address + 2,
+                },
+            );
+        }
+
+        Listing::new(lines)
+    }
+
+    /// `value` as read by the instruction at `address`.
+    fn operand(&self, address: usize, value: isize, is_register: bool, names: &[&str]) -> String {
+        match is_register {
+            true if self.ip == Some(value as usize) => address.to_string(),
+            true => names[value as usize].to_string(),
+            false => value.to_string(),
+        }
+    }
+
+    fn expression(&self, address: usize, instruction: &Instruction, names: &[&str]) -> Expression {
+        let (a_register, b_register) = instruction.op_code.reads_registers();
+        let a = self.operand(address, instruction.a, a_register, names);
+        let b = self.operand(address, instruction.b, b_register, names);
+        match instruction.op_code.operator() {
+            None => Expression::Value(a),
+            Some(Operator::Arithmetic(symbol)) => Expression::Arithmetic(a, symbol, b),
+            Some(Operator::Compare(compare)) => Expression::Compare(Condition::new(a, compare, b)),
+        }
+    }
+
+    /// Condition of a relative jump by the result of the comparison right
+    /// before it.
+    fn branch_condition(&self, address: usize, names: &[&str]) -> Option<Condition> {
+        let ip = self.ip? as isize;
+        let jump = self.instructions[address];
+        let flag = match jump {
+            Instruction {
+                op_code: OpCode::Addr,
+                a,
+                b,
+                c,
+            } if c == ip && (a == ip) != (b == ip) => a + b - ip,
+            _ => return None,
+        };
+
+        let comparison = self.instructions[address - 1];
+        if comparison.c != flag {
+            return None;
+        }
+        match self.expression(address - 1, &comparison, names) {
+            Expression::Compare(condition) => Some(condition),
+            _ => None,
+        }
+    }
+
+    fn lower(&self, address: usize, instruction: &Instruction, names: &[&str]) -> Line {
+        let Instruction { op_code, a, b, c } = *instruction;
+
+        if self.ip == Some(c as usize) {
+            let reads = op_code.reads_registers();
+            let constant = [(a, reads.0), (b, reads.1)]
+                .iter()
+                .all(|&(value, is_register)| !is_register || value == c);
+            if !constant {
+                let target = self.expression(address, instruction, names);
+                return Line::new(address, Some(format!("goto {target} + 1")), Flow::Unknown);
+            }
+
+            let mut registers = vec![0; names.len().max(c as usize + 1)];
+            registers[c as usize] = address as isize;
+            return match usize::try_from(op_code.run(a, b, &registers) + 1) {
+                Ok(target) => Line::new(address, None, Flow::Jump(target)),
+                Err(_) => Line::new(address, None, Flow::Halt),
+            };
+        }
+
+        let target = names[c as usize];
+        let statement = match self.expression(address, instruction, names) {
+            Expression::Arithmetic(left, symbol, right) if left == target => {
+                format!("{target} {symbol}= {right}")
+            }
+            Expression::Arithmetic(left, symbol, right) if right == target => {
+                format!("{target} {symbol}= {left}")
+            }
+            expression => format!("{target} = {expression}"),
+        };
+        Line::new(address, Some(statement), Flow::Next)
+    }
+}
+
+enum Expression {
+    Value(String),
+    Arithmetic(String, &'static str, String),
+    Compare(Condition),
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expression::Value(value) => write!(f, "{value}"),
+            Expression::Arithmetic(left, symbol, right) => write!(f, "{left} {symbol} {right}"),
+            Expression::Compare(condition) => write!(f, "({condition}) as isize"),
+        }
+    }
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ip) = self.ip {
+            writeln!(f, "#ip {ip}")?;
+        }
+        for instruction in &self.instructions {
+            writeln!(f, "{instruction}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut program = Program::default();
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let error = |message| ParseError {
+                line: index + 1,
+                message,
+            };
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(register) = line.strip_prefix("#ip") {
+                let register = register.trim();
+                let register = register
+                    .parse()
+                    .map_err(|_| error(format!("Expected a register, got {register:?}")))?;
+                program.ip = Some(register);
+            } else {
+                program.instructions.push(line.parse().map_err(error)?);
+            }
+        }
+
+        Ok(program)
+    }
+}
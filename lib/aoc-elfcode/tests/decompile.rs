@@ -0,0 +1,221 @@
+use aoc_elfcode::{Block, Compare, Condition, End, Flow, Line, Listing, Program};
+
+/// The hashing loop of 2018 day 21, halting once r5 matches r0.
+const HASH: &str = "#ip 4
+seti 123 0 5
+bani 5 456 5
+eqri 5 72 5
+addr 5 4 4
+seti 0 0 4
+seti 0 0 5
+bori 5 65536 3
+seti 10828530 0 5
+bani 3 255 2
+addr 5 2 5
+bani 5 16777215 5
+muli 5 65899 5
+bani 5 16777215 5
+gtir 256 3 2
+addr 2 4 4
+addi 4 1 4
+seti 27 0 4
+seti 0 7 2
+addi 2 1 1
+muli 1 256 1
+gtrr 1 3 1
+addr 1 4 4
+addi 4 1 4
+seti 25 3 4
+addi 2 1 2
+seti 17 1 4
+setr 2 4 3
+seti 7 3 4
+eqrr 5 0 2
+addr 2 4 4
+seti 5 3 4";
+
+fn statement(address: usize, text: &str) -> Line {
+    Line::new(address, Some(text.to_string()), Flow::Next)
+}
+
+#[test]
+fn nests_loops_and_breaks() {
+    let program: Program = HASH.parse().unwrap();
+
+    assert_eq!(
+        program.listing().to_pseudo_code(),
+        "r5 = 123;
+loop {
+    r5 &= 456;
+    if r5 == 72 {
+        break;
+    }
+}
+r5 = 0;
+loop {
+    r3 = r5 | 65536;
+    r5 = 10828530;
+    loop {
+        r2 = r3 & 255;
+        r5 += r2;
+        r5 &= 16777215;
+        r5 *= 65899;
+        r5 &= 16777215;
+        if 256 > r3 {
+            break;
+        }
+        r2 = 0;
+        loop {
+            r1 = r2 + 1;
+            r1 *= 256;
+            if r1 > r3 {
+                break;
+            }
+            r2 += 1;
+        }
+        r3 = r2;
+    }
+    if r5 == r0 {
+        break;
+    }
+}
+"
+    );
+}
+
+#[test]
+fn names_registers() {
+    let program: Program = "#ip 1\nseti 5 0 0\nmulr 0 1 2\naddi 1 7 1".parse().unwrap();
+    let listing = program.listing_with_names(&["count", "ip", "total"]);
+
+    assert_eq!(listing.to_pseudo_code(), "count = 5;\ntotal = count * 1;\n");
+}
+
+#[test]
+fn folds_jumps_into_edges() {
+    let program: Program = HASH.parse().unwrap();
+    let listing = program.listing();
+    let blocks = listing.blocks();
+    let addresses: Vec<usize> = blocks.iter().map(|block| block.address).collect();
+
+    assert_eq!(addresses, [0, 1, 5, 6, 8, 17, 18, 24, 26, 28]);
+    assert_eq!(
+        blocks[6],
+        Block {
+            address: 18,
+            statements: vec!["r1 = r2 + 1".to_string(), "r1 *= 256".to_string()],
+            end: End::Branch {
+                condition: Condition::new("r1", Compare::Gt, "r3"),
+                taken: Some(8),
+                not_taken: Some(7),
+            },
+        }
+    );
+    assert_eq!(blocks[7].end, End::Goto(Some(6)));
+    assert_eq!(listing.entry(), Some(0));
+}
+
+#[test]
+fn loops_testing_at_the_bottom() {
+    // The 3-bit computer of 2024 day 17, addresses going up by two
+    let listing = Listing::new(vec![
+        statement(0, "RB = RA % 8"),
+        statement(2, "RA >>= 3"),
+        statement(4, "out(RB % 8)"),
+        Line::new(
+            6,
+            None,
+            Flow::Branch {
+                condition: Condition::new("RA", Compare::Ne, "0"),
+                target: 0,
+            },
+        ),
+    ]);
+
+    assert_eq!(
+        listing.to_pseudo_code(),
+        "loop {
+    RB = RA % 8;
+    RA >>= 3;
+    out(RB % 8);
+    if RA == 0 {
+        break;
+    }
+}
+"
+    );
+}
+
+#[test]
+fn while_loops_and_else() {
+    let listing = Listing::new(vec![
+        Line::new(
+            0,
+            None,
+            Flow::Branch {
+                condition: Condition::new("a", Compare::Ge, "10"),
+                target: 6,
+            },
+        ),
+        Line::new(
+            1,
+            None,
+            Flow::Branch {
+                condition: Condition::new("a", Compare::Eq, "b"),
+                target: 4,
+            },
+        ),
+        statement(2, "b += 1"),
+        Line::new(3, None, Flow::Jump(5)),
+        statement(4, "b = 0"),
+        Line::new(5, Some("a += 1".to_string()), Flow::Jump(0)),
+        statement(6, "out(b)"),
+    ]);
+
+    assert_eq!(
+        listing.to_pseudo_code(),
+        "while a < 10 {
+    if a != b {
+        b += 1;
+    } else {
+        b = 0;
+    }
+    a += 1;
+}
+out(b);
+"
+    );
+}
+
+#[test]
+fn labels_code_behind_jumps_it_cant_follow() {
+    let program: Program = "#ip 2\naddi 2 2 2\naddr 2 0 2\nseti 1 0 1\nseti 3 0 1\nseti 1 0 2"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        program.listing().to_pseudo_code(),
+        "goto 3;
+goto 1 + r0 + 1;
+// 2:
+loop {
+    r1 = 1;
+    // 3:
+    r1 = 3;
+}
+"
+    );
+}
+
+#[test]
+fn exports_the_control_flow_graph() {
+    let program: Program = HASH.parse().unwrap();
+    let dot = program.listing().to_dot();
+
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.contains("    18 [label=\"18:\\lr1 = r2 + 1\\lr1 *= 256\\l\"]\n"));
+    assert!(dot.contains("    18 -> 26 [label=\"r1 > r3\"]\n"));
+    assert!(dot.contains("    18 -> 24 [label=\"r1 <= r3\"]\n"));
+    assert!(dot.contains("    28 -> exit [label=\"r5 == r0\"]\n"));
+    assert!(dot.ends_with("    exit [shape=oval]\n}\n"));
+}
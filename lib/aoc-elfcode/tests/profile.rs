@@ -0,0 +1,100 @@
+use aoc_elfcode::{HotLoop, Profile, Program, Stop, Vm};
+
+/// Counts r1 up to 10, then sets r0 to 7.
+const COUNTER: &str = "#ip 5
+seti 0 0 1
+addi 1 1 1
+gtri 1 9 2
+addr 2 5 5
+seti 0 0 5
+seti 7 0 0";
+
+#[test]
+fn counts_hits_per_instruction() {
+    let program: Program = COUNTER.parse().unwrap();
+    let mut vm = Vm::<6>::new(&program).with_profile();
+
+    assert_eq!(vm.run(), Stop::Halted);
+    assert_eq!(vm.profile().unwrap().hits(), [1, 10, 10, 10, 9, 1]);
+}
+
+#[test]
+fn finds_loops_moving_one_register() {
+    let program: Program = COUNTER.parse().unwrap();
+    let mut vm = Vm::<6>::new(&program).with_profile();
+    vm.run();
+
+    assert_eq!(
+        vm.profile().unwrap().hot_loops(),
+        [HotLoop {
+            header: 1,
+            body: vec![1, 2, 3, 4],
+            register: 1,
+            delta: 1,
+            iterations: 9,
+        }]
+    );
+}
+
+#[test]
+fn ignores_loops_going_around_too_few_times() {
+    let mut profile = Profile::new();
+    for r1 in 0..3 {
+        profile.record(0, &[0, r1], 1);
+        profile.record(1, &[0, r1], 0);
+    }
+
+    assert_eq!(profile.hits(), [3, 3]);
+    assert!(profile.hot_loops().is_empty());
+}
+
+#[test]
+fn ignores_loops_moving_several_registers() {
+    let mut profile = Profile::new();
+    for step in 0..10 {
+        profile.record(0, &[step, step], 0);
+    }
+
+    assert!(profile.hot_loops().is_empty());
+}
+
+#[test]
+fn reports_hits_and_hot_loops() {
+    let program: Program = COUNTER.parse().unwrap();
+    let mut vm = Vm::<6>::new(&program).with_profile();
+    vm.run();
+    let report = vm.profile().unwrap().report();
+
+    assert!(report.contains("   1         10\n"));
+    assert!(report.contains("   1: r1 += 1 over 4 instructions, 9 iterations (1 2 3 4)\n"));
+}
+
+#[test]
+fn fast_forwards_skip_loops() {
+    let program: Program = COUNTER.parse().unwrap();
+    let mut vm = Vm::<6>::new(&program);
+    vm.add_fast_forward(1, |registers| {
+        registers[1] = 10;
+        Some(5)
+    });
+
+    assert_eq!(vm.run(), Stop::Halted);
+    assert_eq!(vm.registers()[..2], [7, 10]);
+    assert_eq!(vm.executed(), 2);
+}
+
+#[test]
+fn fast_forwards_can_decline() {
+    let program: Program = COUNTER.parse().unwrap();
+    let mut calls = 0;
+    let mut vm = Vm::<6>::new(&program);
+    vm.add_fast_forward(1, |_| {
+        calls += 1;
+        None
+    });
+
+    assert_eq!(vm.run(), Stop::Halted);
+    assert_eq!(vm.registers()[..2], [7, 10]);
+    drop(vm);
+    assert_eq!(calls, 10);
+}
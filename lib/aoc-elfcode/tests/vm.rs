@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use aoc_elfcode::{infer_op_codes, Instruction, OpCode, Program, Sample, Step, Stop, Vm};
+
+/// The example from 2018 day 19, leaving 6 in register 0.
+const EXAMPLE: &str = "#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5";
+
+#[test]
+fn op_codes_round_trip_through_their_names() {
+    for op_code in OpCode::ALL {
+        assert_eq!(op_code.name().parse(), Ok(op_code));
+    }
+    assert!("nope".parse::<OpCode>().is_err());
+}
+
+#[test]
+fn op_codes_read_registers_or_values() {
+    let registers = [3, 2, 1, 1];
+
+    assert_eq!(OpCode::Mulr.run(2, 1, &registers), 2);
+    assert_eq!(OpCode::Muli.run(2, 1, &registers), 1);
+    assert_eq!(OpCode::Seti.run(2, 1, &registers), 2);
+    assert_eq!(OpCode::Gtir.run(2, 0, &registers), 0);
+    assert_eq!(OpCode::Eqri.run(0, 3, &registers), 1);
+}
+
+#[test]
+fn parses_programs() {
+    let program: Program = EXAMPLE.parse().unwrap();
+
+    assert_eq!(program.ip, Some(0));
+    assert_eq!(program.len(), 7);
+    assert_eq!(
+        program.get(3),
+        Some(&Instruction::new(OpCode::Addr, 1, 2, 3))
+    );
+    assert_eq!(program.to_string().parse(), Ok(program));
+}
+
+#[test]
+fn parse_errors_point_at_the_line() {
+    let error = "#ip 0\nseti 5 0 1\nnope 1 2 3"
+        .parse::<Program>()
+        .unwrap_err();
+    assert_eq!(error.line, 3);
+
+    let error = "#ip x".parse::<Program>().unwrap_err();
+    assert_eq!(error.line, 1);
+
+    let error = "addi 1 2".parse::<Program>().unwrap_err();
+    assert_eq!(error.line, 1);
+}
+
+#[test]
+fn runs_until_halted() {
+    let program: Program = EXAMPLE.parse().unwrap();
+    let mut vm: Vm<6> = Vm::new(&program);
+
+    assert_eq!(vm.run(), Stop::Halted);
+    assert_eq!(vm.registers(), &[6, 5, 6, 0, 0, 9]);
+    assert_eq!(vm.executed(), 5);
+    assert!(!vm.step());
+}
+
+#[test]
+fn runs_without_a_bound_instruction_pointer() {
+    let program: Program = "seti 5 0 0\naddi 0 2 1\nmulr 0 1 2".parse().unwrap();
+    let mut vm: Vm<4> = Vm::new(&program).with_registers([0, 0, 0, 1]);
+
+    assert_eq!(vm.run(), Stop::Halted);
+    assert_eq!(vm.registers(), &[5, 7, 35, 1]);
+}
+
+#[test]
+fn stops_and_resumes_at_breakpoints() {
+    let program: Program = EXAMPLE.parse().unwrap();
+    let mut vm: Vm<6> = Vm::new(&program);
+    vm.add_breakpoint(6);
+
+    assert_eq!(vm.run(), Stop::Breakpoint(6));
+    assert_eq!(vm.ip(), 6);
+    assert_eq!(vm.registers()[5], 0);
+    assert_eq!(vm.run(), Stop::Halted);
+    assert_eq!(vm.registers()[5], 9);
+}
+
+#[test]
+fn stops_at_the_instruction_limit() {
+    let program: Program = "#ip 0\nseti -1 0 0".parse().unwrap();
+    let mut vm: Vm<1> = Vm::new(&program).with_limit(1000);
+
+    assert_eq!(vm.run(), Stop::Limit);
+    assert_eq!(vm.executed(), 1000);
+}
+
+#[test]
+fn traces_executed_instructions() {
+    let program: Program = EXAMPLE.parse().unwrap();
+    let mut vm: Vm<6> = Vm::new(&program).with_trace();
+    vm.run();
+
+    let ips: Vec<usize> = vm.trace().iter().map(|step| step.ip).collect();
+    assert_eq!(ips, [0, 1, 2, 4, 6]);
+    assert_eq!(
+        vm.trace()[3],
+        Step {
+            ip: 4,
+            instruction: Instruction::new(OpCode::Setr, 1, 0, 0),
+            registers: [5, 5, 6, 0, 0, 0],
+        }
+    );
+}
+
+#[test]
+fn sample_candidates() {
+    let sample = Sample {
+        before: [3, 2, 1, 1],
+        instruction: [9, 2, 1, 2],
+        after: [3, 2, 2, 1],
+    };
+
+    let candidates: Vec<OpCode> = sample.candidates().collect();
+    assert_eq!(candidates, [OpCode::Addi, OpCode::Mulr, OpCode::Seti]);
+}
+
+#[test]
+fn infers_op_codes_from_samples() {
+    // Numbers handed out back to front
+    let truth: HashMap<isize, OpCode> = OpCode::ALL
+        .into_iter()
+        .rev()
+        .enumerate()
+        .map(|(number, op_code)| (number as isize, op_code))
+        .collect();
+    let states = [[0, 1, 2, 3], [3, 2, 1, 0], [2, 2, 0, 6], [9, 9, 1, 4]];
+    let mut samples = Vec::new();
+    for (&number, &op_code) in &truth {
+        for before in states {
+            for (a, b) in (0..4).flat_map(|a| (0..4).map(move |b| (a, b))) {
+                let mut after = before;
+                Instruction::new(op_code, a, b, 3).execute(&mut after);
+                samples.push(Sample {
+                    before,
+                    instruction: [number, a, b, 3],
+                    after,
+                });
+            }
+        }
+    }
+
+    assert_eq!(infer_op_codes(&samples), Some(truth));
+}
+
+#[test]
+fn contradicting_samples_infer_nothing() {
+    let samples = [
+        Sample {
+            before: [0, 0, 0],
+            instruction: [0, 1, 0, 0],
+            after: [1, 0, 0],
+        },
+        Sample {
+            before: [0, 0, 0],
+            instruction: [0, 2, 0, 0],
+            after: [0, 0, 0],
+        },
+    ];
+
+    assert_eq!(infer_op_codes(&samples), None);
+}
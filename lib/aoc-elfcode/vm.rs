@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use crate::{Instruction, Profile, Program};
+
+/// Native stand-in for a loop, see [`Vm::add_fast_forward`].
+type FastForward<'a, const N: usize> = Box<dyn FnMut(&mut [isize; N]) -> Option<usize> + 'a>;
+
+/// Why [`Vm::run`] handed control back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction pointer left the program.
+    Halted,
+    /// The instruction at this address is next, not run yet.
+    Breakpoint(usize),
+    /// The instruction limit was used up.
+    Limit,
+}
+
+/// An executed instruction with the registers it left behind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<const N: usize> {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub registers: [isize; N],
+}
+
+/// Runs a [`Program`] on `N` registers, starting out all zero.
+pub struct Vm<'a, const N: usize> {
+    program: &'a Program,
+    registers: [isize; N],
+    ip: usize,
+    executed: usize,
+    breakpoints: HashSet<usize>,
+    limit: Option<usize>,
+    trace: Option<Vec<Step<N>>>,
+    profile: Option<Profile>,
+    /// Indexed by address, checked before every instruction
+    fast_forwards: Vec<Option<FastForward<'a, N>>>,
+}
+
+impl<'a, const N: usize> Vm<'a, N> {
+    pub fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            registers: [0; N],
+            ip: 0,
+            executed: 0,
+            breakpoints: HashSet::new(),
+            limit: None,
+            trace: None,
+            profile: None,
+            fast_forwards: Vec::new(),
+        }
+    }
+
+    pub fn with_registers(mut self, registers: [isize; N]) -> Self {
+        self.registers = registers;
+        self
+    }
+
+    /// Stops [`Vm::run`] once `limit` instructions ran in total.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Records every executed instruction, see [`Vm::trace`].
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(Vec::new());
+        self
+    }
+
+    /// Counts how often each instruction runs and looks out for hot loops,
+    /// see [`Vm::profile`].
+    pub fn with_profile(mut self) -> Self {
+        self.profile = Some(Profile::new());
+        self
+    }
+
+    pub fn program(&self) -> &'a Program {
+        self.program
+    }
+
+    pub fn registers(&self) -> &[isize; N] {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut [isize; N] {
+        &mut self.registers
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn set_ip(&mut self, ip: usize) {
+        self.ip = ip;
+    }
+
+    /// Instructions run so far.
+    pub fn executed(&self) -> usize {
+        self.executed
+    }
+
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    /// Executed instructions in order, empty unless built [`Vm::with_trace`].
+    pub fn trace(&self) -> &[Step<N>] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn profile(&self) -> Option<&Profile> {
+        self.profile.as_ref()
+    }
+
+    /// Hands the registers to `fast_forward` whenever the instruction at `ip`
+    /// is up next. Returning the address to carry on from skips the
+    /// instructions it did the work of, `None` runs the instruction as
+    /// usual.
+    pub fn add_fast_forward(
+        &mut self,
+        ip: usize,
+        fast_forward: impl FnMut(&mut [isize; N]) -> Option<usize> + 'a,
+    ) {
+        if self.fast_forwards.len() <= ip {
+            self.fast_forwards.resize_with(ip + 1, || None);
+        }
+        self.fast_forwards[ip] = Some(Box::new(fast_forward));
+    }
+
+    /// Instruction up next, `None` once halted.
+    pub fn current(&self) -> Option<&'a Instruction> {
+        self.program.get(self.ip)
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.current().is_none()
+    }
+
+    /// Runs the next instruction, or the fast forward standing in for it,
+    /// returning `false` without doing anything when halted. With the
+    /// instruction pointer bound to a register, that register holds the
+    /// address during the instruction and whatever it ends up as picks the
+    /// next one.
+    pub fn step(&mut self) -> bool {
+        let Some(instruction) = self.current() else {
+            return false;
+        };
+        if let Some(Some(fast_forward)) = self.fast_forwards.get_mut(self.ip) {
+            if let Some(next) = fast_forward(&mut self.registers) {
+                self.ip = next;
+                return true;
+            }
+        }
+
+        if let Some(register) = self.program.ip {
+            self.registers[register] = self.ip as isize;
+        }
+        instruction.execute(&mut self.registers);
+        let next = match self.program.ip {
+            Some(register) => self.registers[register] + 1,
+            None => self.ip as isize + 1,
+        };
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(Step {
+                ip: self.ip,
+                instruction: *instruction,
+                registers: self.registers,
+            });
+        }
+        // Jumping before the start leaves the program just like jumping past its end
+        let next = usize::try_from(next).unwrap_or(usize::MAX);
+        if let Some(profile) = &mut self.profile {
+            profile.record(self.ip, &self.registers, next);
+        }
+        self.ip = next;
+        self.executed += 1;
+        true
+    }
+
+    /// Steps until halted, at a breakpoint or out of instructions to spend.
+    /// The breakpoint sitting on the current instruction is skipped, so
+    /// calling this again after a breakpoint carries on.
+    pub fn run(&mut self) -> Stop {
+        let mut resuming = true;
+        loop {
+            if self.is_halted() {
+                return Stop::Halted;
+            }
+            if !resuming && self.breakpoints.contains(&self.ip) {
+                return Stop::Breakpoint(self.ip);
+            }
+            if self.limit.is_some_and(|limit| self.executed >= limit) {
+                return Stop::Limit;
+            }
+            resuming = false;
+            self.step();
+        }
+    }
+}
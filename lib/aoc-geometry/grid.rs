@@ -0,0 +1,325 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Vector2, COMPASS, DIRECTIONS, SOUTH_EAST, SOUTH_WEST};
+
+/// A rectangular grid stored row by row, indexed by `Vector2(x, y)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from row-major cells, panics if they don't fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells can't be split into rows of {width}",
+            cells.len()
+        );
+
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses one tile per char, one row per non-empty line. Lines are
+    /// trimmed first, so whitespace at either end never becomes a tile. Maps
+    /// where leading spaces line up columns need building with
+    /// [`Grid::from_vec`] instead.
+    pub fn from_str(input: &str, mut from_char: impl FnMut(char) -> T) -> Self {
+        Self::from_str_with_position(input, |_, c| from_char(c))
+    }
+
+    /// Like [`Grid::from_str`] but also hands the tile its position.
+    pub fn from_str_with_position(
+        input: &str,
+        mut from_char: impl FnMut(Vector2, char) -> T,
+    ) -> Self {
+        let mut width = 0;
+        let mut cells = Vec::new();
+        for (y, line) in input
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .enumerate()
+        {
+            let row_start = cells.len();
+            cells.extend(
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| from_char(Vector2(x as isize, y as isize), c)),
+            );
+            let row_width = cells.len() - row_start;
+            if y == 0 {
+                width = row_width;
+            }
+            assert_eq!(width, row_width, "Row {y} is not {width} tiles wide");
+        }
+
+        if cells.is_empty() {
+            return Self {
+                width: 0,
+                height: 0,
+                cells,
+            };
+        }
+        Self::from_vec(width, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> Vector2 {
+        Vector2(self.width as isize, self.height as isize)
+    }
+
+    pub fn contains(&self, position: &Vector2) -> bool {
+        position.is_within(&self.size())
+    }
+
+    fn index_of(&self, position: &Vector2) -> Option<usize> {
+        if self.contains(position) {
+            Some(position.1 as usize * self.width + position.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    fn position_of(&self, index: usize) -> Vector2 {
+        Vector2((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    pub fn get(&self, position: &Vector2) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: &Vector2) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| (self.position_of(index), tile))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vector2, &mut T)> {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(index, tile)| {
+            (
+                Vector2((index % width) as isize, (index / width) as isize),
+                tile,
+            )
+        })
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Vector2> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |index| Vector2((index % width) as isize, (index / width) as isize))
+    }
+
+    /// Position of the first tile in reading order matching `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Vector2> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.position_of(index))
+    }
+
+    fn neighbors_in<'a>(
+        &'a self,
+        position: Vector2,
+        directions: &'a [Vector2],
+    ) -> impl Iterator<Item = (Vector2, &'a T)> {
+        directions.iter().filter_map(move |direction| {
+            let neighbor = position + *direction;
+            self.get(&neighbor).map(|tile| (neighbor, tile))
+        })
+    }
+
+    /// The up to four orthogonally adjacent tiles.
+    pub fn neighbors4(&self, position: Vector2) -> impl Iterator<Item = (Vector2, &T)> {
+        self.neighbors_in(position, &DIRECTIONS)
+    }
+
+    /// The up to eight orthogonally and diagonally adjacent tiles.
+    pub fn neighbors8(&self, position: Vector2) -> impl Iterator<Item = (Vector2, &T)> {
+        self.neighbors_in(position, &COMPASS)
+    }
+
+    /// Walks from `start` (inclusive) in `direction` until leaving the grid.
+    pub fn ray(&self, start: Vector2, direction: Vector2) -> impl Iterator<Item = (Vector2, &T)> {
+        std::iter::successors(Some(start), move |position| Some(*position + direction))
+            .map_while(|position| self.get(&position).map(|tile| (position, tile)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The `\` diagonal running down-right through `position`.
+    pub fn diagonal(&self, position: Vector2) -> impl Iterator<Item = &T> {
+        let offset = position.0.min(position.1);
+        self.ray(position - Vector2(offset, offset), SOUTH_EAST)
+            .map(|(_, tile)| tile)
+    }
+
+    /// The `/` diagonal running down-left through `position`.
+    pub fn anti_diagonal(&self, position: Vector2) -> impl Iterator<Item = &T> {
+        let offset = (self.width as isize - 1 - position.0).min(position.1);
+        self.ray(position + Vector2(offset, -offset), SOUTH_WEST)
+            .map(|(_, tile)| tile)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Vector2) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| {
+            Vector2(y as isize, x as isize)
+        })
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, move |x, y| {
+            Vector2(y as isize, (height - 1 - x) as isize)
+        })
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, move |x, y| {
+            Vector2((width - 1 - y) as isize, x as isize)
+        })
+    }
+
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.width, self.height, move |x, y| {
+            Vector2((width - 1 - x) as isize, y as isize)
+        })
+    }
+
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.width, self.height, move |x, y| {
+            Vector2(x as isize, (height - 1 - y) as isize)
+        })
+    }
+}
+
+impl<T> Index<Vector2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vector2) -> &T {
+        self.get(&position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Vector2> for Grid<T> {
+    fn index_mut(&mut self, position: Vector2) -> &mut T {
+        self.get_mut(&position)
+            .unwrap_or_else(|| panic!("{position} is outside of the grid"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+mod grid;
+mod polygon;
+mod vector2;
+mod vector3;
+
+pub use grid::Grid;
+pub use polygon::{CompressedPolygon, Location, Polygon};
+pub use vector2::*;
+pub use vector3::Vector3;
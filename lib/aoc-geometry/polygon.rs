@@ -0,0 +1,198 @@
+use crate::Vector2;
+
+/// Where a point is relative to a [`Polygon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A closed polygon on the integer lattice, the last vertex connecting back
+/// to the first. Vertices in the middle of a straight edge are fine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Vector2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vector2>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Vector2] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Vector2, Vector2)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the area by the shoelace formula, which keeps it a whole number.
+    pub fn doubled_area(&self) -> isize {
+        self.edges()
+            .map(|(from, to)| from.determinant(&to))
+            .sum::<isize>()
+            .abs()
+    }
+
+    /// Lattice points on the edges.
+    pub fn boundary_points(&self) -> isize {
+        self.edges().map(|(from, to)| (to - from).gcd()).sum()
+    }
+
+    /// Lattice points strictly inside, by Pick's theorem.
+    pub fn interior_points(&self) -> isize {
+        (self.doubled_area() - self.boundary_points() + 2) / 2
+    }
+
+    pub fn locate(&self, point: Vector2) -> Location {
+        let mut inside = false;
+        for (from, to) in self.edges() {
+            let edge = to - from;
+            let cross = edge.determinant(&(point - from));
+            if cross == 0
+                && point.0 >= from.0.min(to.0)
+                && point.0 <= from.0.max(to.0)
+                && point.1 >= from.1.min(to.1)
+                && point.1 <= from.1.max(to.1)
+            {
+                return Location::Boundary;
+            }
+            // Edges count from their lower end up to but not including their
+            // upper end, so rays through a vertex cross once, not twice
+            if (from.1 > point.1) != (to.1 > point.1) && (cross > 0) == (edge.1 > 0) {
+                inside = !inside;
+            }
+        }
+
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Inside or on the boundary.
+    pub fn contains(&self, point: Vector2) -> bool {
+        self.locate(point) != Location::Outside
+    }
+
+    /// For answering [`CompressedPolygon::contains_rectangle`], panics unless
+    /// every edge is horizontal or vertical.
+    pub fn compress(&self) -> CompressedPolygon {
+        CompressedPolygon::new(self)
+    }
+}
+
+/// A rectilinear polygon squashed down to one cell per stretch of rows and
+/// columns where nothing changes: one for every vertex coordinate and one for
+/// the gap after it. Cells outside the polygon are summed up so that any
+/// rectangle can be checked at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedPolygon {
+    xs: Vec<isize>,
+    ys: Vec<isize>,
+    /// Outside cells above and left of every cell, one extra row and column
+    outside: Vec<Vec<usize>>,
+}
+
+impl CompressedPolygon {
+    fn new(polygon: &Polygon) -> Self {
+        let compress = |coordinates: Vec<isize>| {
+            let mut compressed: Vec<isize> = coordinates
+                .into_iter()
+                .flat_map(|coordinate| [coordinate, coordinate + 1])
+                .collect();
+            let first = compressed.iter().min().copied().unwrap_or_default();
+            compressed.push(first - 1);
+            compressed.sort_unstable();
+            compressed.dedup();
+            compressed
+        };
+        let xs = compress(polygon.vertices.iter().map(|vertex| vertex.0).collect());
+        let ys = compress(polygon.vertices.iter().map(|vertex| vertex.1).collect());
+        let index = |coordinates: &[isize], coordinate: isize| {
+            Self::index(coordinates, coordinate).expect("Vertices are never below the first cell")
+        };
+
+        let mut cells = vec![vec![Location::Inside; xs.len()]; ys.len()];
+        for (from, to) in polygon.edges() {
+            assert!(
+                from.0 == to.0 || from.1 == to.1,
+                "Edge {from} to {to} is not horizontal or vertical"
+            );
+            let (x0, x1) = (index(&xs, from.0.min(to.0)), index(&xs, from.0.max(to.0)));
+            let (y0, y1) = (index(&ys, from.1.min(to.1)), index(&ys, from.1.max(to.1)));
+            for row in cells[y0..=y1].iter_mut() {
+                row[x0..=x1].fill(Location::Boundary);
+            }
+        }
+
+        // The first row and column are past every vertex, flood the outside
+        // in from there
+        let mut stack = vec![(0, 0)];
+        while let Some((x, y)) = stack.pop() {
+            if cells[y][x] != Location::Inside {
+                continue;
+            }
+            cells[y][x] = Location::Outside;
+            if x > 0 {
+                stack.push((x - 1, y));
+            }
+            if y > 0 {
+                stack.push((x, y - 1));
+            }
+            if x + 1 < xs.len() {
+                stack.push((x + 1, y));
+            }
+            if y + 1 < ys.len() {
+                stack.push((x, y + 1));
+            }
+        }
+
+        let mut outside = vec![vec![0; xs.len() + 1]; ys.len() + 1];
+        for (y, row) in cells.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                outside[y + 1][x + 1] = outside[y][x + 1] + outside[y + 1][x] - outside[y][x]
+                    + (*cell == Location::Outside) as usize;
+            }
+        }
+
+        Self { xs, ys, outside }
+    }
+
+    /// The cell `coordinate` falls in, `None` when it is before the first.
+    fn index(coordinates: &[isize], coordinate: isize) -> Option<usize> {
+        coordinates
+            .partition_point(|other| *other <= coordinate)
+            .checked_sub(1)
+    }
+
+    /// Whether every lattice point of the axis-aligned rectangle with
+    /// opposite corners `a` and `b` is inside or on the boundary.
+    pub fn contains_rectangle(&self, a: Vector2, b: Vector2) -> bool {
+        let (min, max) = (
+            Vector2(a.0.min(b.0), a.1.min(b.1)),
+            Vector2(a.0.max(b.0), a.1.max(b.1)),
+        );
+        let cells = |coordinates: &[isize], low: isize, high: isize| {
+            Some((
+                Self::index(coordinates, low)?,
+                Self::index(coordinates, high)? + 1,
+            ))
+        };
+        let (Some((x0, x1)), Some((y0, y1))) =
+            (cells(&self.xs, min.0, max.0), cells(&self.ys, min.1, max.1))
+        else {
+            // Everything before the first cell is outside
+            return false;
+        };
+
+        let outside = self.outside[y1][x1] + self.outside[y0][x0]
+            - self.outside[y0][x1]
+            - self.outside[y1][x0];
+        outside == 0
+    }
+}
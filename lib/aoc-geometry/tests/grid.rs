@@ -0,0 +1,113 @@
+use aoc_geometry::{Grid, Vector2, EAST};
+
+/// Three wide and two high, so mixing up the axes shows:
+///
+/// ```text
+/// abc
+/// def
+/// ```
+fn letters() -> Grid<char> {
+    Grid::from_str("abc\ndef\n", |c| c)
+}
+
+fn text<'a>(tiles: impl IntoIterator<Item = &'a char>) -> String {
+    tiles.into_iter().collect()
+}
+
+fn rows(grid: &Grid<char>) -> Vec<String> {
+    grid.rows().map(text).collect()
+}
+
+#[test]
+fn from_str_reads_rows_of_trimmed_lines() {
+    let grid = letters();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.size(), Vector2(3, 2));
+    assert_eq!(grid[Vector2(2, 1)], 'f');
+    assert_eq!(grid.get(&Vector2(3, 0)), None);
+    assert_eq!(grid.find(|&tile| tile == 'e'), Some(Vector2(1, 1)));
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    // Whitespace around a line is never a tile and blank lines are skipped
+    assert_eq!(Grid::from_str("  abc \n\n\tdef\n\n", |c| c), grid);
+
+    let positions = Grid::from_str_with_position("abc\ndef", |position, _| position);
+    assert_eq!(positions[Vector2(2, 1)], Vector2(2, 1));
+    assert_eq!(Grid::from_str("", |c| c).size(), Vector2(0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Row 1 is not 3 tiles wide")]
+fn from_str_needs_whole_rows() {
+    Grid::from_str("abc\nde", |c| c);
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = letters();
+
+    assert_eq!(rows(&grid), ["abc", "def"]);
+    assert_eq!(text(grid.row(1)), "def");
+    assert_eq!(
+        grid.columns().map(text).collect::<Vec<_>>(),
+        ["ad", "be", "cf"]
+    );
+    assert_eq!(text(grid.column(2)), "cf");
+}
+
+#[test]
+fn transpose_and_rotate_swap_the_sides() {
+    let grid = letters();
+
+    assert_eq!(rows(&grid.transpose()), ["ad", "be", "cf"]);
+    assert_eq!(rows(&grid.rotate_right()), ["da", "eb", "fc"]);
+    assert_eq!(rows(&grid.rotate_left()), ["cf", "be", "ad"]);
+    assert_eq!(rows(&grid.flip_horizontal()), ["cba", "fed"]);
+    assert_eq!(rows(&grid.flip_vertical()), ["def", "abc"]);
+
+    assert_eq!(grid.transpose().transpose(), grid);
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+    assert_eq!(
+        grid.rotate_right().rotate_right(),
+        grid.flip_horizontal().flip_vertical()
+    );
+    assert_eq!(
+        grid.rotate_right()
+            .rotate_right()
+            .rotate_right()
+            .rotate_right(),
+        grid
+    );
+}
+
+#[test]
+fn diagonals_run_through_the_position() {
+    let grid = letters();
+
+    assert_eq!(text(grid.diagonal(Vector2(2, 1))), "bf");
+    assert_eq!(text(grid.diagonal(Vector2(0, 0))), "ae");
+    assert_eq!(text(grid.diagonal(Vector2(0, 1))), "d");
+    assert_eq!(text(grid.anti_diagonal(Vector2(1, 1))), "ce");
+    assert_eq!(text(grid.anti_diagonal(Vector2(0, 1))), "bd");
+    assert_eq!(text(grid.anti_diagonal(Vector2(0, 0))), "a");
+}
+
+#[test]
+fn neighbors_stay_inside_the_grid() {
+    let grid = letters();
+
+    assert_eq!(
+        grid.neighbors4(Vector2(0, 0)).collect::<Vec<_>>(),
+        [(Vector2(0, 1), &'d'), (Vector2(1, 0), &'b')]
+    );
+    assert_eq!(
+        text(grid.neighbors8(Vector2(1, 0)).map(|(_, tile)| tile)),
+        "cfeda"
+    );
+    assert_eq!(grid.neighbors8(Vector2(1, 1)).count(), 5);
+    assert_eq!(
+        text(grid.ray(Vector2(1, 1), EAST).map(|(_, tile)| tile)),
+        "ef"
+    );
+}
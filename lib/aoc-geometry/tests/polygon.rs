@@ -0,0 +1,84 @@
+use aoc_geometry::{Location, Polygon, Vector2};
+
+/// An L shape:
+///
+/// ```text
+/// #####.
+/// #...#.
+/// #...###
+/// #.....#
+/// #######
+/// ```
+fn l_shape() -> Polygon {
+    Polygon::new(vec![
+        Vector2(0, 0),
+        Vector2(4, 0),
+        Vector2(4, 2),
+        Vector2(6, 2),
+        Vector2(6, 4),
+        Vector2(0, 4),
+    ])
+}
+
+#[test]
+fn shoelace_and_pick_count_lattice_points() {
+    let polygon = l_shape();
+
+    assert_eq!(polygon.doubled_area(), 2 * 20);
+    assert_eq!(polygon.boundary_points(), 20);
+    assert_eq!(polygon.interior_points(), 11);
+
+    let triangle = Polygon::new(vec![Vector2(0, 0), Vector2(4, 0), Vector2(0, 3)]);
+    assert_eq!(triangle.doubled_area(), 12);
+    assert_eq!(triangle.boundary_points(), 8);
+    assert_eq!(triangle.interior_points(), 3);
+}
+
+#[test]
+fn points_on_edges_and_through_vertices_are_located() {
+    let polygon = l_shape();
+
+    assert_eq!(polygon.locate(Vector2(2, 2)), Location::Inside);
+    assert_eq!(polygon.locate(Vector2(5, 3)), Location::Inside);
+    assert_eq!(polygon.locate(Vector2(5, 2)), Location::Boundary);
+    assert_eq!(polygon.locate(Vector2(0, 3)), Location::Boundary);
+    assert_eq!(polygon.locate(Vector2(5, 1)), Location::Outside);
+    // Level with the top edge and the notch, the ray grazes vertices
+    assert_eq!(polygon.locate(Vector2(-1, 0)), Location::Outside);
+    assert_eq!(polygon.locate(Vector2(-1, 2)), Location::Outside);
+    assert_eq!(polygon.locate(Vector2(2, 2)), Location::Inside);
+    assert!(polygon.contains(Vector2(6, 4)));
+
+    let counted = (-1..8)
+        .flat_map(|x| (-1..6).map(move |y| Vector2(x, y)))
+        .filter(|point| polygon.locate(*point) == Location::Inside)
+        .count();
+    assert_eq!(counted as isize, polygon.interior_points());
+}
+
+#[test]
+fn rectangles_have_to_fit_whole() {
+    let polygon = l_shape();
+    let compressed = polygon.compress();
+
+    assert!(compressed.contains_rectangle(Vector2(0, 0), Vector2(4, 4)));
+    assert!(compressed.contains_rectangle(Vector2(6, 2), Vector2(0, 4)));
+    assert!(!compressed.contains_rectangle(Vector2(0, 0), Vector2(5, 4)));
+    assert!(!compressed.contains_rectangle(Vector2(5, 0), Vector2(6, 2)));
+    assert!(!compressed.contains_rectangle(Vector2(-3, 1), Vector2(1, 1)));
+    assert!(!compressed.contains_rectangle(Vector2(1, 1), Vector2(1, 9)));
+
+    for a in (0..7).flat_map(|x| (0..5).map(move |y| Vector2(x, y))) {
+        for b in (0..7).flat_map(|x| (0..5).map(move |y| Vector2(x, y))) {
+            let scanned = (a.0.min(b.0)..=a.0.max(b.0))
+                .all(|x| (a.1.min(b.1)..=a.1.max(b.1)).all(|y| polygon.contains(Vector2(x, y))));
+            assert_eq!(compressed.contains_rectangle(a, b), scanned, "{a} {b}");
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "is not horizontal or vertical")]
+fn compression_needs_a_rectilinear_polygon() {
+    Polygon::new(vec![Vector2(0, 0), Vector2(4, 0), Vector2(0, 3)]).compress();
+}
@@ -0,0 +1,198 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+pub const UP: Vector2 = Vector2(0, -1);
+pub const RIGHT: Vector2 = Vector2(1, 0);
+pub const DOWN: Vector2 = Vector2(0, 1);
+pub const LEFT: Vector2 = Vector2(-1, 0);
+pub const ZERO: Vector2 = Vector2(0, 0);
+pub const DIRECTIONS: [Vector2; 4] = [UP, DOWN, LEFT, RIGHT];
+
+pub const NORTH: Vector2 = UP;
+pub const NORTH_EAST: Vector2 = Vector2(1, -1);
+pub const EAST: Vector2 = RIGHT;
+pub const SOUTH_EAST: Vector2 = Vector2(1, 1);
+pub const SOUTH: Vector2 = DOWN;
+pub const SOUTH_WEST: Vector2 = Vector2(-1, 1);
+pub const WEST: Vector2 = LEFT;
+pub const NORTH_WEST: Vector2 = Vector2(-1, -1);
+pub const DIAGONALS: [Vector2; 4] = [NORTH_EAST, SOUTH_EAST, SOUTH_WEST, NORTH_WEST];
+pub const COMPASS: [Vector2; 8] = [
+    NORTH, NORTH_EAST, EAST, SOUTH_EAST, SOUTH, SOUTH_WEST, WEST, NORTH_WEST,
+];
+
+/// A point or offset on an integer grid where `y` grows downwards.
+///
+/// Ordering is reading order: top to bottom, then left to right.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Vector2(pub isize, pub isize);
+
+impl Vector2 {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self(x, y)
+    }
+
+    pub fn add_scalar(&self, scalar: isize) -> Self {
+        Self(self.0 + scalar, self.1 + scalar)
+    }
+
+    pub fn dot(&self, other: &Self) -> isize {
+        self.0 * other.0 + self.1 * other.1
+    }
+
+    pub fn determinant(&self, other: &Self) -> isize {
+        self.0 * other.1 - self.1 * other.0
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> isize {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> isize {
+        (self.0 - other.0).abs().max((self.1 - other.1).abs())
+    }
+
+    pub fn rot_right_90(&self) -> Self {
+        Self(-self.1, self.0)
+    }
+
+    pub fn rot_left_90(&self) -> Self {
+        Self(self.1, -self.0)
+    }
+
+    pub fn rot_180(&self) -> Self {
+        -*self
+    }
+
+    pub fn gcd(&self) -> isize {
+        let mut a = self.0.abs();
+        let mut b = self.1.abs();
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        a
+    }
+
+    pub fn get_direction(&self, other: &Self) -> Self {
+        *other - *self
+    }
+
+    pub fn normalize(&self) -> Self {
+        match self.gcd() {
+            0 => *self,
+            g => *self / g,
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs(), self.1.abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self(self.0.signum(), self.1.signum())
+    }
+
+    pub fn is_within(&self, size: &Self) -> bool {
+        self.0 >= 0 && self.1 >= 0 && self.0 < size.0 && self.1 < size.1
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Vector2> {
+        let origin = *self;
+        DIRECTIONS.iter().map(move |direction| origin + *direction)
+    }
+}
+
+impl Ord for Vector2 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1).then(self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for Vector2 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Vector2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl SubAssign for Vector2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+/// Component-wise multiplication.
+impl Mul for Vector2 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0, self.1 * other.1)
+    }
+}
+
+impl Mul<isize> for Vector2 {
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self {
+        Self(self.0 * scalar, self.1 * scalar)
+    }
+}
+
+impl MulAssign<isize> for Vector2 {
+    fn mul_assign(&mut self, scalar: isize) {
+        *self = *self * scalar;
+    }
+}
+
+impl Div<isize> for Vector2 {
+    type Output = Self;
+
+    fn div(self, scalar: isize) -> Self {
+        Self(self.0 / scalar, self.1 / scalar)
+    }
+}
+
+impl From<(isize, isize)> for Vector2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self(x, y)
+    }
+}
+
+impl Display for Vector2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.0, self.1)
+    }
+}
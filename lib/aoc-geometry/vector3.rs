@@ -0,0 +1,174 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Vector3(pub isize, pub isize, pub isize);
+
+impl Vector3 {
+    /// The six face-adjacent offsets.
+    pub const FACES: [Vector3; 6] = [
+        Vector3(0, -1, 0),
+        Vector3(0, 1, 0),
+        Vector3(-1, 0, 0),
+        Vector3(1, 0, 0),
+        Vector3(0, 0, 1),
+        Vector3(0, 0, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self(x, y, z)
+    }
+
+    pub const fn fill(value: isize) -> Self {
+        Self(value, value, value)
+    }
+
+    pub fn dot(&self, other: &Self) -> isize {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Self(
+            self.1 * other.2 - self.2 * other.1,
+            self.2 * other.0 - self.0 * other.2,
+            self.0 * other.1 - self.1 * other.0,
+        )
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> isize {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs() + (self.2 - other.2).abs()
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> isize {
+        (self.0 - other.0)
+            .abs()
+            .max((self.1 - other.1).abs())
+            .max((self.2 - other.2).abs())
+    }
+
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs(), self.1.abs(), self.2.abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self(self.0.signum(), self.1.signum(), self.2.signum())
+    }
+
+    pub fn min(&self, other: &Self) -> Self {
+        Self(
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+        )
+    }
+
+    pub fn max(&self, other: &Self) -> Self {
+        Self(
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+        )
+    }
+
+    pub fn neighbors(&self) -> impl Iterator<Item = Vector3> {
+        let origin = *self;
+        Self::FACES.iter().map(move |face| origin + *face)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+impl AddAssign for Vector3 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0, self.1 - other.1, self.2 - other.2)
+    }
+}
+
+impl SubAssign for Vector3 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2)
+    }
+}
+
+impl Mul<isize> for Vector3 {
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self {
+        Self(self.0 * scalar, self.1 * scalar, self.2 * scalar)
+    }
+}
+
+impl MulAssign<isize> for Vector3 {
+    fn mul_assign(&mut self, scalar: isize) {
+        *self = *self * scalar;
+    }
+}
+
+impl Div<isize> for Vector3 {
+    type Output = Self;
+
+    fn div(self, scalar: isize) -> Self {
+        Self(self.0 / scalar, self.1 / scalar, self.2 / scalar)
+    }
+}
+
+impl From<(isize, isize, isize)> for Vector3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Self(x, y, z)
+    }
+}
+
+/// Parses `x,y,z` with optional whitespace around each component.
+impl FromStr for Vector3 {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split(',').map(|component| {
+            component
+                .trim()
+                .parse::<isize>()
+                .map_err(|err| format!("Invalid component {component:?} in {s:?}: {err}"))
+        });
+        let mut next = || {
+            components
+                .next()
+                .unwrap_or_else(|| Err(format!("Missing component in {s:?}")))
+        };
+        let vector = Self(next()?, next()?, next()?);
+        if components.next().is_some() {
+            return Err(format!("Too many components in {s:?}"));
+        }
+        Ok(vector)
+    }
+}
+
+impl Display for Vector3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{},{})", self.0, self.1, self.2)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Graph, Weight};
+
+/// Shortest distance between every pair of nodes with Floyd–Warshall,
+/// indexed as `distances[origin][destination]`. `None` when there is no
+/// route, every node being at distance zero from itself.
+pub fn get_distances<T, W: Weight>(graph: &Graph<T, W>) -> Vec<Vec<Option<W>>> {
+    let mut distances: Vec<Vec<Option<W>>> = vec![vec![None; graph.len()]; graph.len()];
+    for (node, row) in distances.iter_mut().enumerate() {
+        row[node] = Some(W::default());
+    }
+    for edge in graph.edges() {
+        let distance = &mut distances[edge.origin][edge.destination];
+        if distance.is_none_or(|distance| edge.weight < distance) {
+            *distance = Some(edge.weight);
+        }
+    }
+
+    for via in 0..graph.len() {
+        let from_via = distances[via].clone();
+        for row in distances.iter_mut() {
+            let Some(to_via) = row[via] else {
+                continue;
+            };
+            for (distance, from_via) in row.iter_mut().zip(&from_via) {
+                let Some(from_via) = *from_via else {
+                    continue;
+                };
+                let new_distance = to_via + from_via;
+                if distance.is_none_or(|distance| new_distance < distance) {
+                    *distance = Some(new_distance);
+                }
+            }
+        }
+    }
+
+    distances
+}
@@ -0,0 +1,245 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use crate::Weight;
+
+/// States and costs learned by [`get_best_paths`].
+///
+/// Every state keeps all the predecessors that reach it at its cheapest cost,
+/// so ties between routes survive the search.
+#[derive(Debug, Clone)]
+pub struct Search<S, W> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    costs: Vec<W>,
+    previous: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<S, W> Default for Search<S, W> {
+    fn default() -> Self {
+        Self {
+            states: Vec::new(),
+            ids: HashMap::new(),
+            costs: Vec::new(),
+            previous: Vec::new(),
+            goals: Vec::new(),
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash, W: Weight> Search<S, W> {
+    /// Cost of the best path, `None` when no goal was reachable.
+    pub fn cost(&self) -> Option<W> {
+        self.goals.first().map(|&goal| self.costs[goal])
+    }
+
+    /// Goal states reached at the best cost.
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&goal| &self.states[goal])
+    }
+
+    /// Cheapest known cost of `state`. Only states settled before the search
+    /// stopped are guaranteed to be optimal.
+    pub fn get_cost(&self, state: &S) -> Option<W> {
+        self.ids.get(state).map(|&id| self.costs[id])
+    }
+
+    /// One of the best paths, from start to goal.
+    pub fn path(&self) -> Option<Vec<S>> {
+        let mut current = *self.goals.first()?;
+        let mut path = vec![self.states[current].clone()];
+        while let Some(&previous) = self.previous[current].first() {
+            path.push(self.states[previous].clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every path reaching a goal at the best cost, from start to goal.
+    pub fn paths(&self) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        let mut visit: Vec<Vec<usize>> = self.goals.iter().map(|&goal| vec![goal]).collect();
+
+        while let Some(path) = visit.pop() {
+            let current = *path.last().unwrap();
+            if self.previous[current].is_empty() {
+                paths.push(
+                    path.iter()
+                        .rev()
+                        .map(|&id| self.states[id].clone())
+                        .collect(),
+                );
+                continue;
+            }
+
+            for &previous in &self.previous[current] {
+                let mut next = path.clone();
+                next.push(previous);
+                visit.push(next);
+            }
+        }
+        paths
+    }
+
+    /// States lying on any of the best paths, without listing the paths.
+    pub fn on_best_paths(&self) -> impl Iterator<Item = &S> {
+        let mut seen = vec![false; self.states.len()];
+        let mut visit = self.goals.clone();
+        while let Some(current) = visit.pop() {
+            if seen[current] {
+                continue;
+            }
+            seen[current] = true;
+            visit.extend(&self.previous[current]);
+        }
+
+        self.states
+            .iter()
+            .zip(seen)
+            .filter_map(|(state, seen)| seen.then_some(state))
+    }
+
+    fn insert(&mut self, state: S, cost: W) -> usize {
+        let id = self.states.len();
+        self.ids.insert(state.clone(), id);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.previous.push(Vec::new());
+        id
+    }
+}
+
+/// Cheapest path from any of `starts` to a state matching `is_goal`, together
+/// with its cost. The path includes both the start and the goal.
+///
+/// `heuristic` must never overestimate the remaining cost, the Manhattan
+/// distance being the usual pick on a grid with unit steps.
+pub fn get_path<S, W, I, F, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+    heuristic: H,
+    is_goal: G,
+) -> Option<(Vec<S>, W)>
+where
+    S: Clone + Eq + Hash,
+    W: Weight,
+    I: IntoIterator<Item = (S, W)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> W,
+    G: FnMut(&S) -> bool,
+{
+    let search = search(starts, successors, heuristic, is_goal, true);
+    Some((search.path()?, search.cost()?))
+}
+
+/// Keeps searching after the first goal until every path of the same cost
+/// has been found. Step costs must be positive, or the paths could loop.
+pub fn get_best_paths<S, W, I, F, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+    heuristic: H,
+    is_goal: G,
+) -> Search<S, W>
+where
+    S: Clone + Eq + Hash,
+    W: Weight,
+    I: IntoIterator<Item = (S, W)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> W,
+    G: FnMut(&S) -> bool,
+{
+    search(starts, successors, heuristic, is_goal, false)
+}
+
+/// Cheapest cost of every state reachable from `starts`.
+pub fn get_costs<S, W, I, F>(starts: impl IntoIterator<Item = S>, successors: F) -> HashMap<S, W>
+where
+    S: Clone + Eq + Hash,
+    W: Weight,
+    I: IntoIterator<Item = (S, W)>,
+    F: FnMut(&S) -> I,
+{
+    let search = search(starts, successors, |_| W::default(), |_| false, false);
+    search
+        .ids
+        .into_iter()
+        .map(|(state, id)| (state, search.costs[id]))
+        .collect()
+}
+
+fn search<S, W, I, F, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+    first_goal_only: bool,
+) -> Search<S, W>
+where
+    S: Clone + Eq + Hash,
+    W: Weight,
+    I: IntoIterator<Item = (S, W)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> W,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::default();
+    let mut visit = BinaryHeap::new();
+
+    for start in starts {
+        if search.ids.contains_key(&start) {
+            continue;
+        }
+        let estimate = heuristic(&start);
+        let id = search.insert(start, W::default());
+        visit.push(Reverse((estimate, W::default(), id)));
+    }
+
+    while let Some(Reverse((estimate, cost, current))) = visit.pop() {
+        // Stale entry, the state was improved after this one got queued
+        if cost > search.costs[current] {
+            continue;
+        }
+        if search.cost().is_some_and(|best| estimate > best) {
+            break;
+        }
+
+        if is_goal(&search.states[current]) {
+            search.goals.push(current);
+            if first_goal_only {
+                break;
+            }
+            continue;
+        }
+
+        for (next, step) in successors(&search.states[current]) {
+            let next_cost = cost + step;
+            let next_id = match search.ids.get(&next) {
+                Some(&id) => id,
+                None => {
+                    let estimate = next_cost + heuristic(&next);
+                    let id = search.insert(next, next_cost);
+                    search.previous[id].push(current);
+                    visit.push(Reverse((estimate, next_cost, id)));
+                    continue;
+                }
+            };
+
+            if next_cost < search.costs[next_id] {
+                search.costs[next_id] = next_cost;
+                search.previous[next_id] = vec![current];
+                let estimate = next_cost + heuristic(&search.states[next_id]);
+                visit.push(Reverse((estimate, next_cost, next_id)));
+            } else if next_cost == search.costs[next_id]
+                && !search.previous[next_id].contains(&current)
+            {
+                search.previous[next_id].push(current);
+            }
+        }
+    }
+    search
+}
@@ -1,7 +1,11 @@
+//! The graph module 2024/20 and the other Dijkstra days carried before
+//! aoc-graph, cut down to what `dijkstra::get_path` needs. Nodes are looked up
+//! by name and the queue breaks ties by comparing those names.
+
 use hashbrown::HashMap;
 use std::sync::{Arc, Mutex};
 
-type NodeRef<T: NodeValue> = Arc<Mutex<Node<T>>>;
+pub type NodeRef<T> = Arc<Mutex<Node<T>>>;
 
 pub trait NodeValue {
     fn to_name(&self) -> String;
@@ -62,9 +66,7 @@ impl<T: NodeValue> Graph<T> {
     pub fn new(nodes: HashMap<String, NodeRef<T>>, edges: Vec<Edge<T>>) -> Self {
         let mut edge_map = HashMap::new();
         for edge in edges {
-            let entry = edge_map
-                .entry(edge.origin_name.clone())
-                .or_insert(Vec::new());
+            let entry: &mut Vec<Edge<T>> = edge_map.entry(edge.origin_name.clone()).or_default();
             entry.push(edge);
         }
 
@@ -78,8 +80,7 @@ impl<T: NodeValue> Graph<T> {
     where
         F: FnMut(&Edge<T>) -> bool,
     {
-        let neighbors = self
-            .edges
+        self.edges
             .get(node)
             .unwrap()
             .iter()
@@ -90,53 +91,6 @@ impl<T: NodeValue> Graph<T> {
                     Some(edge.destination_name.clone())
                 }
             })
-            .collect();
-
-        neighbors
-    }
-}
-
-pub mod bfs {
-    use std::{collections::VecDeque, sync::Arc};
-
-    use super::{Edge, Graph, NodeRef, NodeValue};
-
-    pub fn get_paths<T, F>(
-        start: &Arc<T>,
-        goal: &Arc<T>,
-        mut is_edge_walkable: F,
-        graph: &Graph<T>,
-    ) -> Vec<Vec<NodeRef<T>>>
-    where
-        T: NodeValue,
-        F: FnMut(&Edge<T>) -> bool,
-    {
-        let mut paths = Vec::new();
-        let mut visit = VecDeque::from([(start.to_name(), Vec::new())]);
-        let goal_name = goal.to_name();
-
-        while let Some((node_name, mut visited)) = visit.pop_back() {
-            visited.push(node_name.clone());
-            if node_name == goal_name {
-                paths.push(visited.clone());
-                continue;
-            }
-
-            for neighbor in graph.get_neighbors(&node_name, &mut is_edge_walkable) {
-                if visited.contains(&neighbor) {
-                    continue;
-                }
-                visit.push_back((neighbor, visited.clone()));
-            }
-        }
-
-        paths
-            .into_iter()
-            .map(|path| {
-                path.into_iter()
-                    .map(|node_name| Arc::clone(graph.nodes.get(&node_name).unwrap()))
-                    .collect()
-            })
             .collect()
     }
 }
@@ -162,7 +116,7 @@ pub mod dijkstra {
 
     impl PartialOrd for DistanceNode {
         fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            Some(self.cmp(&other))
+            Some(self.cmp(other))
         }
     }
 
@@ -174,12 +128,13 @@ pub mod dijkstra {
 
     impl Eq for DistanceNode {}
 
+    /// The path runs from `goal` back to `start`.
     pub fn get_path<T, F>(
         start: &T,
         goal: &T,
         mut is_edge_walkable: F,
         graph: &Graph<T>,
-    ) -> Option<Vec<NodeRef<T>>>
+    ) -> Option<Vec<(NodeRef<T>, usize)>>
     where
         T: NodeValue,
         F: FnMut(&Edge<T>) -> bool,
@@ -195,9 +150,15 @@ pub mod dijkstra {
         while let Some(DistanceNode(current_node, current_distance)) = visit.pop_first() {
             if current_node == goal_name {
                 let mut current_node = goal_name;
-                let mut path = vec![Arc::clone(graph.nodes.get(&current_node).unwrap())];
+                let mut path: Vec<(NodeRef<T>, usize)> = vec![(
+                    Arc::clone(graph.nodes.get(&current_node).unwrap()),
+                    current_distance,
+                )];
                 while let Some(previous_node) = previous.get(&current_node).unwrap() {
-                    path.push(Arc::clone(graph.nodes.get(previous_node).unwrap()));
+                    path.push((
+                        Arc::clone(graph.nodes.get(previous_node).unwrap()),
+                        *distances.get(previous_node).unwrap(),
+                    ));
                     current_node = previous_node.clone();
                 }
 
@@ -0,0 +1,247 @@
+//! Times `dijkstra::get_path` against the string-keyed `BTreeSet` search the
+//! days shipped with before aoc-graph, on the puzzles that run it.
+//!
+//! Run with `cargo bench -p aoc-graph`. A day's `input.txt` is used once it
+//! has been fetched, its `test.txt` until then.
+
+mod before;
+
+use std::{
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_geometry::{Grid, Vector2};
+use aoc_graph::{dijkstra, Edge, Graph};
+use before::{Node, NodeValue};
+use hashbrown::HashMap;
+
+const RUNS: usize = 11;
+
+impl NodeValue for Vector2 {
+    fn to_name(&self) -> String {
+        format!("{},{}", self.0, self.1)
+    }
+}
+
+#[derive(Debug)]
+struct Cell {
+    position: Vector2,
+    tile: char,
+}
+
+impl NodeValue for Cell {
+    fn to_name(&self) -> String {
+        self.position.to_name()
+    }
+}
+
+/// Which steps a day allows, given the tiles on both ends.
+#[derive(Debug, Clone, Copy)]
+enum Rule {
+    /// Anything but walls, 2024/18 and 2024/20.
+    Open,
+    /// One higher at a time, 2024/10.
+    Uphill,
+}
+
+impl Rule {
+    fn allows(self, from: char, to: char) -> bool {
+        match self {
+            Rule::Open => to != '#',
+            Rule::Uphill => to as u8 == from as u8 + 1,
+        }
+    }
+}
+
+/// Searches a day runs on its map, every one of them from `from` to `to`.
+struct Workload {
+    name: String,
+    tiles: Grid<char>,
+    rule: Rule,
+    searches: Vec<(Vector2, Vector2)>,
+}
+
+/// The day's `input.txt`, or its `test.txt` when there is none.
+fn read(day: &str) -> (String, &'static str) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../2024/days")
+        .join(day);
+    match fs::read_to_string(dir.join("input.txt")) {
+        Ok(input) => (input, "input"),
+        Err(_) => (
+            fs::read_to_string(dir.join("test.txt")).expect("Every day has a test.txt"),
+            "test",
+        ),
+    }
+}
+
+/// 2024/20, once from start to end of the race track.
+fn race_track() -> Workload {
+    let (input, kind) = read("20");
+    let tiles = Grid::from_str(&input, |tile| tile);
+    let start = tiles.find(|&tile| tile == 'S').unwrap();
+    let end = tiles.find(|&tile| tile == 'E').unwrap();
+    Workload {
+        name: format!("2024/20 {kind}"),
+        tiles,
+        rule: Rule::Open,
+        searches: vec![(start, end)],
+    }
+}
+
+/// 2024/18, across the memory space once the first bytes have fallen.
+fn memory_space() -> Workload {
+    let (input, kind) = read("18");
+    let (size, fallen) = if kind == "input" { (71, 1024) } else { (7, 12) };
+    let mut rows = vec![vec!['.'; size]; size];
+    for line in input.lines().filter(|line| !line.is_empty()).take(fallen) {
+        let (x, y) = line.split_once(',').unwrap();
+        rows[y.parse::<usize>().unwrap()][x.parse::<usize>().unwrap()] = '#';
+    }
+    let map = rows
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let corner = size as isize - 1;
+    Workload {
+        name: format!("2024/18 {kind}"),
+        tiles: Grid::from_str(&map, |tile| tile),
+        rule: Rule::Open,
+        searches: vec![(Vector2(0, 0), Vector2(corner, corner))],
+    }
+}
+
+/// 2024/10, from every trailhead to every summit.
+fn hiking_trails() -> Workload {
+    let (input, kind) = read("10");
+    let tiles = Grid::from_str(&input, |tile| tile);
+    let heights = |height| -> Vec<Vector2> {
+        tiles
+            .positions()
+            .filter(|&position| tiles[position] == height)
+            .collect()
+    };
+    let summits = heights('9');
+    let searches = heights('0')
+        .into_iter()
+        .flat_map(|trailhead| summits.iter().map(move |&summit| (trailhead, summit)))
+        .collect();
+    Workload {
+        name: format!("2024/10 {kind}"),
+        tiles,
+        rule: Rule::Uphill,
+        searches,
+    }
+}
+
+fn median(mut f: impl FnMut()) -> Duration {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let now = Instant::now();
+            f();
+            now.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[RUNS / 2]
+}
+
+fn main() {
+    println!(
+        "{:<14} {:>9} {:>12} {:>12} {:>8}",
+        "input", "searches", "before", "after", "speedup"
+    );
+    for workload in [race_track(), memory_space(), hiking_trails()] {
+        let Workload {
+            name,
+            tiles,
+            rule,
+            searches,
+        } = workload;
+
+        // Every cell is linked to its neighbours, walls included, the way the
+        // days build their graphs
+        let mut graph = Graph::new();
+        let mut nodes = HashMap::new();
+        for position in tiles.positions() {
+            graph.add_node(position);
+            let tile = tiles[position];
+            nodes.insert(position.to_name(), Node::new_ref(Cell { position, tile }));
+        }
+        let mut edges = Vec::new();
+        for position in tiles.positions() {
+            let node = graph.get_id(&position).unwrap();
+            for (neighbor, _) in tiles.neighbors4(position) {
+                graph.add_edge(node, graph.get_id(&neighbor).unwrap(), 1);
+                edges.push(before::Edge::new(
+                    &nodes[&position.to_name()],
+                    &nodes[&neighbor.to_name()],
+                ));
+            }
+        }
+        let named = before::Graph::new(nodes, edges);
+
+        let searches: Vec<_> = searches
+            .into_iter()
+            .map(|(from, to)| {
+                let ids = (graph.get_id(&from).unwrap(), graph.get_id(&to).unwrap());
+                let cells = (
+                    Cell {
+                        position: from,
+                        tile: tiles[from],
+                    },
+                    Cell {
+                        position: to,
+                        tile: tiles[to],
+                    },
+                );
+                (ids, cells)
+            })
+            .collect();
+
+        let is_walkable = |edge: &Edge| {
+            let from = tiles[*graph.get_node(edge.origin)];
+            rule.allows(from, tiles[*graph.get_node(edge.destination)])
+        };
+        let is_walkable_before = |edge: &before::Edge<Cell>| {
+            let from = edge.get_origin().value.tile;
+            rule.allows(from, edge.get_destination().value.tile)
+        };
+
+        for ((start, goal), (from, to)) in &searches {
+            assert_eq!(
+                before::dijkstra::get_path(from, to, is_walkable_before, &named)
+                    .map(|path| path.len()),
+                dijkstra::get_path(&graph, *start, *goal, is_walkable).map(|path| path.len()),
+                "{name} from {} to {}",
+                from.position,
+                to.position
+            );
+        }
+
+        let before = median(|| {
+            for (_, (from, to)) in &searches {
+                black_box(before::dijkstra::get_path(
+                    from,
+                    to,
+                    is_walkable_before,
+                    &named,
+                ));
+            }
+        });
+        let after = median(|| {
+            for ((start, goal), _) in &searches {
+                black_box(dijkstra::get_path(&graph, *start, *goal, is_walkable));
+            }
+        });
+        println!(
+            "{name:<14} {:>9} {before:>12.2?} {after:>12.2?} {:>7.1}x",
+            searches.len(),
+            before.as_secs_f64() / after.as_secs_f64()
+        );
+    }
+}
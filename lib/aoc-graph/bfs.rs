@@ -0,0 +1,62 @@
+use std::collections::VecDeque;
+
+use crate::{Edge, Graph, NodeId};
+
+/// Every simple path from `start` to `goal` visiting at most `max_path_len`
+/// nodes. Paths include both `start` and `goal`.
+pub fn get_paths<T, W, F>(
+    graph: &Graph<T, W>,
+    start: NodeId,
+    goal: NodeId,
+    max_path_len: usize,
+    mut is_edge_walkable: F,
+) -> Vec<Vec<NodeId>>
+where
+    F: FnMut(&Edge<W>) -> bool,
+{
+    let mut paths = Vec::new();
+    let mut visit = VecDeque::from([(start, Vec::new())]);
+
+    while let Some((node, mut visited)) = visit.pop_back() {
+        visited.push(node);
+        if node == goal {
+            paths.push(visited);
+            continue;
+        }
+
+        if visited.len() >= max_path_len {
+            continue;
+        }
+
+        for neighbor in graph.get_neighbors(node, &mut is_edge_walkable) {
+            if visited.contains(&neighbor) {
+                continue;
+            }
+            visit.push_back((neighbor, visited.clone()));
+        }
+    }
+
+    paths
+}
+
+/// Walks every simple path out of `start` breadth first, calling
+/// `visiting_node` with each node reached and the path that led there.
+pub fn find_all_paths<T, W, F>(graph: &Graph<T, W>, start: NodeId, mut visiting_node: F)
+where
+    F: FnMut(NodeId, &[NodeId]),
+{
+    let mut visit = VecDeque::from([(start, Vec::new())]);
+
+    while let Some((node, mut visited)) = visit.pop_front() {
+        visited.push(node);
+
+        visiting_node(node, &visited);
+
+        for neighbor in graph.neighbors(node) {
+            if visited.contains(&neighbor) {
+                continue;
+            }
+            visit.push_back((neighbor, visited.clone()));
+        }
+    }
+}
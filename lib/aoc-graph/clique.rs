@@ -0,0 +1,206 @@
+use crate::{Graph, NodeId};
+
+/// Fixed size set of node ids, one bit per node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NodeSet(Vec<u64>);
+
+impl NodeSet {
+    fn empty(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    fn full(len: usize) -> Self {
+        let mut set = Self::empty(len);
+        for node in 0..len {
+            set.insert(node);
+        }
+        set
+    }
+
+    fn insert(&mut self, node: NodeId) {
+        self.0[node / 64] |= 1 << (node % 64);
+    }
+
+    fn remove(&mut self, node: NodeId) {
+        self.0[node / 64] &= !(1 << (node % 64));
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        Self(self.0.iter().zip(&other.0).map(|(a, b)| a & b).collect())
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        Self(self.0.iter().zip(&other.0).map(|(a, b)| a & !b).collect())
+    }
+
+    fn intersection_len(&self, other: &Self) -> usize {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Members in ascending order.
+    fn iter(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.0.iter().enumerate().flat_map(|(index, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(index * 64 + bit)
+            })
+        })
+    }
+}
+
+/// Neighbor sets of every node, leaving out self loops. Cliques only make
+/// sense on undirected graphs, so edges are expected to go both ways.
+fn adjacency<T, W>(graph: &Graph<T, W>) -> Vec<NodeSet> {
+    (0..graph.len())
+        .map(|node| {
+            let mut set = NodeSet::empty(graph.len());
+            for neighbor in graph.neighbors(node).filter(|&neighbor| neighbor != node) {
+                set.insert(neighbor);
+            }
+            set
+        })
+        .collect()
+}
+
+/// Bron–Kerbosch, pivoting on the node of `candidates ∪ excluded` that covers
+/// the most candidates so only the rest have to be branched on.
+fn bron_kerbosch(
+    adjacency: &[NodeSet],
+    clique: &mut Vec<NodeId>,
+    mut candidates: NodeSet,
+    mut excluded: NodeSet,
+    found: &mut impl FnMut(&[NodeId]),
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            found(clique);
+        }
+        return;
+    }
+
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .max_by_key(|&node| candidates.intersection_len(&adjacency[node]))
+        .unwrap();
+
+    for node in candidates
+        .difference(&adjacency[pivot])
+        .iter()
+        .collect::<Vec<_>>()
+    {
+        clique.push(node);
+        bron_kerbosch(
+            adjacency,
+            clique,
+            candidates.intersection(&adjacency[node]),
+            excluded.intersection(&adjacency[node]),
+            found,
+        );
+        clique.pop();
+
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+/// Every maximal clique of an undirected graph, each sorted by node id.
+pub fn maximal_cliques<T, W>(graph: &Graph<T, W>) -> Vec<Vec<NodeId>> {
+    let adjacency = adjacency(graph);
+    let mut cliques = Vec::new();
+    bron_kerbosch(
+        &adjacency,
+        &mut Vec::new(),
+        NodeSet::full(graph.len()),
+        NodeSet::empty(graph.len()),
+        &mut |clique: &[NodeId]| {
+            let mut clique = clique.to_vec();
+            clique.sort_unstable();
+            cliques.push(clique);
+        },
+    );
+    cliques
+}
+
+/// The largest clique of an undirected graph, sorted by node id. Ties go to
+/// the clique found first.
+pub fn maximum_clique<T, W>(graph: &Graph<T, W>) -> Vec<NodeId> {
+    let adjacency = adjacency(graph);
+    let mut best = Vec::new();
+    bron_kerbosch(
+        &adjacency,
+        &mut Vec::new(),
+        NodeSet::full(graph.len()),
+        NodeSet::empty(graph.len()),
+        &mut |clique: &[NodeId]| {
+            if clique.len() > best.len() {
+                best = clique.to_vec();
+            }
+        },
+    );
+    best.sort_unstable();
+    best
+}
+
+/// Grows `clique` with candidates in ascending order, so each clique is only
+/// reached through its sorted node ids.
+fn extend_clique(
+    adjacency: &[NodeSet],
+    k: usize,
+    clique: &mut Vec<NodeId>,
+    mut candidates: NodeSet,
+    cliques: &mut Vec<Vec<NodeId>>,
+) {
+    if clique.len() == k {
+        cliques.push(clique.clone());
+        return;
+    }
+
+    for node in candidates.iter().collect::<Vec<_>>() {
+        if clique.len() + candidates.len() < k {
+            return;
+        }
+        candidates.remove(node);
+
+        clique.push(node);
+        extend_clique(
+            adjacency,
+            k,
+            clique,
+            candidates.intersection(&adjacency[node]),
+            cliques,
+        );
+        clique.pop();
+    }
+}
+
+/// Every clique of exactly `k` nodes in an undirected graph, maximal or not.
+/// Cliques are sorted by node id and listed in lexicographic order.
+pub fn k_cliques<T, W>(graph: &Graph<T, W>, k: usize) -> Vec<Vec<NodeId>> {
+    let mut cliques = Vec::new();
+    extend_clique(
+        &adjacency(graph),
+        k,
+        &mut Vec::new(),
+        NodeSet::full(graph.len()),
+        &mut cliques,
+    );
+    cliques
+}
@@ -0,0 +1,50 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{Graph, NodeId, Weight};
+
+/// Graph over the nodes matching `keep`, each edge standing for the
+/// cheapest route between two kept nodes that only passes through dropped
+/// ones. Node values are the ids in the original graph, in their original
+/// order, so corridors of a grid collapse into one weighted edge between
+/// junctions.
+pub fn contract<T, W, F>(graph: &Graph<T, W>, mut keep: F) -> Graph<NodeId, W>
+where
+    W: Weight,
+    F: FnMut(NodeId, &T) -> bool,
+{
+    let kept: Vec<bool> = graph.nodes().map(|(id, value)| keep(id, value)).collect();
+
+    let mut contracted = Graph::new();
+    for (id, _) in graph.nodes().filter(|(id, _)| kept[*id]) {
+        contracted.add_node(id);
+    }
+
+    for (start, _) in graph.nodes().filter(|(id, _)| kept[*id]) {
+        let origin = contracted.get_id(&start).unwrap();
+        let mut distances: Vec<Option<W>> = vec![None; graph.len()];
+        distances[start] = Some(W::default());
+        let mut visit = BinaryHeap::from([Reverse((W::default(), start))]);
+
+        while let Some(Reverse((current_distance, current_node))) = visit.pop() {
+            if distances[current_node].is_some_and(|distance| current_distance > distance) {
+                continue;
+            }
+            // Kept nodes end the route, going further is their own edge
+            if current_node != start && kept[current_node] {
+                let destination = contracted.get_id(&current_node).unwrap();
+                contracted.add_edge(origin, destination, current_distance);
+                continue;
+            }
+
+            for edge in graph.get_edges(current_node) {
+                let new_distance = current_distance + edge.weight;
+                if distances[edge.destination].is_none_or(|distance| new_distance < distance) {
+                    distances[edge.destination] = Some(new_distance);
+                    visit.push(Reverse((new_distance, edge.destination)));
+                }
+            }
+        }
+    }
+
+    contracted
+}
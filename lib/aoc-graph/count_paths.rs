@@ -0,0 +1,65 @@
+use crate::{
+    topo_sort::{cycle_before, kahn, Cycle},
+    Graph, NodeId,
+};
+
+/// Number of distinct paths from `start` to `goal`, counted over a
+/// topological order of the nodes `start` reaches. A cycle on the way to
+/// `goal` makes that number endless, which is returned as the error.
+pub fn count_paths<T, W>(graph: &Graph<T, W>, start: NodeId, goal: NodeId) -> Result<u128, Cycle> {
+    let mut reachable = vec![false; graph.len()];
+    reachable[start] = true;
+    let mut visit = vec![start];
+    while let Some(node) = visit.pop() {
+        for neighbor in graph.neighbors(node) {
+            if !reachable[neighbor] {
+                reachable[neighbor] = true;
+                visit.push(neighbor);
+            }
+        }
+    }
+    if !reachable[goal] {
+        return Ok(0);
+    }
+
+    // Cycles that never lead to goal only hold back nodes that don't either
+    let (topo, sorted) = kahn(graph, &reachable, |node| node);
+    if !sorted[goal] {
+        return Err(cycle_before(graph, &reachable, &sorted, goal));
+    }
+
+    // ways[node] = number of paths start -> node
+    let mut ways = vec![0u128; graph.len()];
+    ways[start] = 1;
+    for node in topo {
+        let count = ways[node];
+        if count == 0 {
+            continue;
+        }
+        for neighbor in graph.neighbors(node) {
+            ways[neighbor] += count;
+        }
+    }
+
+    Ok(ways[goal])
+}
+
+/// Number of paths from `start` to `goal` that pass through `a` and then `b`.
+pub fn count_paths_through_a_then_b<T, W>(
+    graph: &Graph<T, W>,
+    start: NodeId,
+    goal: NodeId,
+    a: NodeId,
+    b: NodeId,
+) -> Result<u128, Cycle> {
+    let start_a = count_paths(graph, start, a)?;
+    if start_a == 0 {
+        return Ok(0);
+    }
+    let a_b = count_paths(graph, a, b)?;
+    if a_b == 0 {
+        return Ok(0);
+    }
+    let b_goal = count_paths(graph, b, goal)?;
+    Ok(start_a.saturating_mul(a_b).saturating_mul(b_goal))
+}
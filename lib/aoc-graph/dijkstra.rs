@@ -0,0 +1,111 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{Edge, Graph, NodeId, Weight};
+
+// Entries are never removed from the heap when a node improves. The outdated
+// ones are skipped when popped, which is cheaper than keeping the heap exact.
+type Visit<W> = BinaryHeap<Reverse<(W, NodeId)>>;
+
+/// Cheapest path from `start` to `goal`, each node paired with the distance
+/// travelled to reach it. The path starts with `(start, 0)`.
+pub fn get_path<T, W, F>(
+    graph: &Graph<T, W>,
+    start: NodeId,
+    goal: NodeId,
+    mut is_edge_walkable: F,
+) -> Option<Vec<(NodeId, W)>>
+where
+    W: Weight,
+    F: FnMut(&Edge<W>) -> bool,
+{
+    let mut distances: Vec<Option<W>> = vec![None; graph.len()];
+    let mut previous: Vec<Option<NodeId>> = vec![None; graph.len()];
+    distances[start] = Some(W::default());
+
+    let mut visit: Visit<W> = BinaryHeap::from([Reverse((W::default(), start))]);
+
+    while let Some(Reverse((current_distance, current_node))) = visit.pop() {
+        if distances[current_node].is_some_and(|distance| current_distance > distance) {
+            continue;
+        }
+
+        if current_node == goal {
+            let mut path = vec![(goal, current_distance)];
+            let mut current_node = goal;
+            while let Some(previous_node) = previous[current_node] {
+                path.push((previous_node, distances[previous_node].unwrap()));
+                current_node = previous_node;
+            }
+            path.reverse();
+
+            return Some(path);
+        }
+
+        for edge in graph.get_edges(current_node) {
+            if !is_edge_walkable(edge) {
+                continue;
+            }
+
+            let new_distance = current_distance + edge.weight;
+            let is_shorter =
+                distances[edge.destination].is_none_or(|distance| new_distance < distance);
+
+            if is_shorter {
+                distances[edge.destination] = Some(new_distance);
+                previous[edge.destination] = Some(current_node);
+                visit.push(Reverse((new_distance, edge.destination)));
+            }
+        }
+    }
+    None
+}
+
+/// Every distance `goal` was reached at while exploring out to `max_cost`,
+/// shortest first. Later entries are the longer routes found before the
+/// shortest one replaced them.
+pub fn get_path_lengths<T, W, F>(
+    graph: &Graph<T, W>,
+    start: NodeId,
+    goal: NodeId,
+    max_cost: W,
+    mut is_edge_walkable: F,
+) -> Vec<W>
+where
+    W: Weight,
+    F: FnMut(&Edge<W>) -> bool,
+{
+    let mut distances: Vec<Option<W>> = vec![None; graph.len()];
+    distances[start] = Some(W::default());
+
+    let mut visit: Visit<W> = BinaryHeap::from([Reverse((W::default(), start))]);
+    let mut lengths = Vec::new();
+
+    while let Some(Reverse((current_distance, current_node))) = visit.pop() {
+        // Outdated entries of the goal are kept, they are the longer routes
+        if current_node == goal {
+            lengths.push(current_distance);
+            continue;
+        }
+        if current_distance > max_cost
+            || distances[current_node].is_some_and(|distance| current_distance > distance)
+        {
+            continue;
+        }
+
+        for edge in graph.get_edges(current_node) {
+            if !is_edge_walkable(edge) {
+                continue;
+            }
+
+            let new_distance = current_distance + edge.weight;
+            let is_shorter =
+                distances[edge.destination].is_none_or(|distance| new_distance < distance);
+
+            if is_shorter {
+                distances[edge.destination] = Some(new_distance);
+                visit.push(Reverse((new_distance, edge.destination)));
+            }
+        }
+    }
+    lengths
+}
@@ -0,0 +1,127 @@
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+/// Dense index of a node, handed out by [`Graph::add_node`] in insertion order.
+pub type NodeId = usize;
+
+/// Anything that can be summed along a path and compared, `usize` being the usual pick.
+pub trait Weight: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<W: Copy + Ord + Default + Add<Output = W>> Weight for W {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<W = usize> {
+    pub origin: NodeId,
+    pub destination: NodeId,
+    pub weight: W,
+}
+
+/// A directed, weighted graph.
+///
+/// Node values double as their lookup key, so two equal values always map to
+/// the same node. Per-node state that changes while solving belongs outside
+/// of the graph.
+#[derive(Debug, Clone)]
+pub struct Graph<T, W = usize> {
+    nodes: Vec<T>,
+    edges: Vec<Vec<Edge<W>>>,
+    ids: HashMap<T, NodeId>,
+}
+
+impl<T, W> Default for Graph<T, W> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<T, W> Graph<T, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get_node(&self, id: NodeId) -> &T {
+        &self.nodes[id]
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &T)> {
+        self.nodes.iter().enumerate()
+    }
+
+    pub fn find_node(&self, predicate: impl FnMut(&T) -> bool) -> Option<NodeId> {
+        self.nodes.iter().position(predicate)
+    }
+
+    pub fn add_edge(&mut self, origin: NodeId, destination: NodeId, weight: W) {
+        assert!(
+            destination < self.nodes.len(),
+            "Unknown destination node {destination}"
+        );
+        self.edges[origin].push(Edge {
+            origin,
+            destination,
+            weight,
+        });
+    }
+
+    pub fn add_undirected_edge(&mut self, a: NodeId, b: NodeId, weight: W)
+    where
+        W: Copy,
+    {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    /// Outgoing edges of `id`, in the order they were added.
+    pub fn get_edges(&self, id: NodeId) -> &[Edge<W>] {
+        &self.edges[id]
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = &Edge<W>> {
+        self.edges.iter().flatten()
+    }
+
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|edge| edge.destination)
+    }
+
+    pub fn get_neighbors<F>(&self, id: NodeId, mut is_edge_walkable: F) -> Vec<NodeId>
+    where
+        F: FnMut(&Edge<W>) -> bool,
+    {
+        self.edges[id]
+            .iter()
+            .filter(|edge| is_edge_walkable(edge))
+            .map(|edge| edge.destination)
+            .collect()
+    }
+}
+
+impl<T: Hash + Eq + Clone, W> Graph<T, W> {
+    /// Adds `value` unless an equal node exists, returning the id either way.
+    pub fn add_node(&mut self, value: T) -> NodeId {
+        if let Some(&id) = self.ids.get(&value) {
+            return id;
+        }
+
+        let id = self.nodes.len();
+        self.ids.insert(value.clone(), id);
+        self.nodes.push(value);
+        self.edges.push(Vec::new());
+        id
+    }
+
+    pub fn get_id(&self, value: &T) -> Option<NodeId> {
+        self.ids.get(value).copied()
+    }
+}
@@ -0,0 +1,18 @@
+pub mod all_pairs;
+pub mod astar;
+pub mod bfs;
+pub mod clique;
+mod contract;
+mod count_paths;
+pub mod dijkstra;
+mod graph;
+pub mod longest_path;
+pub mod min_cut;
+mod scc;
+mod topo_sort;
+
+pub use contract::contract;
+pub use count_paths::{count_paths, count_paths_through_a_then_b};
+pub use graph::{Edge, Graph, NodeId, Weight};
+pub use scc::strongly_connected_components;
+pub use topo_sort::{find_cycle, topo_sort, topo_sort_by_key, Cycle};
@@ -0,0 +1,294 @@
+use std::{collections::HashMap, ops::Sub};
+
+use crate::{Graph, NodeId, Weight};
+
+/// Visited nodes as bits, which caps these searches at 128 nodes. Contract
+/// bigger graphs down to their junctions first.
+pub type Visited = u128;
+
+fn bit(node: NodeId) -> Visited {
+    1 << node
+}
+
+fn assert_fits<T, W>(graph: &Graph<T, W>) {
+    assert!(
+        graph.len() <= Visited::BITS as usize,
+        "Longest path searches handle at most {} nodes, got {}",
+        Visited::BITS,
+        graph.len()
+    );
+}
+
+/// Best result of the independent searches in `branches`, spread over
+/// threads with the `rayon` feature.
+#[cfg(feature = "rayon")]
+fn best_of<S: Send, R: Ord + Send>(
+    branches: Vec<S>,
+    search: impl Fn(S) -> Option<R> + Sync + Send,
+) -> Option<R> {
+    use rayon::prelude::*;
+
+    branches.into_par_iter().filter_map(search).max()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn best_of<S: Send, R: Ord + Send>(
+    branches: Vec<S>,
+    search: impl Fn(S) -> Option<R> + Sync + Send,
+) -> Option<R> {
+    branches.into_iter().filter_map(search).max()
+}
+
+struct LongestPath<'a, T, W> {
+    graph: &'a Graph<T, W>,
+    goal: NodeId,
+    /// The only node leading to `goal`, if there is one. Leaving it any other
+    /// way would shut `goal` off for good.
+    goal_entry: Option<NodeId>,
+    /// Heaviest edge into each node, no path gains more by entering it
+    max_in: Vec<W>,
+}
+
+impl<T, W: Weight + Sub<Output = W>> LongestPath<'_, T, W> {
+    /// `unvisited` sums `max_in` of the nodes not on the path yet, the most
+    /// the path could still grow by.
+    fn search(
+        &self,
+        node: NodeId,
+        visited: Visited,
+        length: W,
+        unvisited: W,
+        best: &mut Option<W>,
+    ) {
+        if node == self.goal {
+            *best = (*best).max(Some(length));
+            return;
+        }
+        if best.is_some_and(|best| length + unvisited <= best) {
+            return;
+        }
+
+        let must_finish = self.goal_entry == Some(node);
+        for edge in self.graph.get_edges(node) {
+            if must_finish && edge.destination != self.goal {
+                continue;
+            }
+            if visited & bit(edge.destination) == 0 {
+                self.search(
+                    edge.destination,
+                    visited | bit(edge.destination),
+                    length + edge.weight,
+                    unvisited - self.max_in[edge.destination],
+                    best,
+                );
+            }
+        }
+    }
+}
+
+/// Length of the longest simple path from `start` to `goal`, `None` when
+/// `goal` can't be reached. The search splits where the path first has a
+/// choice, searching those branches in parallel with the `rayon` feature.
+pub fn get_length<T, W>(graph: &Graph<T, W>, start: NodeId, goal: NodeId) -> Option<W>
+where
+    T: Sync,
+    W: Weight + Sub<Output = W> + Send + Sync,
+{
+    assert_fits(graph);
+    let mut max_in = vec![W::default(); graph.len()];
+    for edge in graph.edges() {
+        max_in[edge.destination] = max_in[edge.destination].max(edge.weight);
+    }
+    let max_in_total = max_in
+        .iter()
+        .fold(W::default(), |total, &weight| total + weight);
+    let mut entries = graph
+        .edges()
+        .filter(|edge| edge.destination == goal && edge.origin != goal)
+        .map(|edge| edge.origin);
+    let goal_entry = entries
+        .next()
+        .filter(|&entry| entries.all(|other| other == entry));
+    let longest = LongestPath {
+        graph,
+        goal,
+        goal_entry,
+        max_in,
+    };
+
+    // Follow the path for as long as it has no choice
+    let (mut node, mut visited, mut length) = (start, bit(start), W::default());
+    let mut unvisited = max_in_total - longest.max_in[start];
+    let branches = loop {
+        let next: Vec<_> = graph
+            .get_edges(node)
+            .iter()
+            .filter(|edge| visited & bit(edge.destination) == 0)
+            .collect();
+        if node == goal || next.len() != 1 {
+            break next;
+        }
+        node = next[0].destination;
+        visited |= bit(node);
+        length = length + next[0].weight;
+        unvisited = unvisited - longest.max_in[node];
+    };
+    if node == goal {
+        return Some(length);
+    }
+
+    best_of(branches, |edge| {
+        let mut best = None;
+        longest.search(
+            edge.destination,
+            visited | bit(edge.destination),
+            length + edge.weight,
+            unvisited - longest.max_in[edge.destination],
+            &mut best,
+        );
+        best
+    })
+}
+
+struct Rewards<'a, T, W, F> {
+    graph: &'a Graph<T, W>,
+    budget: W,
+    reward: F,
+}
+
+impl<T, W, R, F> Rewards<'_, T, W, F>
+where
+    W: Weight,
+    R: Weight,
+    F: Fn(NodeId, W) -> Option<R>,
+{
+    /// Nodes worth going to next, with the budget spent on arrival and what
+    /// entering them earns.
+    fn next(&self, node: NodeId, visited: Visited, spent: W) -> Vec<(NodeId, W, R)> {
+        self.graph
+            .get_edges(node)
+            .iter()
+            .filter(|edge| visited & bit(edge.destination) == 0)
+            .map(|edge| (edge.destination, spent + edge.weight))
+            .filter(|&(_, spent)| spent <= self.budget)
+            .filter_map(|(next, spent)| Some((next, spent, (self.reward)(next, spent)?)))
+            .collect()
+    }
+
+    fn search_max(
+        &self,
+        node: NodeId,
+        visited: Visited,
+        spent: W,
+        total: R,
+        min_in: &[Option<W>],
+        best: &mut R,
+    ) {
+        *best = (*best).max(total);
+
+        // Every node left reached as soon as it could be
+        let bound = (0..self.graph.len())
+            .filter(|&other| visited & bit(other) == 0)
+            .filter_map(|other| Some((other, spent + min_in[other]?)))
+            .filter(|&(_, spent)| spent <= self.budget)
+            .filter_map(|(other, spent)| (self.reward)(other, spent))
+            .fold(total, |bound, reward| bound + reward);
+        if bound <= *best {
+            return;
+        }
+
+        for (next, spent, reward) in self.next(node, visited, spent) {
+            self.search_max(
+                next,
+                visited | bit(next),
+                spent,
+                total + reward,
+                min_in,
+                best,
+            );
+        }
+    }
+
+    fn search_all(
+        &self,
+        node: NodeId,
+        visited: Visited,
+        spent: W,
+        total: R,
+        best: &mut HashMap<Visited, R>,
+    ) {
+        let entry = best.entry(visited).or_insert(total);
+        *entry = (*entry).max(total);
+
+        for (next, spent, reward) in self.next(node, visited, spent) {
+            self.search_all(next, visited | bit(next), spent, total + reward, best);
+        }
+    }
+}
+
+/// Most reward collected walking out of `start` without spending more than
+/// `budget` on edges. `reward` gets each node along with the budget spent
+/// when reaching it and returns `None` for nodes not worth entering.
+/// Pruning assumes a node never earns more by reaching it later.
+pub fn get_max_reward<T, W, R, F>(graph: &Graph<T, W>, start: NodeId, budget: W, reward: F) -> R
+where
+    T: Sync,
+    W: Weight + Send + Sync,
+    R: Weight + Send,
+    F: Fn(NodeId, W) -> Option<R> + Sync,
+{
+    assert_fits(graph);
+    let mut min_in: Vec<Option<W>> = vec![None; graph.len()];
+    for edge in graph.edges() {
+        let weight = &mut min_in[edge.destination];
+        *weight = Some(weight.map_or(edge.weight, |weight| weight.min(edge.weight)));
+    }
+
+    let rewards = Rewards {
+        graph,
+        budget,
+        reward,
+    };
+    let branches = rewards.next(start, bit(start), W::default());
+    best_of(branches, |(next, spent, reward)| {
+        let mut best = R::default();
+        rewards.search_max(
+            next,
+            bit(start) | bit(next),
+            spent,
+            reward,
+            &min_in,
+            &mut best,
+        );
+        Some(best)
+    })
+    .unwrap_or_default()
+}
+
+/// Most reward collected for every set of nodes a walk out of `start` can
+/// enter within `budget`, see [`get_max_reward`]. The sets leave out
+/// `start`, so two walkers sharing the work can combine disjoint ones.
+pub fn get_best_rewards<T, W, R, F>(
+    graph: &Graph<T, W>,
+    start: NodeId,
+    budget: W,
+    reward: F,
+) -> HashMap<Visited, R>
+where
+    W: Weight,
+    R: Weight,
+    F: Fn(NodeId, W) -> Option<R>,
+{
+    assert_fits(graph);
+    let rewards = Rewards {
+        graph,
+        budget,
+        reward,
+    };
+
+    let mut best = HashMap::new();
+    rewards.search_all(start, bit(start), W::default(), R::default(), &mut best);
+    best.into_iter()
+        .map(|(visited, total)| (visited & !bit(start), total))
+        .collect()
+}
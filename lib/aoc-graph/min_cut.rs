@@ -0,0 +1,193 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::{Edge, Graph, NodeId, Weight};
+
+/// A split of the nodes in two, with the edges crossing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut<W = usize> {
+    pub weight: W,
+    /// Crossing edges, each going from the first side to the second one.
+    pub edges: Vec<Edge<W>>,
+    /// Both sides of the cut, sorted by node id.
+    pub partition: (Vec<NodeId>, Vec<NodeId>),
+}
+
+impl<W: Weight> MinCut<W> {
+    fn from_side<T>(graph: &Graph<T, W>, in_first: &[bool]) -> Self {
+        let edges: Vec<Edge<W>> = graph
+            .edges()
+            .filter(|edge| in_first[edge.origin] && !in_first[edge.destination])
+            .copied()
+            .collect();
+        let (first, second) = (0..graph.len()).partition(|&node| in_first[node]);
+
+        Self {
+            weight: edges
+                .iter()
+                .fold(W::default(), |weight, edge| weight + edge.weight),
+            edges,
+            partition: (first, second),
+        }
+    }
+}
+
+/// Global minimum cut of an undirected graph with Stoer–Wagner, `None` with
+/// less than two nodes. Edges are expected to go both ways, as added by
+/// [`Graph::add_undirected_edge`], and weights must not be negative.
+pub fn stoer_wagner<T, W: Weight>(graph: &Graph<T, W>) -> Option<MinCut<W>> {
+    if graph.len() < 2 {
+        return None;
+    }
+
+    // Merged nodes keep the id of the node they were merged into
+    let mut adjacency: Vec<HashMap<NodeId, W>> = vec![HashMap::new(); graph.len()];
+    for edge in graph.edges().filter(|edge| edge.origin != edge.destination) {
+        let weight = adjacency[edge.origin].entry(edge.destination).or_default();
+        *weight = *weight + edge.weight;
+    }
+    let mut members: Vec<Vec<NodeId>> = (0..graph.len()).map(|node| vec![node]).collect();
+    let mut active: Vec<NodeId> = (0..graph.len()).collect();
+
+    let mut best: Option<(W, Vec<NodeId>)> = None;
+    while active.len() > 1 {
+        let (s, t, cut_of_phase) = maximum_adjacency_phase(&adjacency, &active);
+        if best
+            .as_ref()
+            .is_none_or(|(weight, _)| cut_of_phase < *weight)
+        {
+            best = Some((cut_of_phase, members[t].clone()));
+        }
+
+        // Merge t into s
+        for (neighbor, weight) in std::mem::take(&mut adjacency[t]) {
+            adjacency[neighbor].remove(&t);
+            if neighbor == s {
+                continue;
+            }
+            let to_neighbor = adjacency[s].entry(neighbor).or_default();
+            *to_neighbor = *to_neighbor + weight;
+            let from_neighbor = adjacency[neighbor].entry(s).or_default();
+            *from_neighbor = *from_neighbor + weight;
+        }
+        let merged = std::mem::take(&mut members[t]);
+        members[s].extend(merged);
+        active.retain(|&node| node != t);
+    }
+
+    let (_, side) = best?;
+    let mut in_first = vec![false; graph.len()];
+    for node in side {
+        in_first[node] = true;
+    }
+    Some(MinCut::from_side(graph, &in_first))
+}
+
+/// Adds the most tightly connected node until every active node is in,
+/// returning the last two added and how strongly the last one was connected.
+fn maximum_adjacency_phase<W: Weight>(
+    adjacency: &[HashMap<NodeId, W>],
+    active: &[NodeId],
+) -> (NodeId, NodeId, W) {
+    let mut connection = vec![W::default(); adjacency.len()];
+    let mut added = vec![false; adjacency.len()];
+    // Outdated entries are skipped when popped, like in dijkstra
+    let mut visit: BinaryHeap<(W, NodeId)> =
+        active.iter().map(|&node| (W::default(), node)).collect();
+
+    let (mut previous, mut last) = (active[0], active[0]);
+    while let Some((weight, node)) = visit.pop() {
+        if added[node] || weight < connection[node] {
+            continue;
+        }
+        added[node] = true;
+        (previous, last) = (last, node);
+
+        for (&neighbor, &edge_weight) in &adjacency[node] {
+            if !added[neighbor] {
+                connection[neighbor] = connection[neighbor] + edge_weight;
+                visit.push((connection[neighbor], neighbor));
+            }
+        }
+    }
+
+    (previous, last, connection[last])
+}
+
+/// SplitMix64, enough randomness to shuffle edges without pulling in `rand`.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+fn find(parents: &mut [NodeId], node: NodeId) -> NodeId {
+    let mut root = node;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut node = node;
+    while parents[node] != root {
+        (node, parents[node]) = (parents[node], root);
+    }
+    root
+}
+
+/// Best of `trials` runs of Karger's random contraction, reproducible for a
+/// given `seed`. Edges are contracted in a uniformly shuffled order, so
+/// weights only count towards the cut weight. `None` with less than two
+/// nodes or no trials.
+pub fn karger<T, W: Weight>(graph: &Graph<T, W>, seed: u64, trials: usize) -> Option<MinCut<W>> {
+    if graph.len() < 2 {
+        return None;
+    }
+
+    let mut rng = Rng(seed);
+    let mut edges: Vec<(NodeId, NodeId)> = graph
+        .edges()
+        .map(|edge| (edge.origin, edge.destination))
+        .filter(|(origin, destination)| origin != destination)
+        .collect();
+
+    let mut best: Option<MinCut<W>> = None;
+    for _ in 0..trials {
+        for index in (1..edges.len()).rev() {
+            edges.swap(index, rng.below(index + 1));
+        }
+
+        let mut parents: Vec<NodeId> = (0..graph.len()).collect();
+        let mut components = graph.len();
+        for &(origin, destination) in &edges {
+            if components == 2 {
+                break;
+            }
+            let (origin, destination) =
+                (find(&mut parents, origin), find(&mut parents, destination));
+            if origin != destination {
+                parents[destination] = origin;
+                components -= 1;
+            }
+        }
+
+        // A disconnected graph can end up with more than two components, the
+        // one holding node 0 still makes a valid cut
+        let side = find(&mut parents, 0);
+        let in_first: Vec<bool> = (0..graph.len())
+            .map(|node| find(&mut parents, node) == side)
+            .collect();
+        let cut = MinCut::from_side(graph, &in_first);
+        if best.as_ref().is_none_or(|best| cut.weight < best.weight) {
+            best = Some(cut);
+        }
+    }
+    best
+}
@@ -0,0 +1,70 @@
+use crate::{Graph, NodeId};
+
+/// Strongly connected components with Tarjan's algorithm, each sorted by
+/// node id. Components come in reverse topological order, so nothing in a
+/// component leads to one listed after it.
+pub fn strongly_connected_components<T, W>(graph: &Graph<T, W>) -> Vec<Vec<NodeId>> {
+    let mut index: Vec<Option<usize>> = vec![None; graph.len()];
+    let mut low_link = vec![0; graph.len()];
+    let mut on_stack = vec![false; graph.len()];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for root in 0..graph.len() {
+        if index[root].is_some() {
+            continue;
+        }
+
+        // Explicit call stack of nodes and the next edge to follow, deep
+        // graphs would overflow a recursive one
+        let mut calls = vec![(root, 0)];
+        index[root] = Some(next_index);
+        low_link[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&(node, edge)) = calls.last() {
+            if let Some(edge) = graph.get_edges(node).get(edge) {
+                calls.last_mut().unwrap().1 += 1;
+                let next = edge.destination;
+                match index[next] {
+                    None => {
+                        index[next] = Some(next_index);
+                        low_link[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        calls.push((next, 0));
+                    }
+                    Some(next_index) if on_stack[next] => {
+                        low_link[node] = low_link[node].min(next_index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                low_link[parent] = low_link[parent].min(low_link[node]);
+            }
+            if Some(low_link[node]) == index[node] {
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().unwrap();
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
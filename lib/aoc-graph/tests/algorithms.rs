@@ -0,0 +1,495 @@
+use aoc_graph::{
+    all_pairs, astar, bfs, clique, contract, count_paths, count_paths_through_a_then_b, dijkstra,
+    find_cycle, longest_path, min_cut, strongly_connected_components, topo_sort, topo_sort_by_key,
+    Cycle, Graph, NodeId,
+};
+
+/// a -> b -> d, a -> c -> d, with the route over `c` being cheaper.
+fn diamond() -> (Graph<&'static str>, [NodeId; 4]) {
+    let mut graph = Graph::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    graph.add_edge(a, b, 1);
+    graph.add_edge(b, d, 5);
+    graph.add_edge(a, c, 2);
+    graph.add_edge(c, d, 1);
+    (graph, [a, b, c, d])
+}
+
+#[test]
+fn add_node_reuses_equal_values() {
+    let mut graph: Graph<&str> = Graph::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+
+    assert_eq!(graph.add_node("a"), a);
+    assert_eq!(graph.len(), 2);
+    assert_eq!(graph.get_id(&"b"), Some(b));
+    assert_eq!(graph.get_id(&"z"), None);
+    assert_eq!(*graph.get_node(b), "b");
+}
+
+#[test]
+fn undirected_edges_go_both_ways() {
+    let mut graph: Graph<u8> = Graph::new();
+    let a = graph.add_node(0);
+    let b = graph.add_node(1);
+    graph.add_undirected_edge(a, b, 3);
+
+    assert_eq!(graph.neighbors(a).collect::<Vec<_>>(), vec![b]);
+    assert_eq!(graph.neighbors(b).collect::<Vec<_>>(), vec![a]);
+    assert_eq!(graph.edges().count(), 2);
+}
+
+#[test]
+fn dijkstra_follows_weights() {
+    let (graph, [a, _, c, d]) = diamond();
+
+    let path = dijkstra::get_path(&graph, a, d, |_| true).unwrap();
+    assert_eq!(path, vec![(a, 0), (c, 2), (d, 3)]);
+}
+
+#[test]
+fn dijkstra_respects_walkable_edges() {
+    let (graph, [a, b, c, d]) = diamond();
+
+    let path = dijkstra::get_path(&graph, a, d, |edge| edge.destination != c).unwrap();
+    assert_eq!(path, vec![(a, 0), (b, 1), (d, 6)]);
+    assert!(dijkstra::get_path(&graph, d, a, |_| true).is_none());
+}
+
+#[test]
+fn dijkstra_path_lengths_reports_each_improvement() {
+    let (graph, [a, _, _, d]) = diamond();
+
+    assert_eq!(
+        dijkstra::get_path_lengths(&graph, a, d, 10, |_| true),
+        vec![3, 6]
+    );
+    assert!(dijkstra::get_path_lengths(&graph, a, d, 0, |_| true).is_empty());
+}
+
+#[test]
+fn bfs_finds_every_simple_path() {
+    let (graph, [a, b, c, d]) = diamond();
+
+    let mut paths = bfs::get_paths(&graph, a, d, usize::MAX, |_| true);
+    paths.sort();
+    assert_eq!(paths, vec![vec![a, b, d], vec![a, c, d]]);
+
+    assert!(bfs::get_paths(&graph, a, d, 2, |_| true).is_empty());
+}
+
+#[test]
+fn bfs_find_all_paths_visits_in_path_length_order() {
+    let (graph, [a, _, _, d]) = diamond();
+
+    let mut lengths = Vec::new();
+    bfs::find_all_paths(&graph, a, |node, path| {
+        assert_eq!(path.last(), Some(&node));
+        lengths.push(path.len());
+    });
+    assert_eq!(lengths, vec![1, 2, 2, 3, 3]);
+
+    let mut reached_d = 0;
+    bfs::find_all_paths(&graph, a, |node, _| reached_d += (node == d) as usize);
+    assert_eq!(reached_d, 2);
+}
+
+#[test]
+fn count_paths_in_dag() {
+    let (graph, [a, b, c, d]) = diamond();
+
+    assert_eq!(count_paths(&graph, a, d), Ok(2));
+    assert_eq!(count_paths(&graph, b, d), Ok(1));
+    assert_eq!(count_paths(&graph, d, a), Ok(0));
+    assert_eq!(count_paths_through_a_then_b(&graph, a, d, c, d), Ok(1));
+    assert_eq!(count_paths_through_a_then_b(&graph, a, d, b, c), Ok(0));
+}
+
+#[test]
+fn count_paths_rejects_cycles_on_the_way() {
+    let (mut graph, [a, b, c, d]) = diamond();
+    let e = graph.add_node("e");
+    // Loops between d and e, after the goal c
+    graph.add_edge(d, e, 1);
+    graph.add_edge(e, d, 1);
+    assert_eq!(count_paths(&graph, a, c), Ok(1));
+
+    let Err(Cycle(cycle)) = count_paths(&graph, a, e) else {
+        panic!("Paths to e go around d and e forever");
+    };
+    assert!(cycle == vec![d, e] || cycle == vec![e, d]);
+    assert!(count_paths_through_a_then_b(&graph, a, e, b, d).is_err());
+}
+
+#[test]
+fn topo_sort_orders_edges_forward() {
+    let (graph, [a, b, c, d]) = diamond();
+    assert_eq!(topo_sort(&graph), Ok(vec![a, b, c, d]));
+    assert_eq!(
+        topo_sort_by_key(&graph, std::cmp::Reverse),
+        Ok(vec![a, c, b, d])
+    );
+    assert_eq!(find_cycle(&graph), None);
+}
+
+#[test]
+fn topo_sort_by_key_picks_smallest_ready_step() {
+    // The steps of 2018 day 7's example, added out of order
+    let mut graph: Graph<char> = Graph::new();
+    for (before, after) in [
+        ('F', 'E'),
+        ('C', 'A'),
+        ('C', 'F'),
+        ('A', 'B'),
+        ('A', 'D'),
+        ('B', 'E'),
+        ('D', 'E'),
+    ] {
+        let before = graph.add_node(before);
+        let after = graph.add_node(after);
+        graph.add_edge(before, after, 1);
+    }
+
+    let order = topo_sort_by_key(&graph, |node| *graph.get_node(node)).unwrap();
+    let steps: String = order
+        .into_iter()
+        .map(|node| *graph.get_node(node))
+        .collect();
+    assert_eq!(steps, "CABDFE");
+}
+
+#[test]
+fn find_cycle_returns_a_witness() {
+    let (mut graph, [a, b, _, d]) = diamond();
+    graph.add_edge(d, a, 1);
+
+    let cycle = find_cycle(&graph).unwrap();
+    assert!(cycle.0.contains(&a) && cycle.0.contains(&d));
+    for (index, &node) in cycle.0.iter().enumerate() {
+        let next = cycle.0[(index + 1) % cycle.0.len()];
+        assert!(graph.neighbors(node).any(|neighbor| neighbor == next));
+    }
+    assert!(topo_sort(&graph).is_err());
+    assert_eq!(
+        Cycle(vec![a, b, d]).to_string(),
+        "Graph has a cycle through nodes 0 -> 1 -> 3"
+    );
+}
+
+#[test]
+fn strongly_connected_components_come_in_reverse_topological_order() {
+    let (mut graph, [a, b, c, d]) = diamond();
+    let e = graph.add_node("e");
+    graph.add_edge(b, a, 1);
+    graph.add_edge(d, e, 1);
+    graph.add_edge(e, d, 1);
+
+    assert_eq!(
+        strongly_connected_components(&graph),
+        vec![vec![d, e], vec![c], vec![a, b]]
+    );
+}
+
+/// Walks a 3x3 grid from the top left corner, every step costing 1.
+fn grid_successors(&(x, y): &(i32, i32)) -> Vec<((i32, i32), usize)> {
+    [(1, 0), (-1, 0), (0, 1), (0, -1)]
+        .into_iter()
+        .map(|(dx, dy)| (x + dx, y + dy))
+        .filter(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y))
+        .map(|position| (position, 1))
+        .collect()
+}
+
+fn grid_heuristic(&(x, y): &(i32, i32)) -> usize {
+    (2 - x + 2 - y) as usize
+}
+
+#[test]
+fn astar_finds_cheapest_path() {
+    let (path, cost) = astar::get_path([(0, 0)], grid_successors, grid_heuristic, |&state| {
+        state == (2, 2)
+    })
+    .unwrap();
+
+    assert_eq!(cost, 4);
+    assert_eq!(path.len(), 5);
+    assert_eq!(path.first(), Some(&(0, 0)));
+    assert_eq!(path.last(), Some(&(2, 2)));
+
+    assert!(astar::get_path([(0, 0)], grid_successors, grid_heuristic, |_| false).is_none());
+}
+
+#[test]
+fn astar_best_paths_keeps_ties() {
+    let search = astar::get_best_paths([(0, 0)], grid_successors, grid_heuristic, |&state| {
+        state == (2, 2)
+    });
+
+    assert_eq!(search.cost(), Some(4));
+    // Choosing 2 of the 4 steps to go right
+    assert_eq!(search.paths().len(), 6);
+    assert_eq!(search.on_best_paths().count(), 9);
+    assert_eq!(search.get_cost(&(1, 1)), Some(2));
+}
+
+#[test]
+fn astar_costs_cover_every_reachable_state() {
+    let costs = astar::get_costs([(0, 0), (2, 2)], grid_successors);
+
+    assert_eq!(costs.len(), 9);
+    assert_eq!(costs[&(1, 1)], 2);
+    assert_eq!(costs[&(2, 0)], 2);
+    assert_eq!(costs[&(2, 2)], 0);
+}
+
+/// Triangles a-b-c and b-c-d sharing an edge, with e hanging off d.
+fn two_triangles() -> (Graph<char>, [NodeId; 5]) {
+    let mut graph = Graph::new();
+    let [a, b, c, d, e] = ['a', 'b', 'c', 'd', 'e'].map(|name| graph.add_node(name));
+    for (from, to) in [(a, b), (a, c), (b, c), (b, d), (c, d), (d, e)] {
+        graph.add_undirected_edge(from, to, 1);
+    }
+    (graph, [a, b, c, d, e])
+}
+
+#[test]
+fn clique_maximal_cliques_are_not_contained_in_others() {
+    let (graph, [a, b, c, d, e]) = two_triangles();
+
+    let mut cliques = clique::maximal_cliques(&graph);
+    cliques.sort();
+    assert_eq!(cliques, vec![vec![a, b, c], vec![b, c, d], vec![d, e]]);
+    assert_eq!(clique::maximum_clique(&graph), vec![a, b, c]);
+}
+
+#[test]
+fn clique_k_cliques_include_non_maximal_ones() {
+    let (graph, [a, b, c, d, e]) = two_triangles();
+
+    assert_eq!(
+        clique::k_cliques(&graph, 3),
+        vec![vec![a, b, c], vec![b, c, d]]
+    );
+    assert_eq!(clique::k_cliques(&graph, 2).len(), 6);
+    assert_eq!(clique::k_cliques(&graph, 1).len(), 5);
+    assert!(clique::k_cliques(&graph, 4).is_empty());
+    assert!(clique::k_cliques(&graph, 2).contains(&vec![d, e]));
+}
+
+#[test]
+fn clique_handles_more_than_64_nodes() {
+    let mut graph: Graph<usize> = Graph::new();
+    for node in 0..70 {
+        graph.add_node(node);
+    }
+    for a in 0..70 {
+        for b in a + 1..70 {
+            graph.add_undirected_edge(a, b, 1);
+        }
+    }
+
+    assert_eq!(clique::maximal_cliques(&graph).len(), 1);
+    assert_eq!(clique::maximum_clique(&graph), (0..70).collect::<Vec<_>>());
+    // 70 choose 3
+    assert_eq!(clique::k_cliques(&graph, 3).len(), 54740);
+}
+
+/// Two 4-node cliques held together by the edges 0-4 and 3-7.
+fn two_clusters() -> Graph<usize> {
+    let mut graph = Graph::new();
+    for node in 0..8 {
+        graph.add_node(node);
+    }
+    for cluster in [0..4, 4..8] {
+        for a in cluster.clone() {
+            for b in a + 1..cluster.end {
+                graph.add_undirected_edge(a, b, 1);
+            }
+        }
+    }
+    graph.add_undirected_edge(0, 4, 1);
+    graph.add_undirected_edge(3, 7, 1);
+    graph
+}
+
+#[test]
+fn min_cut_stoer_wagner_splits_clusters() {
+    let graph = two_clusters();
+
+    let cut = min_cut::stoer_wagner(&graph).unwrap();
+    assert_eq!(cut.weight, 2);
+    let mut sides = [cut.partition.0.clone(), cut.partition.1.clone()];
+    sides.sort();
+    assert_eq!(sides, [vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+
+    let mut edges: Vec<_> = cut
+        .edges
+        .iter()
+        .map(|edge| {
+            (
+                edge.origin.min(edge.destination),
+                edge.origin.max(edge.destination),
+            )
+        })
+        .collect();
+    edges.sort();
+    assert_eq!(edges, vec![(0, 4), (3, 7)]);
+    assert!(cut
+        .edges
+        .iter()
+        .all(|edge| cut.partition.0.contains(&edge.origin)));
+}
+
+#[test]
+fn min_cut_stoer_wagner_follows_weights() {
+    let mut graph: Graph<char> = Graph::new();
+    let [a, b, c] = ['a', 'b', 'c'].map(|name| graph.add_node(name));
+    graph.add_undirected_edge(a, b, 5);
+    graph.add_undirected_edge(b, c, 3);
+    graph.add_undirected_edge(a, c, 1);
+
+    let cut = min_cut::stoer_wagner(&graph).unwrap();
+    assert_eq!(cut.weight, 4);
+    assert!(cut.partition == (vec![c], vec![a, b]) || cut.partition == (vec![a, b], vec![c]));
+
+    let single: Graph<char> = Graph::new();
+    assert!(min_cut::stoer_wagner(&single).is_none());
+}
+
+#[test]
+fn min_cut_karger_is_reproducible() {
+    let graph = two_clusters();
+
+    let cut = min_cut::karger(&graph, 7, 50).unwrap();
+    assert_eq!(cut.weight, 2);
+    assert_eq!(min_cut::karger(&graph, 7, 50), Some(cut));
+    assert!(min_cut::karger(&graph, 7, 0).is_none());
+}
+
+/// A 5x3 room with a wall down the middle row except at both ends, so the
+/// corners are joined by two corridors of different lengths.
+fn corridors() -> (Graph<(i32, i32)>, [NodeId; 4]) {
+    let mut graph = Graph::new();
+    let open = |(x, y): (i32, i32)| {
+        (0..5).contains(&x) && (0..3).contains(&y) && (y != 1 || x == 0 || x == 4)
+    };
+    for y in 0..3 {
+        for x in 0..5 {
+            if !open((x, y)) {
+                continue;
+            }
+            let node = graph.add_node((x, y));
+            for neighbor in [(x - 1, y), (x, y - 1)] {
+                if open(neighbor) {
+                    let neighbor = graph.get_id(&neighbor).unwrap();
+                    graph.add_undirected_edge(node, neighbor, 1);
+                }
+            }
+        }
+    }
+    let corners = [(0, 0), (4, 0), (0, 2), (4, 2)].map(|corner| graph.get_id(&corner).unwrap());
+    (graph, corners)
+}
+
+#[test]
+fn contract_collapses_corridors() {
+    let (graph, [top_left, top_right, bottom_left, bottom_right]) = corridors();
+
+    let contracted = contract(&graph, |_, &(x, _)| x == 0);
+    assert_eq!(contracted.len(), 3);
+    let ids = [(0, 0), (0, 1), (0, 2)].map(|position| {
+        let id = graph.get_id(&position).unwrap();
+        contracted.get_id(&id).unwrap()
+    });
+    assert_eq!(contracted.neighbors(ids[1]).count(), 2);
+    // Around the far side rather than through the middle
+    let far_side = contracted
+        .get_edges(ids[0])
+        .iter()
+        .find(|edge| edge.destination == ids[2])
+        .unwrap();
+    assert_eq!(far_side.weight, 10);
+
+    let corners = contract(&graph, |id, _| {
+        [top_left, top_right, bottom_left, bottom_right].contains(&id)
+    });
+    assert_eq!(corners.edges().count(), 8);
+    assert!(corners
+        .edges()
+        .all(|edge| edge.weight == 2 || edge.weight == 4));
+}
+
+#[test]
+fn all_pairs_distances_match_dijkstra() {
+    let (graph, [a, b, c, d]) = diamond();
+
+    let distances = all_pairs::get_distances(&graph);
+    assert_eq!(distances[a][d], Some(3));
+    assert_eq!(distances[b][d], Some(5));
+    assert_eq!(distances[c][c], Some(0));
+    assert_eq!(distances[d][a], None);
+
+    let (graph, _) = corridors();
+    let distances = all_pairs::get_distances(&graph);
+    for (origin, row) in distances.iter().enumerate() {
+        for (destination, &distance) in row.iter().enumerate() {
+            let path = dijkstra::get_path(&graph, origin, destination, |_| true);
+            assert_eq!(
+                distance,
+                path.and_then(|path| path.last().map(|&(_, distance)| distance))
+            );
+        }
+    }
+}
+
+#[test]
+fn longest_path_takes_the_long_way() {
+    let (graph, [a, b, c, d]) = diamond();
+    assert_eq!(longest_path::get_length(&graph, a, d), Some(6));
+    assert_eq!(longest_path::get_length(&graph, c, d), Some(1));
+    assert_eq!(longest_path::get_length(&graph, a, a), Some(0));
+    assert_eq!(longest_path::get_length(&graph, d, b), None);
+
+    // 1 -> 0 -> 4 -> 5 -> 6 -> 7 -> 3 -> 2 covers every node
+    let graph = two_clusters();
+    assert_eq!(longest_path::get_length(&graph, 1, 2), Some(7));
+    assert_eq!(longest_path::get_length(&graph, 0, 7), Some(4));
+}
+
+/// Start `s` with two valves, `a` worth 5 and `b` worth 3 per step left
+/// once reached within a budget of 6.
+fn valves() -> (Graph<char>, impl Fn(NodeId, usize) -> Option<usize>) {
+    let mut graph = Graph::new();
+    let [s, a, b] = ['s', 'a', 'b'].map(|name| graph.add_node(name));
+    graph.add_undirected_edge(s, a, 2);
+    graph.add_undirected_edge(s, b, 1);
+    graph.add_undirected_edge(a, b, 2);
+
+    let rates = [0, 5, 3];
+    let reward = move |node: NodeId, spent: usize| match rates[node] {
+        0 => None,
+        rate => Some((6 - spent) * rate),
+    };
+    (graph, reward)
+}
+
+#[test]
+fn longest_path_rewards_depend_on_arrival() {
+    let (graph, reward) = valves();
+
+    // b first for 5 * 3, then a for 3 * 5
+    assert_eq!(longest_path::get_max_reward(&graph, 0, 6, &reward), 30);
+    assert_eq!(longest_path::get_max_reward(&graph, 0, 1, &reward), 15);
+    assert_eq!(longest_path::get_max_reward(&graph, 0, 0, &reward), 0);
+
+    let best = longest_path::get_best_rewards(&graph, 0, 6, &reward);
+    assert_eq!(best.len(), 4);
+    assert_eq!(best[&0], 0);
+    assert_eq!(best[&0b010], 20);
+    assert_eq!(best[&0b100], 15);
+    assert_eq!(best[&0b110], 30);
+}
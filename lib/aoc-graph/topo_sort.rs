@@ -0,0 +1,105 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt};
+
+use crate::{Graph, NodeId};
+
+/// Nodes going around in a loop, each one with an edge to the next and the
+/// last one back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nodes: Vec<String> = self.0.iter().map(|node| node.to_string()).collect();
+        write!(f, "Graph has a cycle through nodes {}", nodes.join(" -> "))
+    }
+}
+
+impl std::error::Error for Cycle {}
+
+/// Kahn's algorithm over the nodes marked in `included`, always taking the
+/// ready node with the smallest key next. Returns the order and which nodes
+/// made it in, nodes left out being on or behind a cycle.
+pub(crate) fn kahn<T, W, K: Ord>(
+    graph: &Graph<T, W>,
+    included: &[bool],
+    mut key: impl FnMut(NodeId) -> K,
+) -> (Vec<NodeId>, Vec<bool>) {
+    let mut indegree = vec![0usize; graph.len()];
+    for edge in graph.edges().filter(|edge| included[edge.origin]) {
+        indegree[edge.destination] += 1;
+    }
+
+    let mut ready: BinaryHeap<Reverse<(K, NodeId)>> = (0..graph.len())
+        .filter(|&node| included[node] && indegree[node] == 0)
+        .map(|node| Reverse((key(node), node)))
+        .collect();
+    let mut order = Vec::new();
+    let mut sorted = vec![false; graph.len()];
+    while let Some(Reverse((_, node))) = ready.pop() {
+        order.push(node);
+        sorted[node] = true;
+        for neighbor in graph.neighbors(node).filter(|&neighbor| included[neighbor]) {
+            indegree[neighbor] -= 1;
+            if indegree[neighbor] == 0 {
+                ready.push(Reverse((key(neighbor), neighbor)));
+            }
+        }
+    }
+
+    (order, sorted)
+}
+
+/// Walks back from `node`, which [`kahn`] left out, until a node repeats.
+/// Every node left out still waits on another one left out, so this always
+/// ends on a cycle.
+pub(crate) fn cycle_before<T, W>(
+    graph: &Graph<T, W>,
+    included: &[bool],
+    sorted: &[bool],
+    node: NodeId,
+) -> Cycle {
+    let mut waiting_on: Vec<Option<NodeId>> = vec![None; graph.len()];
+    for edge in graph.edges() {
+        if included[edge.origin] && !sorted[edge.origin] {
+            waiting_on[edge.destination] = Some(edge.origin);
+        }
+    }
+
+    let mut position: Vec<Option<usize>> = vec![None; graph.len()];
+    let mut walk = Vec::new();
+    let mut node = node;
+    while position[node].is_none() {
+        position[node] = Some(walk.len());
+        walk.push(node);
+        node = waiting_on[node].expect("Nodes left out wait on another one");
+    }
+
+    let mut cycle = walk.split_off(position[node].unwrap());
+    cycle.reverse();
+    Cycle(cycle)
+}
+
+/// Every node ordered so edges only point forward. Nodes without an order
+/// between them keep their insertion order.
+pub fn topo_sort<T, W>(graph: &Graph<T, W>) -> Result<Vec<NodeId>, Cycle> {
+    topo_sort_by_key(graph, |node| node)
+}
+
+/// Like [`topo_sort`], picking the node with the smallest `key` whenever
+/// several are ready, which gives the lexicographically smallest order.
+pub fn topo_sort_by_key<T, W, K: Ord>(
+    graph: &Graph<T, W>,
+    key: impl FnMut(NodeId) -> K,
+) -> Result<Vec<NodeId>, Cycle> {
+    let included = vec![true; graph.len()];
+    let (order, sorted) = kahn(graph, &included, key);
+    match sorted.iter().position(|&sorted| !sorted) {
+        Some(node) => Err(cycle_before(graph, &included, &sorted, node)),
+        None => Ok(order),
+    }
+}
+
+/// Some cycle of the graph, `None` when it has none.
+pub fn find_cycle<T, W>(graph: &Graph<T, W>) -> Option<Cycle> {
+    topo_sort(graph).err()
+}
@@ -0,0 +1,62 @@
+use std::ops::Mul;
+
+use crate::{Bound, Interval};
+
+/// Every point whose coordinates each fall within the interval of their
+/// axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hyperrectangle<T, const N: usize> {
+    axes: [Interval<T>; N],
+}
+
+impl<T: Bound, const N: usize> Hyperrectangle<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn axes(&self) -> &[Interval<T>; N] {
+        &self.axes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|axis| axis.is_empty())
+    }
+
+    /// Number of points inside.
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.axes
+            .iter()
+            .fold(T::from(1), |volume, axis| volume * axis.len())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(axis, value)| axis.contains(*value))
+    }
+
+    /// `None` rather than an empty one when they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(other)?;
+        }
+        Some(Self { axes })
+    }
+
+    /// Cut in two across `axis`, the part below `at` on that axis and the
+    /// part from it, `None` for the ones that would be empty.
+    pub fn split_at(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(at);
+        let with_axis = |interval: Interval<T>| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            Self { axes }
+        };
+        (below.map(with_axis), above.map(with_axis))
+    }
+}
@@ -0,0 +1,74 @@
+use std::ops::{Add, Sub};
+
+/// Anything intervals can be made of, integers being the usual pick.
+pub trait Bound: Copy + Ord + From<u8> + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + From<u8> + Add<Output = T> + Sub<Output = T>> Bound for T {}
+
+/// The values from `start` up to but not including `end`. Puzzles mostly
+/// give both ends, [`Interval::inclusive`] takes those.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn inclusive(start: T, last: T) -> Self {
+        Self::new(start, last + T::from(1))
+    }
+
+    pub fn with_length(start: T, length: T) -> Self {
+        Self::new(start, start + length)
+    }
+
+    /// The last value in the interval, meaningless for empty ones.
+    pub fn last(&self) -> T {
+        self.end - T::from(1)
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::from(0)
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is in this one too.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// `None` rather than an empty interval when they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The parts below and from `at`, `None` for the ones that would be empty.
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, at.min(self.end));
+        let above = Self::new(at.max(self.start), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+}
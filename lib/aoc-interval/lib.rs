@@ -0,0 +1,9 @@
+mod hyperrectangle;
+mod interval;
+mod piecewise;
+mod set;
+
+pub use hyperrectangle::Hyperrectangle;
+pub use interval::{Bound, Interval};
+pub use piecewise::PiecewiseMap;
+pub use set::IntervalSet;
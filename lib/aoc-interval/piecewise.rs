@@ -0,0 +1,67 @@
+use crate::{Bound, Interval, IntervalSet};
+
+/// Moves values inside any of its source intervals by the same amount as the
+/// start of that interval, leaving the rest where they are. Source intervals
+/// are not supposed to overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+    sources: IntervalSet<T>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        Self {
+            pieces: Vec::new(),
+            sources: IntervalSet::default(),
+        }
+    }
+}
+
+impl<T: Bound> PiecewiseMap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends `source.start` to `destination` and everything else in `source`
+    /// along with it.
+    pub fn insert(&mut self, source: Interval<T>, destination: T) {
+        self.pieces.push((source, destination));
+        self.sources.insert(source);
+    }
+
+    pub fn get(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(source, destination)| {
+                *destination + (value - source.start)
+            })
+    }
+
+    /// Every value of `set` mapped, whole intervals at a time.
+    pub fn apply(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut mapped = set.difference(&self.sources);
+        for (source, destination) in self.pieces.iter() {
+            for interval in set.intervals() {
+                if let Some(overlap) = interval.intersection(source) {
+                    mapped.insert(Interval::with_length(
+                        *destination + (overlap.start - source.start),
+                        overlap.len(),
+                    ));
+                }
+            }
+        }
+        mapped
+    }
+}
+
+impl<T: Bound> FromIterator<(Interval<T>, T)> for PiecewiseMap<T> {
+    fn from_iter<I: IntoIterator<Item = (Interval<T>, T)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (source, destination) in iter {
+            map.insert(source, destination);
+        }
+        map
+    }
+}
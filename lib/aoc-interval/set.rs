@@ -0,0 +1,150 @@
+use crate::{Bound, Interval};
+
+/// Values made up of intervals, kept sorted with overlapping and touching
+/// ones merged, so there is only ever one way to write down the same set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorted, none of them empty, overlapping or touching.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::from(0), |len, interval| len + interval.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|interval| interval.last())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // Everything from `first` up to `last` touches `interval`
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| {
+                Interval::new(merged.start.min(other.start), merged.end.max(other.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for interval in other.intervals.iter() {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut left, mut right) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(left), other.intervals.get(right)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                left += 1;
+            } else {
+                right += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut first_cut = 0;
+        for interval in self.intervals.iter() {
+            while other
+                .intervals
+                .get(first_cut)
+                .is_some_and(|cut| cut.end <= interval.start)
+            {
+                first_cut += 1;
+            }
+
+            let mut start = interval.start;
+            for cut in other.intervals[first_cut..].iter() {
+                if cut.start >= interval.end {
+                    break;
+                }
+                if cut.start > start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+                start = start.max(cut.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        [interval].into_iter().collect()
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval<T>> = iter
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .collect();
+        intervals.sort_by_key(|interval| interval.start);
+
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_interval::{Hyperrectangle, Interval, IntervalSet, PiecewiseMap};
+
+fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+    intervals
+        .iter()
+        .map(|(start, last)| Interval::inclusive(*start, *last))
+        .collect()
+}
+
+#[test]
+fn intervals_know_their_ends() {
+    let interval = Interval::inclusive(3, 5);
+
+    assert_eq!(interval, Interval::new(3, 6));
+    assert_eq!(interval, Interval::with_length(3, 3));
+    assert_eq!(interval.last(), 5);
+    assert_eq!(interval.len(), 3);
+    assert!(interval.contains(5) && !interval.contains(6));
+    assert!(interval.covers(&Interval::inclusive(4, 5)));
+    assert!(!interval.overlaps(&Interval::inclusive(6, 8)));
+    assert_eq!(Interval::new(5, 2).len(), 0);
+    assert_eq!(
+        interval.split_at(4),
+        (Some(Interval::new(3, 4)), Some(Interval::new(4, 6)))
+    );
+    assert_eq!(interval.split_at(9), (Some(interval), None));
+}
+
+#[test]
+fn sets_coalesce_overlapping_and_touching_intervals() {
+    let ranges = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+
+    assert_eq!(ranges.intervals(), set(&[(3, 5), (10, 20)]).intervals());
+    assert_eq!(ranges.len(), 14);
+    assert_eq!((ranges.min(), ranges.max()), (Some(3), Some(20)));
+    assert!(ranges.contains(10) && ranges.contains(20) && !ranges.contains(6));
+
+    let mut inserted = ranges.clone();
+    inserted.insert(Interval::inclusive(6, 9));
+    assert_eq!(inserted, set(&[(3, 20)]));
+}
+
+#[test]
+fn sets_combine() {
+    let a = set(&[(0, 9), (20, 29)]);
+    let b = set(&[(5, 24), (28, 40)]);
+
+    assert_eq!(a.union(&b), set(&[(0, 40)]));
+    assert_eq!(a.intersection(&b), set(&[(5, 9), (20, 24), (28, 29)]));
+    assert_eq!(a.difference(&b), set(&[(0, 4), (25, 27)]));
+    assert_eq!(b.difference(&a), set(&[(10, 19), (30, 40)]));
+    assert_eq!(
+        a.difference(&set(&[(2, 3), (5, 6)])),
+        set(&[(0, 1), (4, 4), (7, 9), (20, 29)])
+    );
+    assert!(a.difference(&a).is_empty());
+}
+
+#[test]
+fn piecewise_maps_move_whole_intervals() {
+    // seed-to-soil from 2023 day 5
+    let map: PiecewiseMap<u64> = [
+        (Interval::with_length(98, 2), 50),
+        (Interval::with_length(50, 48), 52),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(map.get(79), 81);
+    assert_eq!(map.get(99), 51);
+    assert_eq!(map.get(10), 10);
+
+    let seeds: IntervalSet<u64> = [Interval::with_length(79, 14), Interval::with_length(55, 13)]
+        .into_iter()
+        .collect();
+    let expected: IntervalSet<u64> = [Interval::with_length(81, 14), Interval::with_length(57, 13)]
+        .into_iter()
+        .collect();
+    assert_eq!(map.apply(&seeds), expected);
+
+    let straddling = IntervalSet::from(Interval::new(96, 102));
+    let expected = [
+        Interval::new(98, 100),
+        Interval::new(50, 52),
+        Interval::new(100, 102),
+    ];
+    assert_eq!(map.apply(&straddling), expected.into_iter().collect());
+}
+
+#[test]
+fn hyperrectangles_split_along_an_axis() {
+    let xmas = Hyperrectangle::new([Interval::inclusive(1_u64, 4000); 4]);
+
+    assert_eq!(xmas.volume(), 4000_u64.pow(4));
+    let (below, above) = xmas.split_at(1, 2091);
+    let (below, above) = (below.unwrap(), above.unwrap());
+    assert_eq!(below.axes()[1], Interval::inclusive(1, 2090));
+    assert_eq!(below.volume() + above.volume(), xmas.volume());
+    assert!(below.contains(&[1, 2090, 4000, 1]));
+    assert!(!below.contains(&[1, 2091, 4000, 1]));
+    assert_eq!(below.intersection(&above), None);
+    assert_eq!(xmas.intersection(&above), Some(above));
+    assert_eq!(xmas.split_at(0, 1).0, None);
+}
@@ -0,0 +1,138 @@
+use aoc_geometry::{Grid, Vector2};
+
+use crate::{Error, Result, Span};
+
+/// Non-blank lines of `input`, trimmed.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Span::new(line, index + 1, 1).trim())
+        .filter(|line| !line.is_empty())
+}
+
+/// The first non-blank line, for inputs that fit on one.
+pub fn single_line(input: &str) -> Result<Span<'_>> {
+    lines(input)
+        .next()
+        .ok_or_else(|| Error::new(1, 1, "Expected a line"))
+}
+
+/// `parse` run on every non-blank line, stopping at the first error.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parse: impl FnMut(Span<'a>) -> Result<T>,
+) -> Result<Vec<T>> {
+    lines(input).map(parse).collect()
+}
+
+/// Lines in between blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    text: &'a str,
+    lines: Vec<Span<'a>>,
+}
+
+impl<'a> Section<'a> {
+    /// From the start of the first line to the end of the last.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Trimmed, none of them blank.
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> + '_ {
+        self.lines.iter().copied()
+    }
+
+    /// Number of the first line.
+    pub fn line(&self) -> usize {
+        self.lines[0].line()
+    }
+
+    /// An error pointing at the start of the section.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        self.lines[0].error(message)
+    }
+
+    /// See [`grid`].
+    pub fn grid<T>(&self, mut from_char: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        grid_from(self.lines(), |_, c| from_char(c))
+    }
+
+    /// See [`grid_with_position`].
+    pub fn grid_with_position<T>(
+        &self,
+        from_char: impl FnMut(Vector2, char) -> Option<T>,
+    ) -> Result<Grid<T>> {
+        grid_from(self.lines(), from_char)
+    }
+}
+
+/// The sections of `input`, split up by blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut current: Option<(usize, usize, Vec<Span>)> = None;
+    let mut offset = 0;
+    for (index, raw) in input.split_inclusive('\n').enumerate() {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let span = Span::new(line, index + 1, 1).trim();
+        if span.is_empty() {
+            sections.extend(current.take());
+        } else {
+            let (_, end, lines) = current.get_or_insert_with(|| (offset, offset, Vec::new()));
+            *end = offset + line.len();
+            lines.push(span);
+        }
+        offset += raw.len();
+    }
+    sections.extend(current);
+
+    sections
+        .into_iter()
+        .map(|(start, end, lines)| Section {
+            text: &input[start..end],
+            lines,
+        })
+        .collect()
+}
+
+/// One tile per char, one row per non-blank line. `from_char` turns down
+/// the chars that have no business being in the grid by returning `None`.
+pub fn grid<T>(input: &str, mut from_char: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+    grid_from(lines(input), |_, c| from_char(c))
+}
+
+/// Like [`grid`] but also hands the tile its position.
+pub fn grid_with_position<T>(
+    input: &str,
+    from_char: impl FnMut(Vector2, char) -> Option<T>,
+) -> Result<Grid<T>> {
+    grid_from(lines(input), from_char)
+}
+
+fn grid_from<'a, T>(
+    lines: impl Iterator<Item = Span<'a>>,
+    mut from_char: impl FnMut(Vector2, char) -> Option<T>,
+) -> Result<Grid<T>> {
+    let mut width = None;
+    let mut cells = Vec::new();
+    for (y, line) in lines.enumerate() {
+        let row_width = line.as_str().chars().count();
+        let width = *width.get_or_insert(row_width);
+        if row_width != width {
+            return Err(line.error(format!("Expected {width} tiles, got {row_width}")));
+        }
+
+        for (x, c) in line.as_str().chars().enumerate() {
+            let tile = from_char(Vector2(x as isize, y as isize), c).ok_or_else(|| {
+                Error::new(line.line(), line.column() + x, format!("Unexpected {c:?}"))
+            })?;
+            cells.push(tile);
+        }
+    }
+
+    match width {
+        Some(width) => Ok(Grid::from_vec(width, cells)),
+        None => Err(Error::new(1, 1, "Expected a grid")),
+    }
+}
@@ -0,0 +1,9 @@
+mod error;
+mod input;
+mod pattern;
+mod span;
+
+pub use error::{Error, Result};
+pub use input::{grid, grid_with_position, lines, parse_lines, sections, single_line, Section};
+pub use pattern::{Captures, Pattern};
+pub use span::Span;
@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::{Result, Span};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Hole(String),
+}
+
+/// A line template such as `"x=<x>, y=<y>"`, literal text around named
+/// holes. A hole takes everything up to the literal text following it, the
+/// rest of the line when there is none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pieces: Vec<Piece>,
+}
+
+impl Pattern {
+    /// Panics on holes with nothing in between to tell them apart, or a `<`
+    /// that is never closed.
+    pub fn new(template: &str) -> Self {
+        let mut pieces = Vec::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('<') {
+            if start > 0 {
+                pieces.push(Piece::Literal(rest[..start].to_string()));
+            }
+            let end = rest[start..]
+                .find('>')
+                .unwrap_or_else(|| panic!("Unclosed hole in {template:?}"))
+                + start;
+            assert!(
+                !matches!(pieces.last(), Some(Piece::Hole(_))),
+                "Holes next to each other in {template:?}"
+            );
+            pieces.push(Piece::Hole(rest[start + 1..end].to_string()));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            pieces.push(Piece::Literal(rest.to_string()));
+        }
+
+        Self { pieces }
+    }
+
+    /// What each hole took from `span`, which has to match as a whole.
+    pub fn captures<'p, 'a>(&'p self, span: Span<'a>) -> Result<Captures<'p, 'a>> {
+        let text = span.as_str();
+        let mut captures = Vec::new();
+        let mut position = 0;
+        for (index, piece) in self.pieces.iter().enumerate() {
+            let rest = span.slice(position, text.len());
+            match piece {
+                Piece::Literal(literal) => {
+                    rest.strip_prefix(literal)?;
+                    position += literal.len();
+                }
+                Piece::Hole(name) => {
+                    let end = match self.pieces.get(index + 1) {
+                        Some(Piece::Literal(literal)) => {
+                            position + rest.split_once(literal)?.0.as_str().len()
+                        }
+                        _ => text.len(),
+                    };
+                    let value = span.slice(position, end);
+                    if value.is_empty() {
+                        return Err(value.error(format!("Expected a value for <{name}>")));
+                    }
+                    captures.push((name.as_str(), value));
+                    position = end;
+                }
+            }
+        }
+
+        let rest = span.slice(position, text.len());
+        if !rest.is_empty() {
+            return Err(rest.error(format!("Unexpected {:?}", rest.as_str())));
+        }
+        Ok(Captures { captures })
+    }
+
+    /// Every hole parsed as a `T`, in order. Panics unless there are `N`.
+    pub fn parse<T: FromStr, const N: usize>(&self, span: Span) -> Result<[T; N]> {
+        let values = self
+            .captures(span)?
+            .captures
+            .iter()
+            .map(|(_, value)| value.parse())
+            .collect::<Result<Vec<T>>>()?;
+        let count = values.len();
+        Ok(values
+            .try_into()
+            .unwrap_or_else(|_| panic!("Expected {N} holes, the pattern has {count}")))
+    }
+}
+
+/// The parts of a line a [`Pattern`] matched, by hole name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'p, 'a> {
+    captures: Vec<(&'p str, Span<'a>)>,
+}
+
+impl<'a> Captures<'_, 'a> {
+    /// Panics if the pattern has no hole called `name`.
+    pub fn get(&self, name: &str) -> Span<'a> {
+        self.captures
+            .iter()
+            .find(|(hole, _)| *hole == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("No hole called <{name}>"))
+    }
+
+    pub fn parse<T: FromStr>(&self, name: &str) -> Result<T> {
+        self.get(name).parse()
+    }
+}
@@ -0,0 +1,193 @@
+use std::{any::type_name, fmt, str::FromStr};
+
+use crate::{Error, Result};
+
+/// Part of a single input line along with where it starts, so whatever goes
+/// wrong parsing it can point at the spot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str, line: usize, column: usize) -> Self {
+        Self { text, line, column }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error pointing at the start of the span.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::new(self.line, self.column, message)
+    }
+
+    /// The bytes `start..end` of the span.
+    pub(crate) fn slice(&self, start: usize, end: usize) -> Span<'a> {
+        Span {
+            text: &self.text[start..end],
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+        }
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start, end)
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>> {
+        match self.text.starts_with(prefix) {
+            true => Ok(self.slice(prefix.len(), self.text.len())),
+            false => Err(self.error(format!("Expected {prefix:?}, got {:?}", self.text))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Span<'a>> {
+        match self.text.ends_with(suffix) {
+            true => Ok(self.slice(0, self.text.len() - suffix.len())),
+            false => Err(self.error(format!("Expected {suffix:?} at the end of {:?}", self.text))),
+        }
+    }
+
+    /// Around the first `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>)> {
+        let Some(start) = self.text.find(separator) else {
+            return Err(self.error(format!("Expected {separator:?} in {:?}", self.text)));
+        };
+        Ok((
+            self.slice(0, start),
+            self.slice(start + separator.len(), self.text.len()),
+        ))
+    }
+
+    /// The first `count` chars and the rest.
+    pub fn split_at(&self, count: usize) -> Result<(Span<'a>, Span<'a>)> {
+        let Some((middle, _)) = self
+            .text
+            .char_indices()
+            .chain([(self.text.len(), ' ')])
+            .nth(count)
+        else {
+            return Err(self.error(format!("Expected {count} chars, got {:?}", self.text)));
+        };
+        Ok((self.slice(0, middle), self.slice(middle, self.text.len())))
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        let mut start = Some(0);
+        std::iter::from_fn(move || {
+            let from = start?;
+            let Some(offset) = span.text[from..].find(separator) else {
+                start = None;
+                return Some(span.slice(from, span.text.len()));
+            };
+            start = Some(from + offset + separator.len());
+            Some(span.slice(from, from + offset))
+        })
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.text.split_whitespace().map(move |part| {
+            let start = part.as_ptr() as usize - span.text.as_ptr() as usize;
+            span.slice(start, start + part.len())
+        })
+    }
+
+    /// The trimmed sides of a `key: value` line.
+    pub fn key_value(&self) -> Result<(Span<'a>, Span<'a>)> {
+        let (key, value) = self.split_once(":")?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// The whole span as a `T`.
+    pub fn parse<T: FromStr>(&self) -> Result<T> {
+        self.text.parse().map_err(|_| {
+            let name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+            self.error(format!("Expected {name}, got {:?}", self.text))
+        })
+    }
+
+    /// Every trimmed, non-empty part between `separator`s as a `T`.
+    pub fn list<T: FromStr>(&self, separator: &'a str) -> Result<Vec<T>> {
+        self.split(separator)
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse())
+            .collect()
+    }
+
+    /// `from_char` run on every char, pointing at the first one it turns
+    /// down by returning `None`.
+    pub fn map_chars<T>(&self, mut from_char: impl FnMut(char) -> Option<T>) -> Result<Vec<T>> {
+        self.text
+            .chars()
+            .enumerate()
+            .map(|(index, c)| {
+                from_char(c).ok_or_else(|| {
+                    Error::new(self.line, self.column + index, format!("Unexpected {c:?}"))
+                })
+            })
+            .collect()
+    }
+
+    /// Every integer in the span, whatever sits in between. A `-` right in
+    /// front of the digits makes them negative unless it follows a letter or
+    /// digit, so ranges like `2-4` stay positive.
+    pub fn ints<T: FromStr>(&self) -> Result<Vec<T>> {
+        let bytes = self.text.as_bytes();
+        let mut ints = Vec::new();
+        let mut index = 0;
+        while index < bytes.len() {
+            if !bytes[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+
+            let mut start = index;
+            let signed = start > 0
+                && bytes[start - 1] == b'-'
+                && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+            if signed {
+                start -= 1;
+            }
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            ints.push(self.slice(start, index).parse()?);
+        }
+        Ok(ints)
+    }
+
+    /// Exactly `N` integers, see [`Span::ints`].
+    pub fn ints_array<T: FromStr, const N: usize>(&self) -> Result<[T; N]> {
+        let ints = self.ints()?;
+        let count = ints.len();
+        ints.try_into()
+            .map_err(|_| self.error(format!("Expected {N} numbers, got {count}")))
+    }
+}
+
+impl fmt::Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
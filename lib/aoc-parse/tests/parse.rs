@@ -0,0 +1,180 @@
+use aoc_geometry::Vector2;
+use aoc_parse::{
+    grid, grid_with_position, lines, parse_lines, sections, single_line, Error, Pattern, Span,
+};
+
+fn error(line: usize, column: usize, message: &str) -> Error {
+    Error {
+        line,
+        column,
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn lines_skip_blanks_and_keep_their_numbers() {
+    let lines: Vec<_> = lines("a\n\n  b \r\nc").collect();
+
+    assert_eq!(
+        lines,
+        [
+            Span::new("a", 1, 1),
+            Span::new("b", 3, 3),
+            Span::new("c", 4, 1)
+        ]
+    );
+}
+
+#[test]
+fn parse_errors_point_at_the_value() {
+    let result = parse_lines("1 2\n3 x", |line| line.list::<u32>(" "));
+
+    assert_eq!(result, Err(error(2, 3, "Expected u32, got \"x\"")));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Line 2, column 3: Expected u32, got \"x\""
+    );
+}
+
+#[test]
+fn lists_split_on_separators() {
+    let line = Span::new("7, 8,  9", 1, 1);
+
+    assert_eq!(line.list::<i32>(","), Ok(vec![7, 8, 9]));
+    assert_eq!(line.split(",").nth(2), Some(Span::new("  9", 1, 6)));
+    assert_eq!(
+        Span::new("1  2 3", 1, 1).split_whitespace().nth(1),
+        Some(Span::new("2", 1, 4))
+    );
+}
+
+#[test]
+fn ints_ignore_what_is_in_between() {
+    assert_eq!(
+        Span::new("p=0,4 v=3,-3", 1, 1).ints::<i64>(),
+        Ok(vec![0, 4, 3, -3])
+    );
+    assert_eq!(
+        Span::new("2-4,6-8", 1, 1).ints::<u32>(),
+        Ok(vec![2, 4, 6, 8])
+    );
+    assert_eq!(Span::new("no numbers", 1, 1).ints::<u32>(), Ok(vec![]));
+}
+
+#[test]
+fn chars_point_at_the_one_that_did_not_fit() {
+    let line = single_line("\n  R12").unwrap();
+    let (direction, steps) = line.split_at(1).unwrap();
+
+    assert_eq!(direction, Span::new("R", 2, 3));
+    assert_eq!(steps.parse::<u8>(), Ok(12));
+    assert_eq!(
+        line.map_chars(|c| c.to_digit(10)),
+        Err(error(2, 3, "Unexpected 'R'"))
+    );
+    assert_eq!(single_line(" \n"), Err(error(1, 1, "Expected a line")));
+}
+
+#[test]
+fn key_values_are_trimmed() {
+    let (key, value) = Span::new("Time:   7  15", 1, 1).key_value().unwrap();
+
+    assert_eq!(key.as_str(), "Time");
+    assert_eq!(value, Span::new("7  15", 1, 9));
+    assert_eq!(
+        Span::new("Time", 4, 1).key_value(),
+        Err(error(4, 1, "Expected \":\" in \"Time\""))
+    );
+}
+
+#[test]
+fn prefixes_and_suffixes_have_to_be_there() {
+    let line = Span::new("Game 12:", 1, 1);
+
+    assert_eq!(
+        line.strip_prefix("Game ")
+            .and_then(|rest| rest.strip_suffix(":")),
+        Ok(Span::new("12", 1, 6))
+    );
+    assert_eq!(
+        line.strip_prefix("Round"),
+        Err(error(1, 1, "Expected \"Round\", got \"Game 12:\""))
+    );
+}
+
+#[test]
+fn sections_split_on_blank_lines() {
+    let sections = sections("a\nb\n\n\nc\n  \nd\n");
+
+    assert_eq!(sections.len(), 3);
+    assert_eq!(sections[0].as_str(), "a\nb");
+    assert_eq!(sections[1].line(), 5);
+    assert_eq!(
+        sections[2].lines().collect::<Vec<_>>(),
+        [Span::new("d", 7, 1)]
+    );
+}
+
+#[test]
+fn grids_turn_down_unknown_tiles_and_ragged_rows() {
+    let wall = |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    };
+
+    let walls = grid("#.\n.#\n", wall).unwrap();
+    assert_eq!(walls.get(&Vector2(1, 1)), Some(&true));
+    assert_eq!(grid("#.\n.x", wall), Err(error(2, 2, "Unexpected 'x'")));
+    assert_eq!(
+        grid("#.\n.", wall),
+        Err(error(2, 1, "Expected 2 tiles, got 1"))
+    );
+    assert_eq!(grid("\n", wall), Err(error(1, 1, "Expected a grid")));
+}
+
+#[test]
+fn grids_in_sections_know_their_lines() {
+    let sections = sections("#\n\n.#\n#x");
+    let mut start = None;
+    let found = sections[1].grid_with_position(|position, c| {
+        if c == '.' {
+            start = Some(position);
+        }
+        (c != 'x').then_some(c)
+    });
+
+    assert_eq!(found, Err(error(4, 2, "Unexpected 'x'")));
+    assert_eq!(start, Some(Vector2(0, 0)));
+    assert!(grid_with_position("ab", |_, c| Some(c)).is_ok());
+}
+
+#[test]
+fn patterns_capture_holes_by_name() {
+    let pattern = Pattern::new("Button <name>: X+<x>, Y+<y>");
+    let line = Span::new("Button A: X+94, Y+34", 1, 1);
+    let captures = pattern.captures(line).unwrap();
+
+    assert_eq!(captures.get("name").as_str(), "A");
+    assert_eq!(captures.parse::<i64>("y"), Ok(34));
+    assert_eq!(pattern.parse::<String, 3>(line).unwrap()[1], "94");
+}
+
+#[test]
+fn patterns_point_at_what_did_not_match() {
+    let pattern = Pattern::new("x=<x>, y=<y>");
+
+    assert_eq!(pattern.parse(Span::new("x=-1, y=5", 1, 1)), Ok([-1, 5]));
+    assert_eq!(
+        pattern.parse::<i32, 2>(Span::new("x=1; y=5", 3, 1)),
+        Err(error(3, 3, "Expected \", y=\" in \"1; y=5\""))
+    );
+    assert_eq!(
+        pattern.parse::<i32, 2>(Span::new("x=1, y=z", 2, 1)),
+        Err(error(2, 8, "Expected i32, got \"z\""))
+    );
+    assert_eq!(
+        Pattern::new("<a> apples").parse::<u8, 1>(Span::new("3 apples!", 1, 1)),
+        Err(error(1, 9, "Unexpected \"!\""))
+    );
+}
@@ -0,0 +1,124 @@
+use crate::Simulation;
+
+/// Steps from `start` on repeat every `length` steps, fingerprint wise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before the end of the first time around that looks the same
+    /// as `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    /// How many times around the loop `step` is past its equivalent step.
+    pub fn laps(&self, step: usize) -> usize {
+        step.saturating_sub(self.start) / self.length
+    }
+}
+
+fn stepped<S: Simulation + Clone>(simulation: &S, steps: usize) -> S {
+    let mut simulation = simulation.clone();
+    for _ in 0..steps {
+        simulation.step();
+    }
+    simulation
+}
+
+/// Start of the cycle once its length is known, by running two copies
+/// `length` steps apart until they line up.
+fn cycle_start<S: Simulation + Clone>(simulation: &S, length: usize) -> Cycle {
+    let mut tortoise = simulation.clone();
+    let mut hare = stepped(simulation, length);
+    let mut start = 0;
+    while tortoise.fingerprint() != hare.fingerprint() {
+        tortoise.step();
+        hare.step();
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+/// Brent's cycle detection: the hare runs ahead in stretches doubling in
+/// length, the tortoise teleporting to it at the start of each, until the
+/// hare runs into it. Clones the simulation a handful of times and never
+/// stores fingerprints. Never returns for simulations that don't repeat.
+pub fn brent<S: Simulation + Clone>(simulation: &S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = simulation.fingerprint();
+    let mut hare = simulation.clone();
+    hare.step();
+    let mut fingerprint = hare.fingerprint();
+    while tortoise != fingerprint {
+        if power == length {
+            tortoise = fingerprint;
+            power *= 2;
+            length = 0;
+        }
+        hare.step();
+        fingerprint = hare.fingerprint();
+        length += 1;
+    }
+
+    cycle_start(simulation, length)
+}
+
+/// Floyd's cycle detection: the hare runs twice as fast as the tortoise
+/// until they meet somewhere in the cycle. Brent's usually gets there in
+/// fewer steps. Never returns for simulations that don't repeat.
+pub fn floyd<S: Simulation + Clone>(simulation: &S) -> Cycle {
+    let mut tortoise = simulation.clone();
+    let mut hare = simulation.clone();
+    loop {
+        tortoise.step();
+        hare.step();
+        hare.step();
+        if tortoise.fingerprint() == hare.fingerprint() {
+            break;
+        }
+    }
+
+    let mut length = 1;
+    hare = tortoise.clone();
+    hare.step();
+    while tortoise.fingerprint() != hare.fingerprint() {
+        hare.step();
+        length += 1;
+    }
+
+    cycle_start(simulation, length)
+}
+
+/// The simulation after `steps` steps, skipping every full time around its
+/// cycle. Only exact when the fingerprint covers the whole state.
+pub fn state_at<S: Simulation + Clone>(simulation: &S, steps: usize) -> S {
+    let cycle = brent(simulation);
+    stepped(simulation, cycle.equivalent_step(steps))
+}
+
+/// The metric after `steps` steps. Every time around the cycle adds the same
+/// to it, nothing for metrics that only depend on the fingerprint, the height
+/// of a growing tower for ones that don't.
+pub fn metric_at<S: Simulation + Clone>(simulation: &S, steps: usize) -> i64 {
+    let cycle = brent(simulation);
+    let mut simulation = simulation.clone();
+    let mut metrics = vec![simulation.metric()];
+    for _ in 0..steps.min(cycle.start + cycle.length) {
+        simulation.step();
+        metrics.push(simulation.metric());
+    }
+    if steps < metrics.len() {
+        return metrics[steps];
+    }
+
+    let per_lap = metrics[cycle.start + cycle.length] - metrics[cycle.start];
+    metrics[cycle.equivalent_step(steps)] + cycle.laps(steps) as i64 * per_lap
+}
@@ -0,0 +1,50 @@
+use crate::Simulation;
+
+/// A metric going up by `per_step` every step from `step` on, where it was
+/// `metric`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Drift {
+    pub step: usize,
+    pub metric: i64,
+    pub per_step: i64,
+}
+
+impl Drift {
+    /// The metric at any step after [`Drift::step`].
+    pub fn metric_at(&self, step: usize) -> i64 {
+        self.metric + (step as i64 - self.step as i64) * self.per_step
+    }
+}
+
+/// Steps `simulation` until its metric changed by the same amount
+/// `confirmations` steps in a row, for simulations settling into a pattern
+/// that keeps moving, so the fingerprint never repeats but the metric grows
+/// linearly. Leaves `simulation` at [`Drift::step`]. Never returns if the
+/// metric never settles.
+pub fn linear_drift<S: Simulation>(simulation: &mut S, confirmations: usize) -> Drift {
+    let mut step = 0;
+    let mut metric = simulation.metric();
+    let mut per_step = None;
+    let mut confirmed = 0;
+    loop {
+        simulation.step();
+        step += 1;
+        let next = simulation.metric();
+        let change = next - metric;
+        metric = next;
+
+        if per_step == Some(change) {
+            confirmed += 1;
+        } else {
+            per_step = Some(change);
+            confirmed = 1;
+        }
+        if confirmed >= confirmations {
+            return Drift {
+                step,
+                metric,
+                per_step: change,
+            };
+        }
+    }
+}
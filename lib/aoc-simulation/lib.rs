@@ -0,0 +1,21 @@
+mod cycle;
+mod drift;
+
+pub use cycle::{brent, floyd, metric_at, state_at, Cycle};
+pub use drift::{linear_drift, Drift};
+
+/// Something that moves forward one step at a time, for puzzles asking what
+/// it looks like after far too many steps to simulate them all.
+pub trait Simulation {
+    /// Equal for states that go on the same way from there. Doesn't need to
+    /// be the whole state, [`Simulation::metric`] may keep drifting while it
+    /// repeats.
+    type Fingerprint: Eq;
+
+    fn step(&mut self);
+
+    fn fingerprint(&self) -> Self::Fingerprint;
+
+    /// The number the puzzle asks for.
+    fn metric(&self) -> i64;
+}
@@ -0,0 +1,124 @@
+use aoc_simulation::{brent, floyd, linear_drift, metric_at, state_at, Cycle, Drift, Simulation};
+
+/// Walks `0, 1, 2, ...` until `lead`, then loops through the next `length`
+/// values forever, counting every step.
+#[derive(Debug, Clone)]
+struct Rho {
+    value: usize,
+    lead: usize,
+    length: usize,
+    steps: i64,
+}
+
+impl Rho {
+    fn new(lead: usize, length: usize) -> Self {
+        Self {
+            value: 0,
+            lead,
+            length,
+            steps: 0,
+        }
+    }
+}
+
+impl Simulation for Rho {
+    type Fingerprint = usize;
+
+    fn step(&mut self) {
+        self.value += 1;
+        if self.value == self.lead + self.length {
+            self.value = self.lead;
+        }
+        self.steps += 1;
+    }
+
+    fn fingerprint(&self) -> usize {
+        self.value
+    }
+
+    fn metric(&self) -> i64 {
+        self.value as i64 * 10 + self.steps
+    }
+}
+
+/// A glider moving right by one every step, growing a tail of 3 from step 4.
+struct Glider {
+    step: i64,
+}
+
+impl Simulation for Glider {
+    type Fingerprint = i64;
+
+    fn step(&mut self) {
+        self.step += 1;
+    }
+
+    fn fingerprint(&self) -> i64 {
+        self.step
+    }
+
+    fn metric(&self) -> i64 {
+        match self.step {
+            ..=3 => self.step * self.step,
+            _ => 9 + (self.step - 3) * 3,
+        }
+    }
+}
+
+#[test]
+fn brent_and_floyd_find_the_same_cycle() {
+    for (lead, length) in [(0, 1), (0, 7), (5, 1), (3, 4), (12, 30)] {
+        let rho = Rho::new(lead, length);
+        let cycle = Cycle {
+            start: lead,
+            length,
+        };
+
+        assert_eq!(brent(&rho), cycle);
+        assert_eq!(floyd(&rho), cycle);
+    }
+}
+
+#[test]
+fn equivalent_steps_wrap_around_the_cycle() {
+    let cycle = Cycle {
+        start: 3,
+        length: 4,
+    };
+
+    assert_eq!(cycle.equivalent_step(2), 2);
+    assert_eq!(cycle.equivalent_step(7), 3);
+    assert_eq!(cycle.equivalent_step(1_000_000_001), 5);
+    assert_eq!(cycle.laps(2), 0);
+    assert_eq!(cycle.laps(12), 2);
+}
+
+#[test]
+fn state_and_metric_at_skip_ahead() {
+    let rho = Rho::new(3, 4);
+    let steps = 1_000_000_001;
+
+    assert_eq!(state_at(&rho, steps).value, 5);
+    assert_eq!(metric_at(&rho, steps), 5 * 10 + steps as i64);
+    assert_eq!(metric_at(&rho, 2), 2 * 10 + 2);
+}
+
+#[test]
+fn linear_drift_waits_for_confirmations() {
+    let mut glider = Glider { step: 0 };
+    let drift = linear_drift(&mut glider, 3);
+
+    assert_eq!(
+        drift,
+        Drift {
+            step: 6,
+            metric: 18,
+            per_step: 3
+        }
+    );
+    assert_eq!(glider.step, 6);
+    assert_eq!(
+        drift.metric_at(50_000_000_000),
+        9 + (50_000_000_000 - 3) * 3
+    );
+}
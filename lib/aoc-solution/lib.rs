@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+/// The result of a part. Most days end in a number, a few spell out a word or
+/// a list instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    String(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_string())
+    }
+}
+
+/// A single day of a year. Both parts take the whole puzzle input and leave
+/// printing to the caller, so they can run back to back on the same input.
+pub trait Solution {
+    /// The `--- Day N: Title ---` line printed before the results.
+    fn title(&self) -> &'static str;
+    fn part1(&self, input: &str) -> Option<Answer>;
+    fn part2(&self, input: &str) -> Option<Answer>;
+}
+
+/// Runs the part given as the first argument, or both parts when there is
+/// none, printing the results the way every day always has.
+pub fn run(solution: &dyn Solution, input: &str, part: Option<&str>) {
+    println!("{}", solution.title());
+    if let Some(part) = part {
+        if let Some(result) = match part {
+            "1" => solution.part1(input),
+            "2" => solution.part2(input),
+            _ => panic!("💥 Invalid part number: {part}"),
+        } {
+            println!("🎁 Result part {part}: {result}");
+        }
+    } else {
+        if let Some(result_1) = solution.part1(input) {
+            println!("🎁 Result part 1: {result_1}");
+        }
+        if let Some(result_2) = solution.part2(input) {
+            println!("🎁 Result part 2: {result_2}");
+        }
+    }
+}
+
+/// Entry point of a day binary, reading the whole input from stdin once so
+/// that both parts see it.
+pub fn main(solution: &dyn Solution) {
+    let input = std::io::read_to_string(std::io::stdin()).expect("Unable to read stdin");
+    run(solution, &input, std::env::args().nth(1).as_deref());
+}
@@ -0,0 +1,42 @@
+use aoc_solution::{Answer, Solution};
+
+/// Sums the numbers of the input, and joins them with dashes.
+struct Numbers;
+
+impl Solution for Numbers {
+    fn title(&self) -> &'static str {
+        "--- Day 0: Numbers ---"
+    }
+
+    fn part1(&self, input: &str) -> Option<Answer> {
+        let sum: u64 = input
+            .lines()
+            .map(|line| line.parse::<u64>().ok())
+            .sum::<Option<u64>>()?;
+        Some(sum.into())
+    }
+
+    fn part2(&self, input: &str) -> Option<Answer> {
+        Some(input.lines().collect::<Vec<_>>().join("-").into())
+    }
+}
+
+#[test]
+fn parts_can_run_on_the_same_input() {
+    let input = "1\n2\n3\n";
+
+    assert_eq!(Numbers.part1(input), Some(Answer::Integer(6)));
+    assert_eq!(
+        Numbers.part2(input),
+        Some(Answer::String("1-2-3".to_string()))
+    );
+    assert_eq!(Numbers.part1(input), Some(Answer::Integer(6)));
+    assert_eq!(Numbers.part1("x"), None);
+}
+
+#[test]
+fn answers_display_as_their_value() {
+    assert_eq!(Answer::from(-42i32).to_string(), "-42");
+    assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    assert_eq!(Answer::from("1,0,2").to_string(), "1,0,2");
+}
@@ -0,0 +1,121 @@
+use std::{collections::HashMap, fmt::Display, fs};
+
+use aoc_solution::Answer;
+use toml::{Table, Value};
+
+use crate::{days::Day, read_input, root, solve};
+
+/// Inputs every day is checked against, `test.txt` and `input.txt`.
+pub const INPUTS: [&str; 2] = ["test", "input"];
+
+/// Recorded answers of one year, read from `YEAR/answers.toml` where each
+/// `[day.part]` table maps an input name to its expected answer:
+///
+/// ```toml
+/// [17.1]
+/// test = "4,6,3,5,6,3,5,2,1,0"
+/// input = "1,0,2,0,5,7,2,1,3"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, u8, String), Answer>,
+}
+
+impl Answers {
+    /// Answers of `year`, empty when the year has no `answers.toml` yet.
+    pub fn read(year: u16) -> Result<Self, String> {
+        let path = root().join(year.to_string()).join("answers.toml");
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: Table = text.parse().map_err(|error| format!("{error}"))?;
+        let mut answers = Self::default();
+
+        for (day, parts) in table {
+            let day: u8 = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+            let parts = parts
+                .as_table()
+                .ok_or(format!("Day {day} is not a table"))?;
+
+            for (part, inputs) in parts {
+                let part: u8 = part.parse().map_err(|_| format!("Invalid part: {part}"))?;
+                let inputs = inputs
+                    .as_table()
+                    .ok_or(format!("Day {day} part {part} is not a table"))?;
+
+                for (input, value) in inputs {
+                    let answer = match value {
+                        Value::Integer(value) => Answer::from(*value),
+                        Value::String(value) => Answer::from(value.as_str()),
+                        _ => return Err(format!("Invalid answer for {day}.{part}.{input}")),
+                    };
+                    answers.expected.insert((day, part, input.clone()), answer);
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&Answer> {
+        self.expected.get(&(day, part, input.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: Option<Answer>,
+    },
+    /// No answer recorded, or no input to run it on.
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "fail, expected {expected} but got {actual}"),
+            Status::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "fail, expected {expected} but got nothing"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+/// Runs `part` of `day` on the input named `input` and compares the result
+/// with the recorded answer. Parts without an answer are not run at all.
+pub fn check(day: &Day, answers: &Answers, part: u8, input: &str) -> Status {
+    let Some(expected) = answers.get(day.day, part, input) else {
+        return Status::Missing;
+    };
+    let Some(input) = read_input(day, input) else {
+        return Status::Missing;
+    };
+
+    let actual = solve(day, part, &input);
+
+    // Compared as text, big answers may be recorded as strings since TOML
+    // integers stop at i64
+    if actual
+        .as_ref()
+        .is_some_and(|actual| actual.to_string() == expected.to_string())
+    {
+        Status::Pass
+    } else {
+        Status::Fail {
+            expected: expected.clone(),
+            actual,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// How long to keep running a part before reporting its timing.
+#[derive(Debug, Clone)]
+pub struct Bench {
+    /// Runs discarded before sampling starts, there is always at least one.
+    pub warmup: Duration,
+    /// Sampling stops once this is spent and `min_runs` are done.
+    pub budget: Duration,
+    pub min_runs: usize,
+    pub max_runs: usize,
+}
+
+impl Default for Bench {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(200),
+            budget: Duration::from_secs(2),
+            min_runs: 3,
+            max_runs: 100,
+        }
+    }
+}
+
+impl Bench {
+    pub fn measure<T>(&self, mut run: impl FnMut() -> T) -> Timing {
+        let start = Instant::now();
+        loop {
+            std::hint::black_box(run());
+            if start.elapsed() >= self.warmup {
+                break;
+            }
+        }
+
+        let start = Instant::now();
+        let mut samples = Vec::new();
+        while samples.len() < self.min_runs
+            || (samples.len() < self.max_runs && start.elapsed() < self.budget)
+        {
+            let run_start = Instant::now();
+            std::hint::black_box(run());
+            samples.push(run_start.elapsed());
+        }
+        Timing::from_samples(samples)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "No samples to time");
+        samples.sort();
+
+        let percentile = |p: usize| samples[(samples.len() * p).div_ceil(100) - 1];
+        Self {
+            runs: samples.len(),
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+        }
+    }
+}
+
+/// One timed part, as stored in the history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub timing: Timing,
+}
+
+const HEADER: &str = "commit,year,day,part,input,runs,min_ns,median_ns,p95_ns";
+
+impl Record {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            self.commit,
+            self.year,
+            self.day,
+            self.part,
+            self.input,
+            self.timing.runs,
+            self.timing.min.as_nanos(),
+            self.timing.median.as_nanos(),
+            self.timing.p95.as_nanos(),
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split(',').collect();
+        let [commit, year, day, part, input, runs, min, median, p95] = fields[..] else {
+            return None;
+        };
+        let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+
+        Some(Self {
+            commit: commit.to_string(),
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            input: input.to_string(),
+            timing: Timing {
+                runs: runs.parse().ok()?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                p95: nanos(p95)?,
+            },
+        })
+    }
+}
+
+/// Every timing recorded so far, oldest first, kept as CSV so runs of
+/// different commits can be compared.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Reads the history at `path`, starting a new one when there is none.
+    pub fn read(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let records = fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .skip(1)
+            .filter_map(Record::from_csv)
+            .collect();
+        Self { path, records }
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Latest timing of the same part and input made on another commit.
+    pub fn previous(&self, record: &Record) -> Option<&Record> {
+        self.records.iter().rev().find(|previous| {
+            previous.commit != record.commit
+                && previous.year == record.year
+                && previous.day == record.day
+                && previous.part == record.part
+                && previous.input == record.input
+        })
+    }
+
+    pub fn append(&mut self, records: Vec<Record>) -> io::Result<()> {
+        let is_new = !self.path.exists();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        if is_new {
+            writeln!(file, "{HEADER}")?;
+        }
+        for record in records.iter() {
+            writeln!(file, "{}", record.to_csv())?;
+        }
+        self.records.extend(records);
+        Ok(())
+    }
+}
+
+/// Short hash of the checked out commit, marked `-dirty` when there are
+/// uncommitted changes.
+pub fn git_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(crate::root())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{commit}-dirty"),
+        _ => commit,
+    }
+}
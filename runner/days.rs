@@ -0,0 +1,139 @@
+use aoc_solution::Solution;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// Folder of the day relative to the repository root, holding its inputs.
+    pub path: &'static str,
+    pub solution: &'static dyn Solution,
+}
+
+/// Calls `$callback!` with a `(year, day, path, crate)` tuple for every day
+/// the runner knows about, sorted by year and day. Days commented out are
+/// missing from the dependencies, see `Cargo.toml`.
+#[macro_export]
+macro_rules! for_each_day {
+    ($callback:ident) => {
+        $callback! {
+            (2018, 1, "2018/days/01", aoc_2018_1),
+            (2018, 2, "2018/days/02", aoc_2018_2),
+            (2018, 3, "2018/days/03", aoc_2018_3),
+            (2018, 4, "2018/days/04", aoc_2018_4),
+            (2018, 5, "2018/days/05", aoc_2018_5),
+            (2018, 6, "2018/days/06", aoc_2018_6),
+            (2018, 7, "2018/days/07", aoc_2018_7),
+            (2018, 8, "2018/days/08", aoc_2018_8),
+            (2018, 9, "2018/days/09", aoc_2018_9),
+            (2018, 10, "2018/days/10", aoc_2018_10),
+            (2018, 11, "2018/days/11", aoc_2018_11),
+            (2018, 12, "2018/days/12", aoc_2018_12),
+            (2018, 13, "2018/days/13", aoc_2018_13),
+            (2018, 14, "2018/days/14", aoc_2018_14),
+            (2018, 15, "2018/days/15", aoc_2018_15),
+            (2018, 16, "2018/days/16", aoc_2018_16),
+            (2018, 17, "2018/days/17", aoc_2018_17),
+            (2018, 18, "2018/days/18", aoc_2018_18),
+            (2018, 19, "2018/days/19", aoc_2018_19),
+            (2018, 20, "2018/days/20", aoc_2018_20),
+            (2018, 21, "2018/days/21", aoc_2018_21),
+            (2018, 22, "2018/days/22", aoc_2018_22),
+            (2022, 1, "2022/days/day-1", aoc_2022_day_1),
+            (2022, 2, "2022/days/day-2", aoc_2022_day_2),
+            (2022, 4, "2022/days/day-4", aoc_2022_day_4),
+            (2022, 5, "2022/days/day-5", aoc_2022_day_5),
+            (2022, 6, "2022/days/day-6", aoc_2022_day_6),
+            (2022, 9, "2022/days/day-9", aoc_2022_day_9),
+            (2022, 10, "2022/days/day-10", aoc_2022_day_10),
+            (2022, 11, "2022/days/day-11", aoc_2022_day_11),
+            (2022, 12, "2022/days/day-12", aoc_2022_day_12),
+            (2022, 13, "2022/days/day-13", aoc_2022_day_13),
+            (2022, 14, "2022/days/day-14", aoc_2022_day_14),
+            (2022, 15, "2022/days/day-15", aoc_2022_day_15),
+            (2022, 16, "2022/days/day-16", aoc_2022_day_16),
+            (2022, 17, "2022/days/day-17", aoc_2022_day_17),
+            (2022, 18, "2022/days/day-18", aoc_2022_day_18),
+            (2022, 19, "2022/days/day-19", aoc_2022_day_19),
+            (2022, 20, "2022/days/day-20", aoc_2022_day_20),
+            (2022, 21, "2022/days/day-21", aoc_2022_day_21),
+            (2022, 22, "2022/days/day-22", aoc_2022_day_22),
+            (2022, 23, "2022/days/day-23", aoc_2022_day_23),
+            (2022, 24, "2022/days/day-24", aoc_2022_day_24),
+            (2022, 25, "2022/days/day-25", aoc_2022_day_25),
+            (2023, 1, "2023/days/1", aoc_2023_1),
+            (2023, 2, "2023/days/2", aoc_2023_2),
+            (2023, 3, "2023/days/3", aoc_2023_3),
+            (2023, 4, "2023/days/4", aoc_2023_4),
+            (2023, 5, "2023/days/5", aoc_2023_5),
+            (2023, 6, "2023/days/6", aoc_2023_6),
+            (2023, 7, "2023/days/7", aoc_2023_7),
+            (2023, 8, "2023/days/8", aoc_2023_8),
+            (2023, 9, "2023/days/9", aoc_2023_9),
+            (2023, 10, "2023/days/10", aoc_2023_10),
+            // (2023, 11, "2023/days/11", aoc_2023_11),
+            (2023, 12, "2023/days/12", aoc_2023_12),
+            (2023, 13, "2023/days/13", aoc_2023_13),
+            (2023, 14, "2023/days/14", aoc_2023_14),
+            (2023, 15, "2023/days/15", aoc_2023_15),
+            (2023, 16, "2023/days/16", aoc_2023_16),
+            (2023, 17, "2023/days/17", aoc_2023_17),
+            (2023, 18, "2023/days/18", aoc_2023_18),
+            (2023, 19, "2023/days/19", aoc_2023_19),
+            (2023, 20, "2023/days/20", aoc_2023_20),
+            (2023, 21, "2023/days/21", aoc_2023_21),
+            (2023, 22, "2023/days/22", aoc_2023_22),
+            (2023, 23, "2023/days/23", aoc_2023_23),
+            (2023, 24, "2023/days/24", aoc_2023_24),
+            (2023, 25, "2023/days/25", aoc_2023_25),
+            (2024, 1, "2024/days/01", aoc_2024_1),
+            (2024, 2, "2024/days/02", aoc_2024_2),
+            (2024, 3, "2024/days/03", aoc_2024_3),
+            (2024, 4, "2024/days/04", aoc_2024_4),
+            (2024, 5, "2024/days/05", aoc_2024_5),
+            (2024, 6, "2024/days/06", aoc_2024_6),
+            (2024, 7, "2024/days/07", aoc_2024_7),
+            (2024, 8, "2024/days/08", aoc_2024_8),
+            (2024, 9, "2024/days/09", aoc_2024_9),
+            (2024, 10, "2024/days/10", aoc_2024_10),
+            (2024, 11, "2024/days/11", aoc_2024_11),
+            (2024, 12, "2024/days/12", aoc_2024_12),
+            (2024, 13, "2024/days/13", aoc_2024_13),
+            (2024, 14, "2024/days/14", aoc_2024_14),
+            (2024, 15, "2024/days/15", aoc_2024_15),
+            (2024, 16, "2024/days/16", aoc_2024_16),
+            (2024, 17, "2024/days/17", aoc_2024_17),
+            (2024, 18, "2024/days/18", aoc_2024_18),
+            (2024, 19, "2024/days/19", aoc_2024_19),
+            (2024, 20, "2024/days/20", aoc_2024_20),
+            (2024, 21, "2024/days/21", aoc_2024_21),
+            (2024, 22, "2024/days/22", aoc_2024_22),
+            (2024, 23, "2024/days/23", aoc_2024_23),
+            (2024, 24, "2024/days/24", aoc_2024_24),
+            (2024, 25, "2024/days/25", aoc_2024_25),
+            (2025, 1, "2025/days/01", aoc_2025_1),
+            (2025, 2, "2025/days/02", aoc_2025_2),
+            (2025, 3, "2025/days/03", aoc_2025_3),
+            (2025, 4, "2025/days/04", aoc_2025_4),
+            (2025, 5, "2025/days/05", aoc_2025_5),
+            (2025, 6, "2025/days/06", aoc_2025_6),
+            (2025, 7, "2025/days/07", aoc_2025_7),
+            (2025, 8, "2025/days/08", aoc_2025_8),
+            (2025, 9, "2025/days/09", aoc_2025_9),
+            // (2025, 10, "2025/days/10", aoc_2025_10),
+            (2025, 11, "2025/days/11", aoc_2025_11),
+            (2025, 12, "2025/days/12", aoc_2025_12),
+        }
+    };
+}
+
+macro_rules! day_table {
+    ($(($year:literal, $day:literal, $path:literal, $krate:ident)),* $(,)?) => {
+        &[$(Day {
+            year: $year,
+            day: $day,
+            path: $path,
+            solution: &$krate::Day,
+        }),*]
+    };
+}
+
+pub const DAYS: &[Day] = for_each_day!(day_table);
@@ -0,0 +1,109 @@
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/JHelar/advent-of-code";
+
+/// Talks to the Advent of Code site, or anything serving the same paths.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    token: Option<String>,
+}
+
+impl Client {
+    /// `token` is the session cookie, only needed to download inputs.
+    pub fn new(base_url: &str, token: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+        }
+    }
+
+    /// Client for `AOC_URL` if set, with the token from `AOC_TOKEN` or the
+    /// `.token` file in the repository root.
+    pub fn from_env(root: &Path) -> Self {
+        let base_url = std::env::var("AOC_URL").unwrap_or(AOC_URL.to_string());
+        let token = std::env::var("AOC_TOKEN")
+            .ok()
+            .or_else(|| fs::read_to_string(root.join(".token")).ok())
+            .map(|token| token.trim().to_string());
+        Self::new(&base_url, token)
+    }
+
+    fn get(&self, path: &str, with_token: bool) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+        let mut request = ureq::get(&url).set("User-Agent", USER_AGENT);
+        if with_token {
+            let token = self
+                .token
+                .as_ref()
+                .ok_or("Missing session token, set AOC_TOKEN or write it to .token")?;
+            request = request.set("Cookie", &format!("session={token}"));
+        }
+
+        let response = request
+            .call()
+            .map_err(|error| format!("Unable to fetch {url}: {error}"))?;
+        let mut body = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .map_err(|error| format!("Unable to read {url}: {error}"))?;
+        Ok(body)
+    }
+
+    /// The `--- Day N: Title ---` heading of the puzzle page.
+    pub fn title(&self, year: u16, day: u8) -> Result<String, String> {
+        let page = self.get(&format!("/{year}/day/{day}"), false)?;
+        let start = page.find("<h2>").ok_or("Unable to find the day title")? + "<h2>".len();
+        let end = page[start..]
+            .find("</h2>")
+            .ok_or("Unable to find the day title")?;
+        Ok(page[start..start + end].to_string())
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.get(&format!("/{year}/day/{day}/input"), true)
+    }
+}
+
+/// Downloaded inputs, so each one is only requested once.
+#[derive(Debug, Clone)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    /// Cached input of the day, downloading it with `client` on a miss.
+    pub fn get_or_fetch(&self, client: &Client, year: u16, day: u8) -> Result<String, String> {
+        if let Some(input) = self.get(year, day) {
+            return Ok(input);
+        }
+
+        let input = client.input(year, day)?;
+        let path = self.path(year, day);
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, &input))
+            .map_err(|error| format!("Unable to cache {}: {error}", path.display()))?;
+        Ok(input)
+    }
+}
@@ -0,0 +1,32 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod fetch;
+pub mod scaffold;
+
+use std::{fs, path::Path};
+
+use aoc_solution::Answer;
+
+use days::Day;
+
+/// The repository root, holding one folder per year.
+pub fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Runner lives inside the repository")
+}
+
+/// Reads `<name>.txt` from the folder of `day`, `None` when there is no such
+/// input.
+pub fn read_input(day: &Day, name: &str) -> Option<String> {
+    fs::read_to_string(root().join(day.path).join(format!("{name}.txt"))).ok()
+}
+
+pub fn solve(day: &Day, part: u8, input: &str) -> Option<Answer> {
+    match part {
+        1 => day.solution.part1(input),
+        2 => day.solution.part2(input),
+        _ => panic!("💥 Invalid part number: {part}"),
+    }
+}
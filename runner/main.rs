@@ -0,0 +1,280 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    panic::{self, AssertUnwindSafe},
+    process,
+    time::Duration,
+};
+
+use aoc::{
+    answers::{self, Answers, Status, INPUTS},
+    bench::{self, Bench, History, Record},
+    days::{Day, DAYS},
+    fetch::{Client, InputCache},
+    read_input, root, scaffold, solve,
+};
+
+const USAGE: &str = "Usage:
+  aoc run [YEAR [DAY] | --all] [--part 1|2] [--input NAME] [--bench]
+  aoc check [YEAR [DAY] | --all] [--part 1|2] [--input NAME]
+  aoc new YEAR DAY
+  aoc fetch YEAR DAY";
+
+#[derive(Debug, Default)]
+struct Options {
+    year: Option<u16>,
+    day: Option<u8>,
+    all: bool,
+    bench: bool,
+    part: Option<String>,
+    input: Option<String>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => options.all = true,
+                "--bench" => options.bench = true,
+                "--part" => {
+                    let part = args.next().ok_or("Missing value for --part")?;
+                    if part != "1" && part != "2" {
+                        return Err(format!("Invalid part number: {part}"));
+                    }
+                    options.part = Some(part);
+                }
+                "--input" => {
+                    options.input = Some(args.next().ok_or("Missing value for --input")?);
+                }
+                _ if options.year.is_none() => {
+                    options.year = Some(arg.parse().map_err(|_| format!("Invalid year: {arg}"))?);
+                }
+                _ if options.day.is_none() => {
+                    options.day = Some(arg.parse().map_err(|_| format!("Invalid day: {arg}"))?);
+                }
+                _ => return Err(format!("Unexpected argument: {arg}")),
+            }
+        }
+
+        if options.all == options.year.is_some() {
+            return Err("Pick either a year or --all".to_string());
+        }
+        Ok(options)
+    }
+
+    fn days(&self) -> Result<Vec<&'static Day>, String> {
+        let days: Vec<&Day> = DAYS
+            .iter()
+            .filter(|day| {
+                self.year.is_none_or(|year| year == day.year)
+                    && self.day.is_none_or(|d| d == day.day)
+            })
+            .collect();
+
+        if days.is_empty() {
+            return Err("No solution matches the given year and day".to_string());
+        }
+        Ok(days)
+    }
+
+    fn year_and_day(&self) -> Result<(u16, u8), String> {
+        match (self.year, self.day) {
+            (Some(year), Some(day)) if (1..=25).contains(&day) => Ok((year, day)),
+            _ => Err("Pick a year and a day between 1 and 25".to_string()),
+        }
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part.as_deref() {
+            Some(part) => vec![part.parse().unwrap()],
+            None => vec![1, 2],
+        }
+    }
+}
+
+fn run(options: Options) -> Result<(), String> {
+    if options.bench {
+        return bench(options);
+    }
+    let input_name = options.input.as_deref().unwrap_or("input");
+
+    for day in options.days()? {
+        let Some(input) = read_input(day, input_name) else {
+            eprintln!("💥 Missing input {}/{input_name}.txt", day.path);
+            continue;
+        };
+
+        aoc_solution::run(day.solution, &input, options.part.as_deref());
+    }
+    Ok(())
+}
+
+fn bench(options: Options) -> Result<(), String> {
+    let input_name = options.input.as_deref().unwrap_or("input");
+    let settings = Bench::default();
+    let commit = bench::git_commit();
+    let mut history = History::read(root().join("bench-history.csv"));
+    let mut records = Vec::new();
+
+    for day in options.days()? {
+        let Some(input) = read_input(day, input_name) else {
+            eprintln!("💥 Missing input {}/{input_name}.txt", day.path);
+            continue;
+        };
+
+        println!("{}", day.solution.title());
+        for part in options.parts() {
+            let measure = || settings.measure(|| solve(day, part, &input));
+            let Ok(timing) = panic::catch_unwind(AssertUnwindSafe(measure)) else {
+                println!("💥 Part {part} panicked");
+                continue;
+            };
+            let record = Record {
+                commit: commit.clone(),
+                year: day.year,
+                day: day.day,
+                part,
+                input: input_name.to_string(),
+                timing,
+            };
+
+            let change = match history.previous(&record) {
+                Some(previous) => format!(
+                    ", {:+.1}% since {}",
+                    (timing.median.as_secs_f64() / previous.timing.median.as_secs_f64() - 1.0)
+                        * 100.0,
+                    previous.commit
+                ),
+                None => String::new(),
+            };
+            println!(
+                "⏱️ Part {part}: min {:.2?}, median {:.2?}, p95 {:.2?} over {} runs{change}",
+                timing.min, timing.median, timing.p95, timing.runs
+            );
+            records.push(record);
+        }
+    }
+
+    print_year_totals(&records);
+    history
+        .append(records)
+        .map_err(|error| format!("Unable to write the bench history: {error}"))
+}
+
+/// Sums the median of every part benched, per year.
+fn print_year_totals(records: &[Record]) {
+    let mut years: BTreeMap<u16, (Vec<u8>, Duration)> = BTreeMap::new();
+    for record in records {
+        let (days, total) = years.entry(record.year).or_default();
+        if days.last() != Some(&record.day) {
+            days.push(record.day);
+        }
+        *total += record.timing.median;
+    }
+
+    println!("\n{:<6}{:>6}{:>12}", "Year", "Days", "Total");
+    for (year, (days, total)) in years {
+        println!("{year:<6}{:>6}{:>12}", days.len(), format!("{total:.2?}"));
+    }
+}
+
+fn check(options: Options) -> Result<(), String> {
+    let inputs = match options.input.as_deref() {
+        Some(input) => vec![input],
+        None => INPUTS.to_vec(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut answers = Answers::default();
+    let mut answers_year = None;
+
+    for day in options.days()? {
+        if answers_year != Some(day.year) {
+            answers = Answers::read(day.year)?;
+            answers_year = Some(day.year);
+        }
+
+        println!("{}", day.solution.title());
+        for part in options.parts() {
+            for input in inputs.iter() {
+                let status = answers::check(day, &answers, part, input);
+                let icon = match status {
+                    Status::Pass => {
+                        passed += 1;
+                        "✅"
+                    }
+                    Status::Fail { .. } => {
+                        failed += 1;
+                        "❌"
+                    }
+                    Status::Missing => {
+                        missing += 1;
+                        "❔"
+                    }
+                };
+                println!("{icon} Part {part} on {input}.txt: {status}");
+            }
+        }
+    }
+
+    println!("🎄 {passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        return Err(format!("{failed} answers did not match"));
+    }
+    Ok(())
+}
+
+fn new(options: Options) -> Result<(), String> {
+    let (year, day) = options.year_and_day()?;
+    let title = Client::from_env(root()).title(year, day)?;
+    println!("{title}");
+
+    scaffold::create_day(root(), year, day, &title)?;
+    scaffold::register_day(root(), year, day)?;
+    println!(
+        "🎄 Created {}, run `aoc fetch {year} {day}` for the input",
+        scaffold::day_path(year, day)
+    );
+    Ok(())
+}
+
+fn fetch(options: Options) -> Result<(), String> {
+    let (year, day) = options.year_and_day()?;
+    let cache = InputCache::new(root().join(".cache/inputs"));
+    let input = cache.get_or_fetch(&Client::from_env(root()), year, day)?;
+
+    let path = match DAYS.iter().find(|d| d.year == year && d.day == day) {
+        Some(day) => day.path.to_string(),
+        None => scaffold::day_path(year, day),
+    };
+    let dir = root().join(&path);
+    if !dir.exists() {
+        return Err(format!(
+            "Missing {path}, create it with `aoc new {year} {day}`"
+        ));
+    }
+
+    std::fs::write(dir.join("input.txt"), input)
+        .map_err(|error| format!("Unable to write the input: {error}"))?;
+    println!("🎄 Wrote {path}/input.txt");
+    Ok(())
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => Options::parse(args).and_then(run),
+        Some("check") => Options::parse(args).and_then(check),
+        Some("new") => Options::parse(args).and_then(new),
+        Some("fetch") => Options::parse(args).and_then(fetch),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(error) = result {
+        eprintln!("💥 {error}");
+        process::exit(1);
+    }
+}
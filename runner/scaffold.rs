@@ -0,0 +1,158 @@
+use std::{fs, path::Path};
+
+/// Folder of a new day relative to the repository root. Older years use
+/// their own layouts, new days always get a zero padded folder.
+pub fn day_path(year: u16, day: u8) -> String {
+    format!("{year}/days/{day:02}")
+}
+
+fn package_name(year: u16, day: u8) -> String {
+    format!("aoc-{year}-{day}")
+}
+
+const CARGO_TOML: &str = r#"[package]
+name = "{package}"
+version = "1.84.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-solution = { path = "../../../lib/aoc-solution" }
+
+[lib]
+path = "lib.rs"
+
+[[bin]]
+name = "{package}"
+path = "main.rs"
+"#;
+
+const LIB_RS: &str = r#"fn read_input(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.trim().to_string()).collect()
+}
+
+fn part1(input: &str) -> Option<isize> {
+    let _lines = read_input(input);
+    None
+}
+
+fn part2(input: &str) -> Option<isize> {
+    let _lines = read_input(input);
+    None
+}
+
+pub struct Day;
+
+impl aoc_solution::Solution for Day {
+    fn title(&self) -> &'static str {
+        "{title}"
+    }
+
+    fn part1(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part1(input).map(aoc_solution::Answer::from)
+    }
+
+    fn part2(&self, input: &str) -> Option<aoc_solution::Answer> {
+        part2(input).map(aoc_solution::Answer::from)
+    }
+}
+"#;
+
+const MAIN_RS: &str = r#"fn main() {
+    aoc_solution::main(&{krate}::Day);
+}
+"#;
+
+/// Writes the crate of a new day under `root`, with an empty `test.txt`.
+pub fn create_day(root: &Path, year: u16, day: u8, title: &str) -> Result<(), String> {
+    let dir = root.join(day_path(year, day));
+    if dir.exists() {
+        return Err(format!("Day {day} of {year} already exists"));
+    }
+    fs::create_dir_all(&dir).map_err(|error| error.to_string())?;
+
+    let package = package_name(year, day);
+    let files = [
+        ("Cargo.toml", CARGO_TOML.replace("{package}", &package)),
+        (
+            "lib.rs",
+            LIB_RS.replace("{title}", &title.replace('"', "\\\"")),
+        ),
+        (
+            "main.rs",
+            MAIN_RS.replace("{krate}", &package.replace('-', "_")),
+        ),
+        ("test.txt", String::new()),
+    ];
+    for (name, content) in files {
+        fs::write(dir.join(name), content)
+            .map_err(|error| format!("Unable to write {name}: {error}"))?;
+    }
+    Ok(())
+}
+
+/// Adds a day created by [`create_day`] to the runner dependencies and to
+/// `for_each_day!`, keeping both sorted by year and day.
+pub fn register_day(root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let package = package_name(year, day);
+    let path = day_path(year, day);
+
+    insert_sorted(
+        &root.join("runner/Cargo.toml"),
+        format!("{package} = {{ path = \"../{path}\" }}"),
+        (year, day),
+        |line| {
+            let (name, _) = line.trim_start_matches("# ").split_once(" = ")?;
+            let mut parts = name.strip_prefix("aoc-")?.split('-');
+            Some((
+                parts.next()?.parse().ok()?,
+                parts.next_back()?.parse().ok()?,
+            ))
+        },
+    )?;
+    insert_sorted(
+        &root.join("runner/days.rs"),
+        format!(
+            "            ({year}, {day}, \"{path}\", {}),",
+            package.replace('-', "_")
+        ),
+        (year, day),
+        |line| {
+            let tuple = line.trim().trim_start_matches("// ").strip_prefix('(')?;
+            let mut fields = tuple.split(", ");
+            Some((fields.next()?.parse().ok()?, fields.next()?.parse().ok()?))
+        },
+    )
+}
+
+/// Inserts `new_line` before the first entry sorting after `key`, or after
+/// the last entry. Lines `key_of` can't read are not entries.
+fn insert_sorted(
+    path: &Path,
+    new_line: String,
+    key: (u16, u8),
+    key_of: impl Fn(&str) -> Option<(u16, u8)>,
+) -> Result<(), String> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read {}: {error}", path.display()))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let entries: Vec<(usize, (u16, u8))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, key_of(line)?)))
+        .collect();
+    if entries.iter().any(|(_, entry)| *entry == key) {
+        return Err(format!("{} already lists {key:?}", path.display()));
+    }
+
+    let index = match entries.iter().find(|(_, entry)| *entry > key) {
+        Some((index, _)) => *index,
+        None => entries.last().map_or(lines.len(), |(index, _)| index + 1),
+    };
+    lines.insert(index, &new_line);
+
+    fs::write(path, lines.join("\n") + "\n")
+        .map_err(|error| format!("Unable to write {}: {error}", path.display()))
+}
@@ -0,0 +1,70 @@
+use aoc::{
+    answers::{self, Answers, Status},
+    days::DAYS,
+};
+
+fn check_day(year: u16, day: u8, input: &str) {
+    let day = DAYS
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+        .unwrap();
+    let answers = Answers::read(year).unwrap();
+
+    for part in [1, 2] {
+        let status = answers::check(day, &answers, part, input);
+        assert!(
+            !matches!(status, Status::Fail { .. }),
+            "{year} day {} part {part} on {input}.txt: {status}",
+            day.day
+        );
+    }
+}
+
+// One module per day, so `cargo test aoc_2024_17` checks a single day. Full
+// puzzle inputs take minutes all together, they only run with `--ignored`.
+macro_rules! answer_tests {
+    ($(($year:literal, $day:literal, $path:literal, $krate:ident)),* $(,)?) => {
+        $(
+            mod $krate {
+                #[test]
+                fn test() {
+                    super::check_day($year, $day, "test");
+                }
+
+                #[test]
+                #[ignore]
+                fn input() {
+                    super::check_day($year, $day, "input");
+                }
+            }
+        )*
+    };
+}
+
+aoc::for_each_day!(answer_tests);
+
+#[test]
+fn parse_answers() {
+    let answers = Answers::parse(
+        r#"
+        [17.1]
+        test = "4,6,3,5,6,3,5,2,1,0"
+        input = 12
+
+        [3.2]
+        input = "184122457"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        answers.get(17, 1, "test"),
+        Some(&"4,6,3,5,6,3,5,2,1,0".into())
+    );
+    assert_eq!(answers.get(17, 1, "input"), Some(&12.into()));
+    assert_eq!(answers.get(3, 2, "input"), Some(&"184122457".into()));
+    assert_eq!(answers.get(17, 2, "input"), None);
+
+    assert!(Answers::parse("[x.1]\ntest = 1").is_err());
+    assert!(Answers::parse("[1.1]\ntest = 1.5").is_err());
+}
@@ -0,0 +1,81 @@
+use std::{env, fs, time::Duration};
+
+use aoc::bench::{Bench, History, Record, Timing};
+
+fn millis(values: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+    values.into_iter().map(Duration::from_millis).collect()
+}
+
+fn record(commit: &str, median: u64) -> Record {
+    Record {
+        commit: commit.to_string(),
+        year: 2024,
+        day: 17,
+        part: 2,
+        input: "input".to_string(),
+        timing: Timing::from_samples(millis([median])),
+    }
+}
+
+#[test]
+fn timing_picks_percentiles_of_sorted_samples() {
+    let timing = Timing::from_samples(millis((1..=20).rev()));
+
+    assert_eq!(timing.runs, 20);
+    assert_eq!(timing.min, Duration::from_millis(1));
+    assert_eq!(timing.median, Duration::from_millis(10));
+    assert_eq!(timing.p95, Duration::from_millis(19));
+
+    let single = Timing::from_samples(millis([7]));
+    assert_eq!(single.median, Duration::from_millis(7));
+    assert_eq!(single.p95, Duration::from_millis(7));
+}
+
+#[test]
+fn bench_respects_min_and_max_runs() {
+    let mut calls = 0;
+    let bench = Bench {
+        warmup: Duration::ZERO,
+        budget: Duration::ZERO,
+        min_runs: 5,
+        max_runs: 10,
+    };
+    let timing = bench.measure(|| calls += 1);
+
+    assert_eq!(timing.runs, 5);
+    // One warmup run comes first
+    assert_eq!(calls, 6);
+
+    let bench = Bench {
+        budget: Duration::from_secs(60),
+        ..bench
+    };
+    assert_eq!(bench.measure(|| ()).runs, 10);
+}
+
+#[test]
+fn history_compares_with_other_commits() {
+    let path = env::temp_dir().join(format!("aoc-bench-history-{}.csv", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut history = History::read(&path);
+    history
+        .append(vec![record("aaaaaaa", 10), record("bbbbbbb", 20)])
+        .unwrap();
+    history.append(vec![record("ccccccc", 30)]).unwrap();
+
+    let history = History::read(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(history.records().len(), 3);
+    assert_eq!(history.records()[2], record("ccccccc", 30));
+
+    let previous = history.previous(&record("ccccccc", 5)).unwrap();
+    assert_eq!(previous.commit, "bbbbbbb");
+    assert!(history
+        .previous(&Record {
+            day: 1,
+            ..record("ccccccc", 5)
+        })
+        .is_none());
+}
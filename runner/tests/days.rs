@@ -0,0 +1,41 @@
+//! Puzzle examples `answers.toml` has no room for, run straight through a
+//! day's `Solution`.
+
+use aoc_solution::Solution;
+
+fn chronospatial_program(register_a: usize, program: &str) -> String {
+    format!("Register A: {register_a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n")
+}
+
+#[test]
+fn chronospatial_computer_runs_the_example() {
+    let input = chronospatial_program(729, "0,1,5,4,3,0");
+
+    assert_eq!(
+        aoc_2024_17::Day.part1(&input),
+        Some("4,6,3,5,6,3,5,2,1,0".into())
+    );
+}
+
+#[test]
+fn chronospatial_computer_finds_the_quine() {
+    let input = chronospatial_program(2024, "0,3,5,4,3,0");
+
+    assert_eq!(aoc_2024_17::Day.part2(&input), Some("117440".into()));
+}
+
+#[test]
+#[should_panic(expected = "Combo operand 7 is reserved")]
+fn chronospatial_computer_rejects_the_reserved_combo() {
+    let input = chronospatial_program(2024, "0,3,5,7,3,0");
+
+    aoc_2024_17::Day.part2(&input);
+}
+
+#[test]
+#[should_panic(expected = "Program does not consume RA three bits at a time")]
+fn chronospatial_computer_only_solves_octal_programs() {
+    let input = chronospatial_program(2024, "0,1,5,4,3,0");
+
+    aoc_2024_17::Day.part2(&input);
+}
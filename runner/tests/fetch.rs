@@ -0,0 +1,165 @@
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use aoc::{
+    fetch::{Client, InputCache},
+    scaffold,
+};
+
+/// Serves the puzzle page and input of 2024 day 7 on a local port, keeping
+/// the request lines and cookies it saw.
+fn stub_server() -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                if line.starts_with("GET") || line.starts_with("Cookie") {
+                    request += line.trim();
+                    request += " ";
+                }
+                line.clear();
+            }
+
+            let (status, body) = if request.starts_with("GET /2024/day/7/input ") {
+                ("200 OK", "190: 10 19\n3267: 81 40 27\n")
+            } else if request.starts_with("GET /2024/day/7 ") {
+                (
+                    "200 OK",
+                    "<main><h2>--- Day 7: Bridge Repair ---</h2></main>",
+                )
+            } else {
+                ("404 Not Found", "")
+            };
+            seen.lock().unwrap().push(request.trim().to_string());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    (url, requests)
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn client_reads_title_and_input() {
+    let (url, requests) = stub_server();
+    let client = Client::new(&url, Some("cookie".to_string()));
+
+    assert_eq!(
+        client.title(2024, 7).unwrap(),
+        "--- Day 7: Bridge Repair ---"
+    );
+    assert_eq!(
+        client.input(2024, 7).unwrap(),
+        "190: 10 19\n3267: 81 40 27\n"
+    );
+    assert!(client.title(2024, 8).is_err());
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0], "GET /2024/day/7 HTTP/1.1");
+    assert_eq!(
+        requests[1],
+        "GET /2024/day/7/input HTTP/1.1 Cookie: session=cookie"
+    );
+}
+
+#[test]
+fn inputs_need_a_token() {
+    let (url, requests) = stub_server();
+    let client = Client::new(&url, None);
+
+    assert!(client.input(2024, 7).is_err());
+    assert!(requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn cache_downloads_each_input_once() {
+    let (url, requests) = stub_server();
+    let client = Client::new(&url, Some("cookie".to_string()));
+    let dir = temp_dir("cache");
+    let cache = InputCache::new(&dir);
+
+    assert_eq!(cache.get(2024, 7), None);
+    let input = cache.get_or_fetch(&client, 2024, 7).unwrap();
+    assert_eq!(cache.get_or_fetch(&client, 2024, 7).unwrap(), input);
+    assert_eq!(fs::read_to_string(dir.join("2024/07.txt")).unwrap(), input);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn new_day_is_registered_in_order() {
+    let root = temp_dir("scaffold");
+    fs::create_dir_all(root.join("runner")).unwrap();
+    fs::write(
+        root.join("runner/Cargo.toml"),
+        "[dependencies]\n\
+         aoc-solution = { path = \"../lib/aoc-solution\" }\n\
+         aoc-2024-1 = { path = \"../2024/days/01\" }\n\
+         # aoc-2024-13 = { path = \"../2024/days/13\" }\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("runner/days.rs"),
+        "        $callback! {\n\
+         \x20           (2024, 1, \"2024/days/01\", aoc_2024_1),\n\
+         \x20           // (2024, 13, \"2024/days/13\", aoc_2024_13),\n\
+         \x20       }\n",
+    )
+    .unwrap();
+
+    scaffold::create_day(&root, 2024, 7, "--- Day 7: Bridge Repair ---").unwrap();
+    scaffold::register_day(&root, 2024, 7).unwrap();
+
+    let lib = fs::read_to_string(root.join("2024/days/07/lib.rs")).unwrap();
+    assert!(lib.contains("\"--- Day 7: Bridge Repair ---\""));
+    assert!(lib.contains("impl aoc_solution::Solution for Day"));
+    let main = fs::read_to_string(root.join("2024/days/07/main.rs")).unwrap();
+    assert!(main.contains("aoc_solution::main(&aoc_2024_7::Day)"));
+    assert_eq!(
+        fs::read_to_string(root.join("2024/days/07/test.txt")).unwrap(),
+        ""
+    );
+
+    let cargo = fs::read_to_string(root.join("runner/Cargo.toml")).unwrap();
+    assert!(cargo.contains(
+        "aoc-2024-1 = { path = \"../2024/days/01\" }\n\
+         aoc-2024-7 = { path = \"../2024/days/07\" }\n\
+         # aoc-2024-13"
+    ));
+    let days = fs::read_to_string(root.join("runner/days.rs")).unwrap();
+    assert!(days.contains(
+        "aoc_2024_1),\n            (2024, 7, \"2024/days/07\", aoc_2024_7),\n            // (2024, 13"
+    ));
+
+    assert!(scaffold::create_day(&root, 2024, 7, "Again").is_err());
+    assert!(scaffold::register_day(&root, 2024, 7).is_err());
+
+    fs::remove_dir_all(root).unwrap();
+}